@@ -1,5 +1,6 @@
-use crate::utils::{parse_validator, parse_value};
+use crate::utils::{parse_transformer, parse_validator, parse_value};
 use graphql_parser::query::Value;
+use inflector::Inflector;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Attribute, AttributeArgs, Error, Lit, Meta, MetaList, NestedMeta, Result, Type};
@@ -60,6 +61,45 @@ impl CacheControl {
     }
 }
 
+#[derive(Debug, Copy, Clone)]
+pub enum RenameRule {
+    CamelCase,
+    SnakeCase,
+    ScreamingSnakeCase,
+    PascalCase,
+}
+
+impl RenameRule {
+    pub fn parse(lit: &Lit) -> Result<Self> {
+        if let Lit::Str(lit) = lit {
+            match lit.value().as_str() {
+                "camelCase" => Ok(RenameRule::CamelCase),
+                "snake_case" => Ok(RenameRule::SnakeCase),
+                "SCREAMING_SNAKE_CASE" => Ok(RenameRule::ScreamingSnakeCase),
+                "PascalCase" => Ok(RenameRule::PascalCase),
+                _ => Err(Error::new_spanned(
+                    lit,
+                    "The naming convention should be one of \"camelCase\", \"snake_case\", \"SCREAMING_SNAKE_CASE\" or \"PascalCase\".",
+                )),
+            }
+        } else {
+            Err(Error::new_spanned(
+                lit,
+                "The naming convention should be a string.",
+            ))
+        }
+    }
+
+    pub fn apply(self, name: &str) -> String {
+        match self {
+            RenameRule::CamelCase => name.to_camel_case(),
+            RenameRule::SnakeCase => name.to_snake_case(),
+            RenameRule::ScreamingSnakeCase => name.to_screaming_snake_case(),
+            RenameRule::PascalCase => name.to_pascal_case(),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Object {
     pub internal: bool,
@@ -67,6 +107,7 @@ pub struct Object {
     pub desc: Option<String>,
     pub cache_control: CacheControl,
     pub extends: bool,
+    pub rename_all: RenameRule,
 }
 
 impl Object {
@@ -76,6 +117,7 @@ impl Object {
         let mut desc = None;
         let mut cache_control = CacheControl::default();
         let mut extends = false;
+        let mut rename_all = RenameRule::CamelCase;
 
         for arg in args {
             match arg {
@@ -104,6 +146,8 @@ impl Object {
                                 "Attribute 'desc' should be a string.",
                             ));
                         }
+                    } else if nv.path.is_ident("rename_all") {
+                        rename_all = RenameRule::parse(&nv.lit)?;
                     }
                 }
                 NestedMeta::Meta(Meta::List(ls)) => {
@@ -121,6 +165,7 @@ impl Object {
             desc,
             cache_control,
             extends,
+            rename_all,
         })
     }
 }
@@ -215,6 +260,8 @@ pub struct Field {
     pub provides: Option<String>,
     pub requires: Option<String>,
     pub is_ref: bool,
+    pub complexity: Option<syn::Expr>,
+    pub flatten: bool,
 }
 
 impl Field {
@@ -228,6 +275,8 @@ impl Field {
         let mut provides = None;
         let mut requires = None;
         let mut is_ref = false;
+        let mut complexity = None;
+        let mut flatten = false;
 
         for attr in attrs {
             match attr.parse_meta()? {
@@ -244,6 +293,15 @@ impl Field {
                             NestedMeta::Meta(Meta::Path(p)) if p.is_ident("ref") => {
                                 is_ref = true;
                             }
+                            NestedMeta::Meta(Meta::Path(p)) if p.is_ident("flatten") => {
+                                if ls.nested.len() > 1 {
+                                    return Err(Error::new_spanned(
+                                        &ls,
+                                        "The 'flatten' attribute can't be used with other attributes.",
+                                    ));
+                                }
+                                flatten = true;
+                            }
                             NestedMeta::Meta(Meta::NameValue(nv)) => {
                                 if nv.path.is_ident("name") {
                                     if let syn::Lit::Str(lit) = &nv.lit {
@@ -290,6 +348,15 @@ impl Field {
                                             "Attribute 'requires' should be a string.",
                                         ));
                                     }
+                                } else if nv.path.is_ident("complexity") {
+                                    if let syn::Lit::Str(lit) = &nv.lit {
+                                        complexity = Some(lit.parse::<syn::Expr>()?);
+                                    } else {
+                                        return Err(Error::new_spanned(
+                                            &nv.lit,
+                                            "Attribute 'complexity' should be a string.",
+                                        ));
+                                    }
                                 }
                             }
                             NestedMeta::Meta(Meta::List(ls)) => {
@@ -315,6 +382,8 @@ impl Field {
                 provides,
                 requires,
                 is_ref,
+                complexity,
+                flatten,
             }))
         } else {
             Ok(None)
@@ -327,6 +396,7 @@ pub struct Enum {
     pub internal: bool,
     pub name: Option<String>,
     pub desc: Option<String>,
+    pub rename_items: RenameRule,
 }
 
 impl Enum {
@@ -334,6 +404,7 @@ impl Enum {
         let mut internal = false;
         let mut name = None;
         let mut desc = None;
+        let mut rename_items = RenameRule::ScreamingSnakeCase;
 
         for arg in args {
             match arg {
@@ -359,6 +430,8 @@ impl Enum {
                                 "Attribute 'desc' should be a string.",
                             ));
                         }
+                    } else if nv.path.is_ident("rename_items") {
+                        rename_items = RenameRule::parse(&nv.lit)?;
                     }
                 }
                 _ => {}
@@ -369,6 +442,7 @@ impl Enum {
             internal,
             name,
             desc,
+            rename_items,
         })
     }
 }
@@ -439,6 +513,8 @@ pub struct InputField {
     pub desc: Option<String>,
     pub default: Option<Value>,
     pub validator: TokenStream,
+    pub transformer: Option<TokenStream>,
+    pub flatten: bool,
 }
 
 impl InputField {
@@ -447,12 +523,24 @@ impl InputField {
         let mut desc = None;
         let mut default = None;
         let mut validator = quote! { None };
+        let mut transformer = None;
+        let mut flatten = false;
 
         for attr in attrs {
             if attr.path.is_ident("field") {
                 if let Meta::List(args) = &attr.parse_meta()? {
                     for meta in &args.nested {
-                        if let NestedMeta::Meta(Meta::NameValue(nv)) = meta {
+                        if let NestedMeta::Meta(Meta::Path(p)) = meta {
+                            if p.is_ident("flatten") {
+                                if args.nested.len() > 1 {
+                                    return Err(Error::new_spanned(
+                                        &args,
+                                        "The 'flatten' attribute can't be used with other attributes.",
+                                    ));
+                                }
+                                flatten = true;
+                            }
+                        } else if let NestedMeta::Meta(Meta::NameValue(nv)) = meta {
                             if nv.path.is_ident("name") {
                                 if let syn::Lit::Str(lit) = &nv.lit {
                                     name = Some(lit.value());
@@ -499,6 +587,7 @@ impl InputField {
                     }
 
                     validator = parse_validator(crate_name, &args)?;
+                    transformer = parse_transformer(&args)?;
                 }
             }
         }
@@ -508,6 +597,8 @@ impl InputField {
             desc,
             default,
             validator,
+            transformer,
+            flatten,
         })
     }
 }
@@ -517,6 +608,8 @@ pub struct InputObject {
     pub internal: bool,
     pub name: Option<String>,
     pub desc: Option<String>,
+    pub oneof: bool,
+    pub rename_all: RenameRule,
 }
 
 impl InputObject {
@@ -524,12 +617,17 @@ impl InputObject {
         let mut internal = false;
         let mut name = None;
         let mut desc = None;
+        let mut oneof = false;
+        let mut rename_all = RenameRule::CamelCase;
 
         for arg in args {
             match arg {
                 NestedMeta::Meta(Meta::Path(p)) if p.is_ident("internal") => {
                     internal = true;
                 }
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("oneof") => {
+                    oneof = true;
+                }
                 NestedMeta::Meta(Meta::NameValue(nv)) => {
                     if nv.path.is_ident("name") {
                         if let syn::Lit::Str(lit) = nv.lit {
@@ -549,6 +647,8 @@ impl InputObject {
                                 "Attribute 'desc' should be a string.",
                             ));
                         }
+                    } else if nv.path.is_ident("rename_all") {
+                        rename_all = RenameRule::parse(&nv.lit)?;
                     }
                 }
                 _ => {}
@@ -559,6 +659,8 @@ impl InputObject {
             internal,
             name,
             desc,
+            oneof,
+            rename_all,
         })
     }
 }
@@ -862,3 +964,60 @@ impl DataSource {
         Ok(Self { internal })
     }
 }
+
+#[derive(Debug)]
+pub struct NewtypeScalar {
+    pub internal: bool,
+    pub name: Option<String>,
+    pub desc: Option<String>,
+    pub transparent: bool,
+}
+
+impl NewtypeScalar {
+    pub fn parse(args: AttributeArgs) -> Result<Self> {
+        let mut internal = false;
+        let mut name = None;
+        let mut desc = None;
+        let mut transparent = false;
+
+        for arg in args {
+            match arg {
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("internal") => {
+                    internal = true;
+                }
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("transparent") => {
+                    transparent = true;
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) => {
+                    if nv.path.is_ident("name") {
+                        if let syn::Lit::Str(lit) = nv.lit {
+                            name = Some(lit.value());
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'name' should be a string.",
+                            ));
+                        }
+                    } else if nv.path.is_ident("desc") {
+                        if let syn::Lit::Str(lit) = nv.lit {
+                            desc = Some(lit.value());
+                        } else {
+                            return Err(Error::new_spanned(
+                                &nv.lit,
+                                "Attribute 'desc' should be a string.",
+                            ));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            internal,
+            name,
+            desc,
+            transparent,
+        })
+    }
+}
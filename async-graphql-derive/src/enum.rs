@@ -1,6 +1,5 @@
 use crate::args;
 use crate::utils::{check_reserved_name, get_crate_name};
-use inflector::Inflector;
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::quote;
@@ -95,7 +94,7 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
         let gql_item_name = item_args
             .name
             .take()
-            .unwrap_or_else(|| variant.ident.to_string().to_screaming_snake_case());
+            .unwrap_or_else(|| enum_args.rename_items.apply(&variant.ident.to_string()));
         let item_deprecation = item_args
             .deprecation
             .as_ref()
@@ -106,7 +105,16 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
             .as_ref()
             .map(|s| quote! { Some(#s) })
             .unwrap_or_else(|| quote! {None});
-        enum_items.push(quote! { #(#item_attrs)* #item_ident});
+        let int_value = match &variant.discriminant {
+            Some((_, discriminant)) => {
+                enum_items.push(quote! { #(#item_attrs)* #item_ident = #discriminant });
+                quote! { Some(#ident::#item_ident as i64) }
+            }
+            None => {
+                enum_items.push(quote! { #(#item_attrs)* #item_ident});
+                quote! { None }
+            }
+        };
         items.push(quote! {
             #crate_name::EnumItem {
                 name: #gql_item_name,
@@ -118,6 +126,7 @@ pub fn generate(enum_args: &args::Enum, input: &DeriveInput) -> Result<TokenStre
                 name: #gql_item_name,
                 description: #item_desc,
                 deprecation: #item_deprecation,
+                int_value: #int_value,
             });
         });
     }
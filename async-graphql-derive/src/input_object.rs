@@ -1,6 +1,5 @@
 use crate::args;
 use crate::utils::{build_value_repr, check_reserved_name, get_crate_name};
-use inflector::Inflector;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Error, Result};
@@ -49,6 +48,20 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
         .map(|s| quote! {Some(#s)})
         .unwrap_or_else(|| quote! {None});
 
+    let oneof = object_args.oneof;
+    // `parse` can only return `None`, the caller reports the reason of the failure with
+    // `is_valid_input_value`, both for literals and for values passed through variables.
+    let check_oneof = if oneof {
+        quote! {
+            if obj.values().filter(|value| **value != #crate_name::Value::Null).count() != 1 {
+                return None;
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let mut apply_defaults = Vec::new();
     let mut get_fields = Vec::new();
     let mut fields = Vec::new();
     let mut schema_fields = Vec::new();
@@ -57,10 +70,36 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
         let field_args = args::InputField::parse(&crate_name, &field.attrs)?;
         let ident = field.ident.as_ref().unwrap();
         let ty = &field.ty;
+
+        if field_args.flatten {
+            // The fields of the nested input object are parsed from the parent object, and listed in its fields.
+            get_fields.push(quote! {
+                let #ident: #ty = {
+                    fn assert_input_object<T: #crate_name::InputObjectType>() {}
+                    assert_input_object::<#ty>();
+                    #crate_name::InputValueType::parse(&#crate_name::Value::Object(obj.clone()))?
+                };
+            });
+            fields.push(ident);
+            schema_fields.push(quote! {
+                <#ty as #crate_name::Type>::create_type_info(registry);
+                let input_fields = registry.flattened_input_fields(#gql_typename, &<#ty as #crate_name::Type>::type_name());
+                for (name, field) in input_fields {
+                    registry.check_field_name(#gql_typename, &fields, &name);
+                    fields.insert(name, field);
+                }
+            });
+            continue;
+        }
         let validator = &field_args.validator;
+        let transformer = field_args
+            .transformer
+            .as_ref()
+            .map(|transformer| quote! { Some(std::sync::Arc::new(#transformer)) })
+            .unwrap_or_else(|| quote! { None });
         let name = field_args
             .name
-            .unwrap_or_else(|| ident.to_string().to_camel_case());
+            .unwrap_or_else(|| object_args.rename_all.apply(&ident.to_string()));
         let desc = field_args
             .desc
             .as_ref()
@@ -76,36 +115,49 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
             .unwrap_or_else(|| quote! {None});
 
         if let Some(default) = &field_args.default {
-            let default_repr = build_value_repr(&crate_name, default);
-            get_fields.push(quote! {
-                let #ident:#ty = {
-                    match obj.get(#name) {
-                        Some(value) => #crate_name::InputValueType::parse(value)?,
-                        None => {
-                            let default = #default_repr;
-                            #crate_name::InputValueType::parse(&default)?
-                        }
-                    }
+            let mut default_repr = build_value_repr(&crate_name, default);
+            if let Some(transformer) = &field_args.transformer {
+                // the provided values are transformed before parsing, see `transform_input_value`
+                default_repr = quote! {
+                    #crate_name::transformers::InputValueTransformer::transform(&#transformer, #default_repr)
                 };
-            });
-        } else {
-            get_fields.push(quote! {
-                let #ident:#ty = #crate_name::InputValueType::parse(obj.get(#name).unwrap_or(&#crate_name::Value::Null))?;
+            }
+            apply_defaults.push(quote! {
+                if !obj.contains_key(#name) {
+                    obj.insert(#name.to_string(), #default_repr);
+                }
             });
         }
 
+        get_fields.push(quote! {
+            let #ident:#ty = #crate_name::InputValueType::parse(obj.get(#name).unwrap_or(&#crate_name::Value::Null))?;
+        });
+
         fields.push(ident);
         schema_fields.push(quote! {
+            registry.check_field_name(#gql_typename, &fields, #name);
             fields.insert(#name.to_string(), #crate_name::registry::InputValue {
                 name: #name,
                 description: #desc,
                 ty: <#ty as #crate_name::Type>::create_type_info(registry),
                 default_value: #default,
                 validator: #validator,
+                transformer: #transformer,
             });
         })
     }
 
+    // Missing fields are populated with their default values before the fields are parsed,
+    // so that a default goes through the same path as a value provided by the client.
+    let apply_defaults = if apply_defaults.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let mut obj = obj.clone();
+            #(#apply_defaults)*
+        }
+    };
+
     let expanded = quote! {
         #new_struct
 
@@ -122,7 +174,8 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
                         let mut fields = std::collections::HashMap::new();
                         #(#schema_fields)*
                         fields
-                    }
+                    },
+                    oneof: #oneof,
                 })
             }
         }
@@ -132,6 +185,8 @@ pub fn generate(object_args: &args::InputObject, input: &DeriveInput) -> Result<
                 use #crate_name::Type;
 
                 if let #crate_name::Value::Object(obj) = value {
+                    #check_oneof
+                    #apply_defaults
                     #(#get_fields)*
                     Some(Self { #(#fields),* })
                 } else {
@@ -67,12 +67,12 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
 
                 collect_inline_fields.push(quote! {
                     if let #ident::#enum_name(obj) = self {
-                        return obj.collect_inline_fields(name, pos, ctx, futures);
+                        return obj.collect_inline_fields(name, pos, ctx, fields);
                     }
                 });
 
                 get_introspection_typename.push(quote! {
-                    #ident::#enum_name(obj) => <#p as #crate_name::Type>::type_name()
+                    #ident::#enum_name(obj) => #crate_name::Type::introspection_type_name(obj)
                 })
             } else {
                 return Err(Error::new_spanned(field, "Invalid type"));
@@ -158,6 +158,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
                     ty: <#ty as #crate_name::Type>::create_type_info(registry),
                     default_value: #schema_default,
                     validator: None,
+                    transformer: None,
                 });
             });
         }
@@ -195,6 +196,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
         let schema_ty = ty.value_type();
 
         schema_fields.push(quote! {
+            registry.check_field_name(#gql_typename, &fields, #name);
             fields.insert(#name.to_string(), #crate_name::registry::Field {
                 name: #name.to_string(),
                 description: #desc,
@@ -209,6 +211,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
                 external: #external,
                 provides: #provides,
                 requires: #requires,
+                compute_complexity: None,
             });
         });
 
@@ -302,7 +305,7 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
                 name: &str,
                 pos: #crate_name::Pos,
                 ctx: &#crate_name::ContextSelectionSet<'a>,
-                futures: &mut Vec<#crate_name::BoxFieldFuture<'a>>,
+                fields: &mut #crate_name::Fields<'a>,
             ) -> #crate_name::Result<()> {
                 #(#collect_inline_fields)*
                 Ok(())
@@ -6,6 +6,7 @@ mod args;
 mod r#enum;
 mod input_object;
 mod interface;
+mod newtype_scalar;
 mod object;
 mod output_type;
 mod simple_object;
@@ -117,6 +118,20 @@ pub fn Subscription(args: TokenStream, input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn NewtypeScalar(args: TokenStream, input: TokenStream) -> TokenStream {
+    let scalar_args = match args::NewtypeScalar::parse(parse_macro_input!(args as AttributeArgs)) {
+        Ok(scalar_args) => scalar_args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let input = parse_macro_input!(input as DeriveInput);
+    match newtype_scalar::generate(&scalar_args, &input) {
+        Ok(expanded) => expanded,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
 pub fn DataSource(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -0,0 +1,88 @@
+use crate::args;
+use crate::utils::{check_reserved_name, get_crate_name};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Result};
+
+pub fn generate(scalar_args: &args::NewtypeScalar, input: &DeriveInput) -> Result<TokenStream> {
+    let crate_name = get_crate_name(scalar_args.internal);
+    let ident = &input.ident;
+    let s = match &input.data {
+        Data::Struct(s) => s,
+        _ => return Err(Error::new_spanned(input, "It should be a struct.")),
+    };
+    let inner_ty = match &s.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "It should be a tuple struct with exactly one field.",
+            ))
+        }
+    };
+
+    if scalar_args.transparent && scalar_args.name.is_some() {
+        return Err(Error::new_spanned(
+            input,
+            "The 'name' and 'transparent' attributes cannot be used together.",
+        ));
+    }
+
+    let type_name = if scalar_args.transparent {
+        quote! { <#inner_ty as #crate_name::Scalar>::type_name() }
+    } else {
+        let gql_typename = scalar_args
+            .name
+            .clone()
+            .unwrap_or_else(|| ident.to_string());
+        check_reserved_name(&gql_typename, scalar_args.internal)?;
+        quote! { #gql_typename }
+    };
+
+    let desc = match &scalar_args.desc {
+        Some(desc) => quote! { Some(#desc) },
+        None if scalar_args.transparent => {
+            quote! { <#inner_ty as #crate_name::Scalar>::description() }
+        }
+        None => quote! { None },
+    };
+
+    let impl_scalar = if scalar_args.internal {
+        quote! { crate::impl_scalar_internal!(#ident); }
+    } else {
+        quote! { #crate_name::impl_scalar!(#ident); }
+    };
+
+    let expanded = quote! {
+        #input
+
+        impl #crate_name::Scalar for #ident {
+            fn type_name() -> &'static str {
+                #type_name
+            }
+
+            fn description() -> Option<&'static str> {
+                #desc
+            }
+
+            fn parse(value: &#crate_name::Value) -> Option<Self> {
+                <#inner_ty as #crate_name::Scalar>::parse(value).map(#ident)
+            }
+
+            fn is_valid(value: &#crate_name::Value) -> bool {
+                <#inner_ty as #crate_name::Scalar>::is_valid(value)
+            }
+
+            fn accepted_forms() -> &'static [&'static str] {
+                <#inner_ty as #crate_name::Scalar>::accepted_forms()
+            }
+
+            fn to_json(&self) -> #crate_name::Result<#crate_name::serde_json::Value> {
+                <#inner_ty as #crate_name::Scalar>::to_json(&self.0)
+            }
+        }
+
+        #impl_scalar
+    };
+    Ok(expanded.into())
+}
@@ -1,7 +1,6 @@
 use crate::args;
 use crate::output_type::OutputType;
 use crate::utils::{build_value_repr, check_reserved_name, get_crate_name};
-use inflector::Inflector;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Error, FnArg, ImplItem, ItemImpl, Pat, Result, ReturnType, Type, TypeReference};
@@ -53,7 +52,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                 let field_name = field
                     .name
                     .clone()
-                    .unwrap_or_else(|| method.sig.ident.to_string().to_camel_case());
+                    .unwrap_or_else(|| object_args.rename_all.apply(&method.sig.ident.to_string()));
                 let field_desc = field
                     .desc
                     .as_ref()
@@ -139,6 +138,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                 let mut schema_args = Vec::new();
                 let mut use_params = Vec::new();
                 let mut get_params = Vec::new();
+                let mut complexity_params = Vec::new();
 
                 for (
                     ident,
@@ -153,7 +153,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                 {
                     let name = name
                         .clone()
-                        .unwrap_or_else(|| ident.ident.to_string().to_camel_case());
+                        .unwrap_or_else(|| object_args.rename_all.apply(&ident.ident.to_string()));
                     let desc = desc
                         .as_ref()
                         .map(|s| quote! {Some(#s)})
@@ -173,6 +173,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                             ty: <#ty as #crate_name::Type>::create_type_info(registry),
                             default_value: #schema_default,
                             validator: #validator,
+                            transformer: None,
                         });
                     });
 
@@ -189,11 +190,29 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                     get_params.push(quote! {
                         let #ident: #ty = ctx.param_value(#name, field.position, #default)?;
                     });
+                    complexity_params.push(quote! {
+                        let #ident: #ty = ctx.param_value(#name, ctx.position, #default)?;
+                    });
                 }
 
+                let compute_complexity = match &field.complexity {
+                    Some(complexity) => quote! {
+                        Some({
+                            #[allow(unused_variables)]
+                            fn compute_complexity(ctx: &#crate_name::Context<'_>, child_complexity: usize) -> #crate_name::Result<usize> {
+                                #(#complexity_params)*
+                                Ok(#complexity)
+                            }
+                            compute_complexity
+                        })
+                    },
+                    None => quote! { None },
+                };
+
                 let schema_ty = ty.value_type();
 
                 schema_fields.push(quote! {
+                    registry.check_field_name(#gql_typename, &fields, #field_name);
                     fields.insert(#field_name.to_string(), #crate_name::registry::Field {
                         name: #field_name.to_string(),
                         description: #field_desc,
@@ -208,6 +227,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         external: #external,
                         provides: #provides,
                         requires: #requires,
+                        compute_complexity: #compute_complexity,
                     });
                 });
 
@@ -312,7 +332,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                 for (ident, ty, args::Argument { name, .. }) in &args {
                     let name = name
                         .clone()
-                        .unwrap_or_else(|| ident.ident.to_string().to_camel_case());
+                        .unwrap_or_else(|| object_args.rename_all.apply(&ident.ident.to_string()));
 
                     if !keys_str.is_empty() {
                         keys_str.push(' ');
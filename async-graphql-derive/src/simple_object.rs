@@ -1,6 +1,6 @@
 use crate::args;
+use crate::output_type::OutputType;
 use crate::utils::{check_reserved_name, get_crate_name};
-use inflector::Inflector;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Error, Fields, Result};
@@ -29,6 +29,8 @@ pub fn generate(object_args: &args::Object, input: &mut DeriveInput) -> Result<T
     let mut getters = Vec::new();
     let mut resolvers = Vec::new();
     let mut schema_fields = Vec::new();
+    let mut flatten_resolvers = Vec::new();
+    let mut field_names = Vec::new();
     let fields = match &mut s.fields {
         Fields::Named(fields) => Some(fields),
         Fields::Unit => None,
@@ -38,10 +40,56 @@ pub fn generate(object_args: &args::Object, input: &mut DeriveInput) -> Result<T
     if let Some(fields) = fields {
         for item in &mut fields.named {
             if let Some(field) = args::Field::parse(&item.attrs)? {
-                let field_name = field
-                    .name
-                    .clone()
-                    .unwrap_or_else(|| item.ident.as_ref().unwrap().to_string().to_camel_case());
+                if field.flatten {
+                    // The fields of the nested object are listed in this object, and resolved by the nested object.
+                    let ident = &item.ident;
+                    let ty = &item.ty;
+                    schema_fields.push(quote! {
+                        <#ty as #crate_name::Type>::create_type_info(registry);
+                        let object_fields = registry.flattened_fields(#gql_typename, &<#ty as #crate_name::Type>::type_name());
+                        for (name, field) in object_fields {
+                            registry.check_field_name(#gql_typename, &fields, &name);
+                            fields.insert(name, field);
+                        }
+                    });
+                    flatten_resolvers.push(quote! {
+                        {
+                            fn assert_object<T: #crate_name::ObjectType>() {}
+                            assert_object::<#ty>();
+                        }
+                        if ctx
+                            .registry()
+                            .types
+                            .get(&*<#ty as #crate_name::Type>::type_name())
+                            .and_then(|ty| ty.field_by_name(&field.name))
+                            .is_some()
+                        {
+                            return #crate_name::ObjectType::resolve_field(&self.#ident, ctx, field).await;
+                        }
+                    });
+                    item.attrs.remove(
+                        item.attrs
+                            .iter()
+                            .enumerate()
+                            .find(|(_, a)| a.path.is_ident("field"))
+                            .map(|(idx, _)| idx)
+                            .unwrap(),
+                    );
+                    continue;
+                }
+
+                let field_name = field.name.clone().unwrap_or_else(|| {
+                    object_args
+                        .rename_all
+                        .apply(&item.ident.as_ref().unwrap().to_string())
+                });
+                if field_names.contains(&field_name) {
+                    return Err(Error::new_spanned(
+                        &item,
+                        format!("Field \"{}\" is defined more than once.", field_name),
+                    ));
+                }
+                field_names.push(field_name.clone());
                 let field_desc = field
                     .desc
                     .as_ref()
@@ -63,6 +111,21 @@ pub fn generate(object_args: &args::Object, input: &mut DeriveInput) -> Result<T
                 };
                 let vis = &item.vis;
                 let ty = &item.ty;
+                let output_ty = OutputType::parse(ty)?;
+                let value_ty = output_ty.value_type();
+
+                let compute_complexity = match &field.complexity {
+                    Some(complexity) => quote! {
+                        Some({
+                            #[allow(unused_variables)]
+                            fn compute_complexity(ctx: &#crate_name::Context<'_>, child_complexity: usize) -> #crate_name::Result<usize> {
+                                Ok(#complexity)
+                            }
+                            compute_complexity
+                        })
+                    },
+                    None => quote! { None },
+                };
 
                 let cache_control = {
                     let public = field.cache_control.public;
@@ -76,16 +139,18 @@ pub fn generate(object_args: &args::Object, input: &mut DeriveInput) -> Result<T
                 };
 
                 schema_fields.push(quote! {
+                    registry.check_field_name(#gql_typename, &fields, #field_name);
                     fields.insert(#field_name.to_string(), #crate_name::registry::Field {
                         name: #field_name.to_string(),
                         description: #field_desc,
                         args: Default::default(),
-                        ty: <#ty as #crate_name::Type>::create_type_info(registry),
+                        ty: <#value_ty as #crate_name::Type>::create_type_info(registry),
                         deprecation: #field_deprecation,
                         cache_control: #cache_control,
                         external: #external,
                         provides: #provides,
                         requires: #requires,
+                        compute_complexity: #compute_complexity,
                     });
                 });
 
@@ -107,10 +172,19 @@ pub fn generate(object_args: &args::Object, input: &mut DeriveInput) -> Result<T
                     });
                 }
 
+                let resolve_obj = match &output_ty {
+                    OutputType::Value(_) => quote! { &self.#ident },
+                    OutputType::Result(_, _) => quote! {
+                        self.#ident.as_ref().map_err(|err| {
+                            err.clone().into_error_with_path(field.position, ctx.path_node.as_ref().unwrap().to_json())
+                        })?
+                    },
+                };
+
                 resolvers.push(quote! {
                     if field.name.as_str() == #field_name {
                         let ctx_obj = ctx.with_selection_set(&field.selection_set);
-                        return #crate_name::OutputValueType::resolve(&self.#ident, &ctx_obj, field.position).await;
+                        return #crate_name::OutputValueType::resolve(#resolve_obj, &ctx_obj, field.position).await;
                     }
                 });
 
@@ -169,6 +243,7 @@ pub fn generate(object_args: &args::Object, input: &mut DeriveInput) -> Result<T
         impl #generics #crate_name::ObjectType for #ident #generics {
             async fn resolve_field(&self, ctx: &#crate_name::Context<'_>, field: &#crate_name::graphql_parser::query::Field) -> #crate_name::Result<#crate_name::serde_json::Value> {
                 #(#resolvers)*
+                #(#flatten_resolvers)*
 
                 Err(#crate_name::QueryError::FieldNotFound {
                     field_name: field.name.clone(),
@@ -1,7 +1,6 @@
 use crate::args;
 use crate::output_type::OutputType;
 use crate::utils::{build_value_repr, check_reserved_name, get_crate_name};
-use inflector::Inflector;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
@@ -45,7 +44,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                 let field_name = field
                     .name
                     .clone()
-                    .unwrap_or_else(|| method.sig.ident.to_string().to_camel_case());
+                    .unwrap_or_else(|| object_args.rename_all.apply(&method.sig.ident.to_string()));
                 let field_desc = field
                     .desc
                     .as_ref()
@@ -138,7 +137,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                 {
                     let name = name
                         .clone()
-                        .unwrap_or_else(|| ident.ident.to_string().to_camel_case());
+                        .unwrap_or_else(|| object_args.rename_all.apply(&ident.ident.to_string()));
                     let desc = desc
                         .as_ref()
                         .map(|s| quote! {Some(#s)})
@@ -158,6 +157,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                             ty: <#ty as #crate_name::Type>::create_type_info(registry),
                             default_value: #schema_default,
                             validator: #validator,
+                            transformer: None,
                         });
                     });
 
@@ -184,6 +184,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                 };
 
                 schema_fields.push(quote! {
+                    registry.check_field_name(#gql_typename, &fields, #field_name);
                     fields.insert(#field_name.to_string(), #crate_name::registry::Field {
                         name: #field_name.to_string(),
                         description: #field_desc,
@@ -197,6 +198,7 @@ pub fn generate(object_args: &args::Object, item_impl: &mut ItemImpl) -> Result<
                         cache_control: Default::default(),
                         external: false,
                         requires: None,
+                        compute_complexity: None,
                         provides: None,
                     });
                 });
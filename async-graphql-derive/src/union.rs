@@ -57,11 +57,11 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
             });
             collect_inline_fields.push(quote! {
                 if let #ident::#enum_name(obj) = self {
-                    return obj.collect_inline_fields(name, pos, ctx, futures);
+                    return obj.collect_inline_fields(name, pos, ctx, fields);
                 }
             });
             get_introspection_typename.push(quote! {
-                #ident::#enum_name(obj) => <#p as #crate_name::Type>::type_name()
+                #ident::#enum_name(obj) => #crate_name::Type::introspection_type_name(obj)
             })
         } else {
             return Err(Error::new_spanned(field, "Invalid type"));
@@ -116,8 +116,11 @@ pub fn generate(interface_args: &args::Interface, input: &DeriveInput) -> Result
                 name: &str,
                 pos: #crate_name::Pos,
                 ctx: &#crate_name::ContextSelectionSet<'a>,
-                futures: &mut Vec<#crate_name::BoxFieldFuture<'a>>,
+                fields: &mut #crate_name::Fields<'a>,
             ) -> #crate_name::Result<()> {
+                if name == #gql_typename {
+                    return #crate_name::collect_fields(ctx, self, fields);
+                }
                 #(#collect_inline_fields)*
                 Ok(())
             }
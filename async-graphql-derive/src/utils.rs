@@ -129,6 +129,15 @@ fn parse_nested_validator(
                         None => Some(item),
                     })
                     .unwrap())
+            } else if ls.path.is_ident("list") {
+                if ls.nested.len() != 1 {
+                    return Err(Error::new_spanned(
+                        ls,
+                        "Exactly one validator must be defined for the elements",
+                    ));
+                }
+                let validator = parse_nested_validator(crate_name, &ls.nested[0])?;
+                Ok(quote! { #crate_name::validators::List(#validator) })
             } else {
                 let ty = &ls.path;
                 for item in &ls.nested {
@@ -174,3 +183,40 @@ pub fn parse_validator(crate_name: &TokenStream, args: &MetaList) -> Result<Toke
     }
     Ok(quote! {None})
 }
+
+pub fn parse_transformer(args: &MetaList) -> Result<Option<TokenStream>> {
+    for arg in &args.nested {
+        if let NestedMeta::Meta(Meta::List(ls)) = arg {
+            if ls.path.is_ident("transformer") {
+                if ls.nested.len() != 1 {
+                    return Err(Error::new_spanned(
+                        ls,
+                        "Exactly one transformer must be defined",
+                    ));
+                }
+                return match &ls.nested[0] {
+                    NestedMeta::Meta(Meta::List(ls)) => {
+                        let ty = &ls.path;
+                        let mut params = Vec::new();
+                        for item in &ls.nested {
+                            if let NestedMeta::Meta(Meta::NameValue(nv)) = item {
+                                let name = &nv.path;
+                                let value = &nv.lit;
+                                params.push(quote! { #name: #value });
+                            } else {
+                                return Err(Error::new_spanned(
+                                    item,
+                                    "Invalid argument for transformer",
+                                ));
+                            }
+                        }
+                        Ok(Some(quote! { #ty { #(#params),* } }))
+                    }
+                    NestedMeta::Meta(Meta::Path(ty)) => Ok(Some(quote! { #ty {} })),
+                    nested_meta => Err(Error::new_spanned(nested_meta, "Invalid transformer")),
+                };
+            }
+        }
+    }
+    Ok(None)
+}
@@ -4,17 +4,25 @@
 #![allow(clippy::type_complexity)]
 #![allow(clippy::needless_doctest_main)]
 
-use async_graphql::http::StreamBody;
+use async_graphql::graphql_parser::parse_query;
+use async_graphql::graphql_parser::query::{Definition, OperationDefinition};
+use async_graphql::http::{
+    playground_source, GQLRequest, GQLResponse, GQLResponseMode, StreamBody,
+};
 use async_graphql::{
-    Data, FieldResult, IntoQueryBuilder, IntoQueryBuilderOpts, ObjectType, QueryBuilder, Schema,
-    SubscriptionType, WebSocketTransport,
+    Data, FieldResult, IntoQueryBuilder, IntoQueryBuilderOpts, ObjectType, ParseRequestError,
+    QueryBuilder, QueryResponse, Schema, SubscriptionType, WebSocketTransport,
 };
 use bytes::Bytes;
 use futures::select;
 use futures::{SinkExt, StreamExt};
+use std::any::Any;
+use std::collections::HashMap;
 use std::sync::Arc;
 use warp::filters::ws::Message;
 use warp::filters::BoxedFilter;
+use warp::http::header::{HeaderValue, CACHE_CONTROL, CONTENT_TYPE};
+use warp::http::StatusCode;
 use warp::reject::Reject;
 use warp::{Filter, Rejection, Reply};
 
@@ -31,6 +39,157 @@ impl std::fmt::Debug for BadRequest {
 
 impl Reject for BadRequest {}
 
+/// Recover the rejections of the GraphQL filters
+///
+/// `BadRequest` is converted to a GraphQL error response with the status code 400, and the other rejections are passed on.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use warp::{Filter, Reply};
+/// use std::convert::Infallible;
+/// use async_graphql::http::GQLResponse;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let filter = async_graphql_warp::graphql(schema)
+///         .and_then(|(schema, builder): (_, QueryBuilder)| async move {
+///             let resp = builder.execute(&schema).await;
+///             Ok::<_, Infallible>(warp::reply::json(&GQLResponse(resp)).into_response())
+///         })
+///         .recover(async_graphql_warp::graphql_recover);
+///     warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+/// }
+/// ```
+pub async fn graphql_recover(err: Rejection) -> Result<impl Reply, Rejection> {
+    if let Some(BadRequest(err)) = err.find() {
+        return Ok(warp::reply::with_status(
+            warp::reply::json(&serde_json::json!({
+                "errors": [{ "message": err.to_string() }]
+            })),
+            StatusCode::BAD_REQUEST,
+        ));
+    }
+    Err(err)
+}
+
+/// GraphQL response
+///
+/// A reply with the JSON response, and the `Cache-Control` header computed from the cache control of the query
+/// (see `CacheControl::value`), so the response can be cached by a CDN.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use async_graphql_warp::GraphQLResponse;
+/// use warp::Filter;
+/// use std::convert::Infallible;
+///
+/// struct QueryRoot;
+///
+/// #[Object(cache_control(max_age = 60))]
+/// impl QueryRoot {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let filter = async_graphql_warp::graphql(schema)
+///         .and_then(|(schema, builder): (_, QueryBuilder)| async move {
+///             Ok::<_, Infallible>(GraphQLResponse::from(builder.execute(&schema).await))
+///         });
+///     warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+/// }
+/// ```
+pub struct GraphQLResponse(pub async_graphql::Result<QueryResponse>);
+
+impl From<async_graphql::Result<QueryResponse>> for GraphQLResponse {
+    fn from(resp: async_graphql::Result<QueryResponse>) -> Self {
+        GraphQLResponse(resp)
+    }
+}
+
+impl Reply for GraphQLResponse {
+    fn into_response(self) -> warp::reply::Response {
+        GraphQLModeResponse(self.0, GQLResponseMode::Json).into_response()
+    }
+}
+
+/// GraphQL response sent in the mode negotiated with the client
+///
+/// Like `GraphQLResponse`, with the status code and the `Content-Type` of the `GQLResponseMode`, usually obtained
+/// from the `Accept` header with `graphql_response_mode`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use async_graphql::http::GQLResponseMode;
+/// use async_graphql_warp::GraphQLModeResponse;
+/// use warp::Filter;
+/// use std::convert::Infallible;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let filter = async_graphql_warp::graphql(schema)
+///         .and(async_graphql_warp::graphql_response_mode())
+///         .and_then(|(schema, builder): (_, QueryBuilder), mode: GQLResponseMode| async move {
+///             Ok::<_, Infallible>(GraphQLModeResponse(builder.execute(&schema).await, mode))
+///         });
+///     warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+/// }
+/// ```
+pub struct GraphQLModeResponse(
+    pub async_graphql::Result<QueryResponse>,
+    pub GQLResponseMode,
+);
+
+impl Reply for GraphQLModeResponse {
+    fn into_response(self) -> warp::reply::Response {
+        let cache_control = self
+            .0
+            .as_ref()
+            .ok()
+            .and_then(|resp| resp.cache_control.value());
+        let gql_resp = GQLResponse(self.0).with_mode(self.1);
+        let status = StatusCode::from_u16(gql_resp.status_code())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let mut resp =
+            warp::reply::with_status(warp::reply::json(&gql_resp), status).into_response();
+        resp.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static(gql_resp.content_type()),
+        );
+        if let Some(value) = cache_control.and_then(|value| HeaderValue::from_str(&value).ok()) {
+            resp.headers_mut().insert(CACHE_CONTROL, value);
+        }
+        resp
+    }
+}
+
+/// Response mode filter
+///
+/// It outputs the `GQLResponseMode` negotiated from the `Accept` header of the request, see
+/// `GQLResponseMode::from_accept`.
+pub fn graphql_response_mode() -> BoxedFilter<(GQLResponseMode,)> {
+    warp::header::optional::<String>("accept")
+        .map(|accept: Option<String>| GQLResponseMode::from_accept(accept.as_deref()))
+        .boxed()
+}
+
 /// GraphQL request filter
 ///
 /// It outputs a tuple containing the `Schema` and `QuertBuilder`.
@@ -89,6 +248,9 @@ where
 }
 
 /// Similar to graphql, but you can set the options `IntoQueryBuilderOpts`.
+///
+/// If `max_request_size` is set, requests without a `Content-Length` header or with a larger one are rejected by
+/// `warp::body::content_length_limit` before the body is read.
 pub fn graphql_opts<Query, Mutation, Subscription>(
     schema: Schema<Query, Mutation, Subscription>,
     opts: IntoQueryBuilderOpts,
@@ -98,9 +260,14 @@ where
     Mutation: ObjectType + Send + Sync + 'static,
     Subscription: SubscriptionType + Send + Sync + 'static,
 {
+    let content_length_limit = match opts.max_request_size {
+        Some(size) => warp::body::content_length_limit(size as u64).boxed(),
+        None => warp::any().boxed(),
+    };
     let opts = Arc::new(opts);
     warp::any()
         .and(warp::post())
+        .and(content_length_limit)
         .and(warp::header::optional::<String>("content-type"))
         .and(warp::body::stream())
         .and(warp::any().map(move || opts.clone()))
@@ -117,6 +284,297 @@ where
         .boxed()
 }
 
+/// Adds a value of a request header to the data of the query builder
+///
+/// It wraps a filter such as `graphql`, if the header `name` is present, its value is converted with `f` and
+/// added to the data of the query builder, so resolvers and guards can read it with `ctx.data`.
+/// Call it again to add other headers.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use warp::{Filter, Reply};
+/// use std::convert::Infallible;
+/// use async_graphql::http::GQLResponse;
+///
+/// struct Token(String);
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     #[field]
+///     async fn token(&self, ctx: &Context<'_>) -> Option<String> {
+///         ctx.data_opt::<Token>().map(|token| token.0.clone())
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let filter = async_graphql_warp::graphql_header_data(
+///         async_graphql_warp::graphql(schema),
+///         "authorization",
+///         |value| Token(value.to_string()),
+///     )
+///     .and_then(|(schema, builder): (_, QueryBuilder)| async move {
+///         let resp = builder.execute(&schema).await;
+///         Ok::<_, Infallible>(warp::reply::json(&GQLResponse(resp)).into_response())
+///     });
+///     warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+/// }
+/// ```
+pub fn graphql_header_data<Query, Mutation, Subscription, F, D>(
+    filter: BoxedFilter<((Schema<Query, Mutation, Subscription>, QueryBuilder),)>,
+    name: &'static str,
+    f: F,
+) -> BoxedFilter<((Schema<Query, Mutation, Subscription>, QueryBuilder),)>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+    F: Fn(&str) -> D + Send + Sync + 'static,
+    D: Any + Send + Sync,
+{
+    let f = Arc::new(f);
+    filter
+        .and(warp::header::optional::<String>(name))
+        .map(
+            move |(schema, builder): (_, QueryBuilder), value: Option<String>| match value {
+                Some(value) => (schema, builder.data(f(&value))),
+                None => (schema, builder),
+            },
+        )
+        .boxed()
+}
+
+/// GraphQL GET request filter
+///
+/// Reads the `query`, `operationName`, `variables` and `extensions` parameters from the query string, and outputs
+/// the same tuple as `graphql`, so the same handler can serve both filters.
+/// `variables` and `extensions` are JSON encoded, `extensions` can be read by the resolvers with `Context::request_extensions`.
+///
+/// Mutations are rejected with `BadRequest`, because GET requests should not have side effects.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use warp::{Filter, Reply};
+/// use std::convert::Infallible;
+/// use async_graphql::http::GQLResponse;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let filter = async_graphql_warp::graphql_get(schema.clone())
+///         .or(async_graphql_warp::graphql(schema))
+///         .unify()
+///         .and_then(|(schema, builder): (_, QueryBuilder)| async move {
+///             let resp = builder.execute(&schema).await;
+///             Ok::<_, Infallible>(warp::reply::json(&GQLResponse(resp)).into_response())
+///         });
+///     warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+/// }
+/// ```
+pub fn graphql_get<Query, Mutation, Subscription>(
+    schema: Schema<Query, Mutation, Subscription>,
+) -> BoxedFilter<((Schema<Query, Mutation, Subscription>, QueryBuilder),)>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+{
+    warp::any()
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .and(warp::any().map(move || schema.clone()))
+        .and_then(|mut params: HashMap<String, String>, schema| async move {
+            let parse_json = |value: Option<String>| {
+                value
+                    .map(|value| serde_json::from_str::<serde_json::Value>(&value))
+                    .transpose()
+                    .map_err(|err| {
+                        warp::reject::custom(BadRequest(ParseRequestError::InvalidRequest(err)))
+                    })
+            };
+            let request = GQLRequest {
+                query: params.remove("query").unwrap_or_default(),
+                operation_name: params.remove("operationName"),
+                variables: parse_json(params.remove("variables"))?,
+                extensions: parse_json(params.remove("extensions"))?,
+            };
+
+            if is_mutation(&request.query, request.operation_name.as_deref()) {
+                return Err(warp::reject::custom(BadRequest(
+                    ParseRequestError::MutationNotAllowedOverGet,
+                )));
+            }
+
+            let builder = request
+                .into_query_builder()
+                .await
+                .map_err(|err| warp::reject::custom(BadRequest(err)))?;
+            Ok::<_, Rejection>((schema, builder))
+        })
+        .boxed()
+}
+
+/// Returns `true` if the operation executed by the query is a mutation.
+///
+/// Queries that can't be parsed return `false`, the error is reported when the query is executed.
+fn is_mutation(query: &str, operation_name: Option<&str>) -> bool {
+    let document = match parse_query(query) {
+        Ok(document) => document,
+        Err(_) => return false,
+    };
+    let operations = document
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            Definition::Operation(operation) => Some(operation),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let operation = match (operations.as_slice(), operation_name) {
+        ([operation], _) => Some(*operation),
+        (_, Some(name)) => operations.iter().copied().find(|operation| {
+            let operation_name = match operation {
+                OperationDefinition::Query(query) => query.name.as_deref(),
+                OperationDefinition::Mutation(mutation) => mutation.name.as_deref(),
+                OperationDefinition::Subscription(subscription) => subscription.name.as_deref(),
+                OperationDefinition::SelectionSet(_) => None,
+            };
+            operation_name == Some(name)
+        }),
+        _ => None,
+    };
+    if let Some(OperationDefinition::Mutation(_)) = operation {
+        true
+    } else {
+        false
+    }
+}
+
+/// GraphQL request filter that outputs the parsed request
+///
+/// It outputs a tuple containing the `Schema` and `GQLRequest`, so the request can be inspected or modified
+/// before it is converted to a `QueryBuilder`. Only JSON requests are supported, use `graphql` for multipart requests.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use async_graphql::http::{GQLRequest, GQLResponse};
+/// use warp::{Filter, Reply};
+/// use std::convert::Infallible;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let filter = async_graphql_warp::graphql_request(schema).and_then(
+///         |(schema, mut request): (_, GQLRequest)| async move {
+///             request.operation_name = None;
+///             let builder = request.into_query_builder().await.unwrap();
+///             let resp = builder.execute(&schema).await;
+///             Ok::<_, Infallible>(warp::reply::json(&GQLResponse(resp)).into_response())
+///         },
+///     );
+///     warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+/// }
+/// ```
+pub fn graphql_request<Query, Mutation, Subscription>(
+    schema: Schema<Query, Mutation, Subscription>,
+) -> BoxedFilter<((Schema<Query, Mutation, Subscription>, GQLRequest),)>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+{
+    graphql_request_opts(schema, Default::default())
+}
+
+/// Similar to graphql_request, but you can set the options `IntoQueryBuilderOpts`.
+///
+/// Only `max_request_size` applies to this filter, requests without a `Content-Length` header or with a larger one
+/// are rejected by `warp::body::content_length_limit` before the body is read.
+pub fn graphql_request_opts<Query, Mutation, Subscription>(
+    schema: Schema<Query, Mutation, Subscription>,
+    opts: IntoQueryBuilderOpts,
+) -> BoxedFilter<((Schema<Query, Mutation, Subscription>, GQLRequest),)>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+{
+    let content_length_limit = match opts.max_request_size {
+        Some(size) => warp::body::content_length_limit(size as u64).boxed(),
+        None => warp::any().boxed(),
+    };
+    warp::any()
+        .and(warp::post())
+        .and(content_length_limit)
+        .and(warp::body::bytes())
+        .and(warp::any().map(move || schema.clone()))
+        .and_then(|body: Bytes, schema| async move {
+            let request: GQLRequest = serde_json::from_slice(&body).map_err(|err| {
+                warp::reject::custom(BadRequest(ParseRequestError::InvalidRequest(err)))
+            })?;
+            Ok::<_, Rejection>((schema, request))
+        })
+        .boxed()
+}
+
+/// GraphQL Playground filter
+///
+/// Serves the GraphQL Playground page for GET requests, the page sends queries to `endpoint` and
+/// subscriptions to `subscription_endpoint`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_graphql::*;
+/// use warp::Filter;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {}
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let filter = warp::path("graphql")
+///         .and(async_graphql_warp::graphql_subscription(schema))
+///         .or(warp::path("playground").and(async_graphql_warp::graphql_playground(
+///             "/graphql",
+///             Some("ws://localhost:8000/graphql"),
+///         )));
+///     warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
+/// }
+/// ```
+pub fn graphql_playground(
+    endpoint: &str,
+    subscription_endpoint: Option<&str>,
+) -> BoxedFilter<(impl Reply,)> {
+    let source = playground_source(endpoint, subscription_endpoint);
+    warp::any()
+        .and(warp::get())
+        .map(move || warp::reply::html(source.clone()))
+        .boxed()
+}
+
 /// GraphQL subscription filter
 ///
 /// # Examples
@@ -0,0 +1,63 @@
+use async_graphql::http::GQLResponse;
+use async_graphql::*;
+use std::convert::Infallible;
+use warp::{Filter, Reply};
+
+#[tokio::test]
+async fn test_graphql_get() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self, n: i32) -> i32 {
+            n
+        }
+    }
+
+    struct MutationRoot;
+
+    #[Object]
+    impl MutationRoot {
+        #[field]
+        async fn action(&self) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, MutationRoot, EmptySubscription);
+    let filter = async_graphql_warp::graphql_get(schema)
+        .and_then(|(schema, builder): (_, QueryBuilder)| async move {
+            let resp = builder.execute(&schema).await;
+            Ok::<_, Infallible>(warp::reply::json(&GQLResponse(resp)).into_response())
+        })
+        .recover(async_graphql_warp::graphql_recover);
+
+    let resp = warp::test::request()
+        .method("GET")
+        .path("/?query=query%20Q(%24n%3A%20Int!)%20%7B%20value(n%3A%20%24n)%20%7D&operationName=Q&variables=%7B%22n%22%3A%2010%7D")
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(body, serde_json::json!({ "data": { "value": 10 } }));
+
+    let resp = warp::test::request()
+        .method("GET")
+        .path("/?query=mutation%20%7B%20action%20%7D")
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(
+        body["errors"][0]["message"],
+        "Mutations are not allowed over GET requests"
+    );
+
+    let resp = warp::test::request()
+        .method("GET")
+        .path("/?query=%7B%20value(n%3A%201)%20%7D&variables=%7B")
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 400);
+}
@@ -0,0 +1,51 @@
+use async_graphql::http::GQLResponse;
+use async_graphql::*;
+use std::convert::Infallible;
+use warp::{Filter, Reply};
+
+#[tokio::test]
+async fn test_header_data() {
+    struct Token(String);
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn token(&self, ctx: &Context<'_>) -> Option<String> {
+            ctx.data_opt::<Token>().map(|token| token.0.clone())
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let filter = async_graphql_warp::graphql_header_data(
+        async_graphql_warp::graphql(schema),
+        "authorization",
+        |value| Token(value.to_string()),
+    )
+    .and_then(|(schema, builder): (_, QueryBuilder)| async move {
+        let resp = builder.execute(&schema).await;
+        Ok::<_, Infallible>(warp::reply::json(&GQLResponse(resp)).into_response())
+    });
+
+    let resp = warp::test::request()
+        .method("POST")
+        .header("authorization", "Bearer 123456")
+        .body(r#"{"query": "{ token }"}"#)
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({ "data": { "token": "Bearer 123456" } })
+    );
+
+    let resp = warp::test::request()
+        .method("POST")
+        .body(r#"{"query": "{ token }"}"#)
+        .reply(&filter)
+        .await;
+    let body: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(body, serde_json::json!({ "data": { "token": null } }));
+}
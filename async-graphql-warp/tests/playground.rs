@@ -0,0 +1,17 @@
+use warp::http::header::CONTENT_TYPE;
+
+#[tokio::test]
+async fn test_playground() {
+    let filter =
+        async_graphql_warp::graphql_playground("/graphql", Some("ws://localhost:8000/graphql"));
+
+    let resp = warp::test::request().method("GET").reply(&filter).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        resp.headers().get(CONTENT_TYPE).unwrap(),
+        "text/html; charset=utf-8"
+    );
+    let body = std::str::from_utf8(resp.body()).unwrap();
+    assert!(body.contains("GraphQL Playground"));
+    assert!(body.contains("ws://localhost:8000/graphql"));
+}
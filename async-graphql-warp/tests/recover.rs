@@ -0,0 +1,54 @@
+use async_graphql::*;
+use warp::Filter;
+
+#[tokio::test]
+async fn test_recover_bad_request() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let filter = async_graphql_warp::graphql(schema)
+        .map(|_| warp::reply())
+        .recover(async_graphql_warp::graphql_recover);
+
+    let resp = warp::test::request()
+        .method("POST")
+        .body("{")
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 400);
+    let body: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert!(body["errors"][0]["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Invalid request"));
+}
+
+#[tokio::test]
+async fn test_recover_pass_through() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let filter = warp::path("graphql")
+        .and(async_graphql_warp::graphql(schema))
+        .map(|_| warp::reply())
+        .recover(async_graphql_warp::graphql_recover);
+
+    let resp = warp::test::request()
+        .method("POST")
+        .path("/other")
+        .body("{")
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 404);
+}
@@ -0,0 +1,169 @@
+use async_graphql::http::{GQLRequest, GQLResponse};
+use async_graphql::*;
+use std::convert::Infallible;
+use warp::{Filter, Reply};
+
+#[tokio::test]
+async fn test_modify_request() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn user_id(&self, user_id: i32) -> i32 {
+            user_id
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let filter = async_graphql_warp::graphql_request(schema).and_then(
+        |(schema, mut request): (_, GQLRequest)| async move {
+            request.variables = Some(serde_json::json!({ "userId": 100 }));
+            let builder = request.into_query_builder().await.unwrap();
+            let resp = builder.execute(&schema).await;
+            Ok::<_, Infallible>(warp::reply::json(&GQLResponse(resp)).into_response())
+        },
+    );
+
+    let resp = warp::test::request()
+        .method("POST")
+        .body(
+            serde_json::to_vec(&serde_json::json!({
+                "query": "query($userId: Int!) { userId(userId: $userId) }",
+                "variables": { "userId": 1 },
+            }))
+            .unwrap(),
+        )
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(body, serde_json::json!({ "data": { "userId": 100 } }));
+}
+
+#[tokio::test]
+async fn test_max_request_size() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    struct MutationRoot;
+
+    #[Object]
+    impl MutationRoot {
+        #[field]
+        async fn upload(&self, file: Upload) -> String {
+            file.filename
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, MutationRoot, EmptySubscription);
+    let filter = async_graphql_warp::graphql_opts(
+        schema,
+        IntoQueryBuilderOpts {
+            max_request_size: Some(1024),
+            ..IntoQueryBuilderOpts::default()
+        },
+    )
+    .and_then(|(schema, builder): (_, QueryBuilder)| async move {
+        let resp = builder.execute(&schema).await;
+        Ok::<_, Infallible>(warp::reply::json(&GQLResponse(resp)).into_response())
+    })
+    .recover(async_graphql_warp::graphql_recover);
+
+    let resp = warp::test::request()
+        .method("POST")
+        .body(r#"{"query": "{ value }"}"#)
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(body, serde_json::json!({ "data": { "value": 10 } }));
+
+    let multipart = |content: &str| {
+        format!(
+            "--xxx\r\n\
+             Content-Disposition: form-data; name=\"operations\"\r\n\r\n\
+             {{ \"query\": \"mutation($file: Upload!) {{ upload(file: $file) }}\", \"variables\": {{ \"file\": null }} }}\r\n\
+             --xxx\r\n\
+             Content-Disposition: form-data; name=\"map\"\r\n\r\n\
+             {{ \"0\": [\"variables.file\"] }}\r\n\
+             --xxx\r\n\
+             Content-Disposition: form-data; name=\"0\"; filename=\"a.txt\"\r\n\
+             Content-Type: text/plain\r\n\r\n\
+             {}\r\n\
+             --xxx--\r\n",
+            content
+        )
+    };
+
+    let resp = warp::test::request()
+        .method("POST")
+        .header("content-type", "multipart/form-data; boundary=xxx")
+        .body(multipart("hello"))
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(body, serde_json::json!({ "data": { "upload": "a.txt" } }));
+
+    let resp = warp::test::request()
+        .method("POST")
+        .header("content-type", "multipart/form-data; boundary=xxx")
+        .body(multipart(&"a".repeat(2048)))
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 413);
+}
+
+#[tokio::test]
+async fn test_request_max_request_size() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let filter = async_graphql_warp::graphql_request_opts(
+        schema,
+        IntoQueryBuilderOpts {
+            max_request_size: Some(1024),
+            ..IntoQueryBuilderOpts::default()
+        },
+    )
+    .and_then(|(schema, request): (_, GQLRequest)| async move {
+        let builder = request.into_query_builder().await.unwrap();
+        let resp = builder.execute(&schema).await;
+        Ok::<_, Infallible>(warp::reply::json(&GQLResponse(resp)).into_response())
+    });
+
+    let resp = warp::test::request()
+        .method("POST")
+        .body(r#"{"query": "{ value }"}"#)
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 200);
+    let body: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(body, serde_json::json!({ "data": { "value": 10 } }));
+
+    let resp = warp::test::request()
+        .method("POST")
+        .body(format!(
+            r#"{{"query": "{{ value }}", "operationName": "{}"}}"#,
+            "a".repeat(2048)
+        ))
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 413);
+}
@@ -0,0 +1,111 @@
+use async_graphql::http::GQLResponseMode;
+use async_graphql::*;
+use async_graphql_warp::{GraphQLModeResponse, GraphQLResponse};
+use std::convert::Infallible;
+use warp::http::header::{CACHE_CONTROL, CONTENT_TYPE};
+use warp::Filter;
+
+#[tokio::test]
+async fn test_response_cache_control() {
+    struct QueryRoot;
+
+    #[Object(cache_control(max_age = 60))]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+
+        #[field(cache_control(max_age = 30, private))]
+        async fn private_value(&self) -> i32 {
+            20
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let filter = async_graphql_warp::graphql(schema).and_then(
+        |(schema, builder): (_, QueryBuilder)| async move {
+            Ok::<_, Infallible>(GraphQLResponse::from(builder.execute(&schema).await))
+        },
+    );
+
+    let resp = warp::test::request()
+        .method("POST")
+        .body(r#"{"query": "{ value }"}"#)
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(resp.headers().get(CACHE_CONTROL).unwrap(), "max-age=60");
+    let body: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(body, serde_json::json!({ "data": { "value": 10 } }));
+
+    let resp = warp::test::request()
+        .method("POST")
+        .body(r#"{"query": "{ value privateValue }"}"#)
+        .reply(&filter)
+        .await;
+    assert_eq!(
+        resp.headers().get(CACHE_CONTROL).unwrap(),
+        "max-age=30, private"
+    );
+
+    let resp = warp::test::request()
+        .method("POST")
+        .body(r#"{"query": "{ unknown }"}"#)
+        .reply(&filter)
+        .await;
+    assert!(resp.headers().get(CACHE_CONTROL).is_none());
+}
+
+#[tokio::test]
+async fn test_response_mode() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let filter = async_graphql_warp::graphql(schema)
+        .and(async_graphql_warp::graphql_response_mode())
+        .and_then(
+            |(schema, builder): (_, QueryBuilder), mode: GQLResponseMode| async move {
+                Ok::<_, Infallible>(GraphQLModeResponse(builder.execute(&schema).await, mode))
+            },
+        );
+
+    let resp = warp::test::request()
+        .method("POST")
+        .body(r#"{"query": "{ unknown }"}"#)
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        resp.headers().get(CONTENT_TYPE).unwrap(),
+        "application/json"
+    );
+
+    let resp = warp::test::request()
+        .method("POST")
+        .header("accept", "application/graphql-response+json")
+        .body(r#"{"query": "{ value }"}"#)
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        resp.headers().get(CONTENT_TYPE).unwrap(),
+        "application/graphql-response+json"
+    );
+
+    let resp = warp::test::request()
+        .method("POST")
+        .header("accept", "application/graphql-response+json")
+        .body(r#"{"query": "{ unknown }"}"#)
+        .reply(&filter)
+        .await;
+    assert_eq!(resp.status(), 400);
+}
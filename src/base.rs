@@ -1,5 +1,5 @@
 use crate::registry::Registry;
-use crate::{registry, Context, ContextSelectionSet, QueryError, Result, ID};
+use crate::{registry, Context, ContextSelectionSet, Fields, QueryError, Result, ID};
 use graphql_parser::query::{Field, Value};
 use graphql_parser::Pos;
 use std::borrow::Cow;
@@ -85,7 +85,7 @@ pub trait ObjectType: OutputValueType {
         name: &str,
         _pos: Pos,
         ctx: &ContextSelectionSet<'a>,
-        futures: &mut Vec<BoxFieldFuture<'a>>,
+        fields: &mut Fields<'a>,
     ) -> Result<()>
     where
         Self: Send + Sync + Sized,
@@ -97,8 +97,15 @@ pub trait ObjectType: OutputValueType {
                 .get(Self::type_name().as_ref())
                 .map(|ty| ty.contains(name))
                 .unwrap_or_default()
+            || ctx
+                .registry
+                .types
+                .get(name)
+                .and_then(|ty| ty.possible_types())
+                .map(|possible_types| possible_types.contains(Self::type_name().as_ref()))
+                .unwrap_or_default()
         {
-            crate::collect_fields(ctx, self, futures)
+            crate::collect_fields(ctx, self, fields)
         } else {
             Ok(())
         }
@@ -159,8 +166,18 @@ pub trait Scalar: Sized + Send {
     }
 
     /// Parse a scalar value, return `Some(Self)` if successful, otherwise return `None`.
+    ///
+    /// A scalar can accept several representations, such as a color written as a `"#rrggbb"` string or as an integer,
+    /// by trying each of them in turn, see `accepted_forms`.
     fn parse(value: &Value) -> Option<Self>;
 
+    /// The input forms accepted by `parse`, such as `"#rrggbb" string` and `RGB integer`.
+    ///
+    /// They are listed in the error when a value can't be parsed, the default is an empty list.
+    fn accepted_forms() -> &'static [&'static str] {
+        &[]
+    }
+
     /// Checks for a valid scalar value.
     ///
     /// The default implementation is to try to parse it, and in some cases you can implement this on your own to improve performance.
@@ -186,6 +203,7 @@ macro_rules! impl_scalar_internal {
                     name: <$ty as crate::Scalar>::type_name().to_string(),
                     description: <$ty>::description(),
                     is_valid: |value| <$ty as crate::Scalar>::is_valid(value),
+                    accepted_forms: <$ty as crate::Scalar>::accepted_forms(),
                 })
             }
         }
@@ -224,6 +242,7 @@ macro_rules! impl_scalar {
                     name: <$ty as async_graphql::Scalar>::type_name().to_string(),
                     description: <$ty>::description(),
                     is_valid: |value| <$ty as async_graphql::Scalar>::is_valid(value),
+                    accepted_forms: <$ty as async_graphql::Scalar>::accepted_forms(),
                 })
             }
         }
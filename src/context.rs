@@ -1,12 +1,19 @@
 use crate::extensions::BoxExtension;
-use crate::registry::Registry;
-use crate::{InputValueType, Pos, QueryError, Result, Schema, Type};
+use crate::registry::{self, Registry};
+use crate::siblings::{SiblingValue, Siblings};
+use crate::validation::utils::{is_valid_input_value, transform_input_value};
+use crate::{
+    Error, FieldResult, InputValueType, OperationType, Pos, QueryError, Result, Schema, Type,
+};
 use fnv::FnvHashMap;
 use graphql_parser::query::{
-    Directive, Field, FragmentDefinition, SelectionSet, Value, VariableDefinition,
+    Directive, Field, FragmentDefinition, Selection, SelectionSet, Type as ParsedType,
+    TypeCondition, Value, VariableDefinition,
 };
+use parking_lot::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::any::{Any, TypeId};
-use std::collections::{BTreeMap, HashMap};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ops::{Deref, DerefMut};
 use std::path::Path;
 use std::sync::atomic::AtomicUsize;
@@ -222,6 +229,8 @@ impl<'a> QueryPathNode<'a> {
     }
 }
 
+pub(crate) type ResponseCompleteCallback = Box<dyn FnOnce() + Send>;
+
 /// Query context
 #[derive(Clone)]
 pub struct ContextBase<'a, T> {
@@ -236,6 +245,13 @@ pub struct ContextBase<'a, T> {
     pub(crate) data: &'a Data,
     pub(crate) ctx_data: Option<&'a Data>,
     pub(crate) fragments: &'a HashMap<String, FragmentDefinition>,
+    pub(crate) representation: Option<&'a Value>,
+    pub(crate) request_extensions: Option<&'a serde_json::Value>,
+    pub(crate) operation_name: Option<&'a str>,
+    pub(crate) operation_type: OperationType,
+    pub(crate) siblings: Option<Arc<Siblings>>,
+    pub(crate) errors: Option<&'a Mutex<Vec<Error>>>,
+    pub(crate) response_complete: Option<&'a Mutex<Vec<ResponseCompleteCallback>>>,
 }
 
 impl<'a, T> Deref for ContextBase<'a, T> {
@@ -248,6 +264,7 @@ impl<'a, T> Deref for ContextBase<'a, T> {
 
 #[doc(hidden)]
 pub struct Environment {
+    pub operation_name: Option<String>,
     pub variables: Variables,
     pub variable_definitions: Vec<VariableDefinition>,
     pub fragments: HashMap<String, FragmentDefinition>,
@@ -274,6 +291,13 @@ impl Environment {
             data: &schema.0.data,
             ctx_data: Some(&self.ctx_data),
             fragments: &self.fragments,
+            representation: None,
+            request_extensions: None,
+            operation_name: self.operation_name.as_deref(),
+            operation_type: OperationType::Subscription,
+            siblings: None,
+            errors: None,
+            response_complete: None,
         }
     }
 }
@@ -306,6 +330,13 @@ impl<'a, T> ContextBase<'a, T> {
             data: self.data,
             ctx_data: self.ctx_data,
             fragments: self.fragments,
+            representation: self.representation,
+            request_extensions: self.request_extensions,
+            operation_name: self.operation_name,
+            operation_type: self.operation_type,
+            siblings: self.siblings.clone(),
+            errors: self.errors,
+            response_complete: self.response_complete,
         }
     }
 
@@ -325,9 +356,24 @@ impl<'a, T> ContextBase<'a, T> {
             data: self.data,
             ctx_data: self.ctx_data,
             fragments: self.fragments,
+            representation: self.representation,
+            request_extensions: self.request_extensions,
+            operation_name: self.operation_name,
+            operation_type: self.operation_type,
+            siblings: self.siblings.clone(),
+            errors: self.errors,
+            response_complete: self.response_complete,
         }
     }
 
+    /// Gets the registry of the schema.
+    ///
+    /// This is for advanced use, such as generic resolvers that need to look up the fields or possible types of a type
+    /// at runtime. The registry is an implementation detail, its API may change in any release.
+    pub fn registry(&self) -> &'a Registry {
+        self.registry
+    }
+
     /// Gets the global data defined in the `Context` or `Schema`.
     pub fn data<D: Any + Send + Sync>(&self) -> &D {
         self.data_opt::<D>()
@@ -342,6 +388,90 @@ impl<'a, T> ContextBase<'a, T> {
             .and_then(|d| d.downcast_ref::<D>())
     }
 
+    /// Gets the global data defined in the `Context` or `Schema`, returns `D::default()` if the specified type data does not exist.
+    ///
+    /// The data is borrowed if it exists, so it's only cloned when it's modified.
+    pub fn data_or_default<D: Any + Default + Clone + Send + Sync>(&self) -> Cow<'_, D> {
+        match self.data_opt::<D>() {
+            Some(data) => Cow::Borrowed(data),
+            None => Cow::Owned(D::default()),
+        }
+    }
+
+    /// Locks the shared data of type `Arc<parking_lot::RwLock<D>>` for reading.
+    ///
+    /// It's a shorthand for `ctx.data::<Arc<RwLock<D>>>().read()`, the data is usually a store that is shared
+    /// across requests and also used outside the schema.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data does not exist.
+    pub fn data_read<D: Any + Send + Sync>(&self) -> RwLockReadGuard<'_, D> {
+        self.data::<Arc<RwLock<D>>>().read()
+    }
+
+    /// Locks the shared data of type `Arc<parking_lot::RwLock<D>>` for writing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data does not exist.
+    pub fn data_write<D: Any + Send + Sync>(&self) -> RwLockWriteGuard<'_, D> {
+        self.data::<Arc<RwLock<D>>>().write()
+    }
+
+    /// Gets a field of the entity representation that is being resolved by `_entities`.
+    ///
+    /// The representation sent by the gateway contains the fields marked with `@requires`,
+    /// so a resolver can use this method to access the fields it requires, returns `None` if the field
+    /// does not exist or cannot be parsed as `V`.
+    pub fn entity_field<V: InputValueType>(&self, name: &str) -> Option<V> {
+        match self.representation {
+            Some(Value::Object(obj)) => obj.get(name).and_then(InputValueType::parse),
+            _ => None,
+        }
+    }
+
+    /// Gets the `extensions` sent with the request, returns `None` if the request has no extensions.
+    pub fn request_extensions(&self) -> Option<&serde_json::Value> {
+        self.request_extensions
+    }
+
+    /// Gets the name of the executing operation, returns `None` if the operation is anonymous.
+    pub fn operation_name(&self) -> Option<&str> {
+        self.operation_name
+    }
+
+    /// Gets the type of the executing operation.
+    pub fn operation_type(&self) -> OperationType {
+        self.operation_type
+    }
+
+    /// Registers a callback that is called after the response of the query has been produced.
+    ///
+    /// This is useful for cleanup or side effects such as flushing a batch or logging, the callbacks are called
+    /// in the order they were registered, even if the response has errors.
+    /// When resolving subscription events there is no response to wait for, so the callback is called immediately.
+    pub fn on_response_complete<F: FnOnce() + Send + 'static>(&self, f: F) {
+        match self.response_complete {
+            Some(response_complete) => response_complete.lock().push(Box::new(f)),
+            None => f(),
+        }
+    }
+
+    /// Adds an error to the `errors` of the response without failing the query.
+    ///
+    /// Returns the error back if the context can't collect errors (e.g. when resolving subscription events),
+    /// in that case the caller should fail with it.
+    pub(crate) fn add_error(&self, err: Error) -> Result<()> {
+        match self.errors {
+            Some(errors) => {
+                errors.lock().push(err);
+                Ok(())
+            }
+            None => Err(err),
+        }
+    }
+
     fn var_value(&self, name: &str, pos: Pos) -> Result<Value> {
         let def = self
             .variable_definitions
@@ -349,7 +479,7 @@ impl<'a, T> ContextBase<'a, T> {
             .find(|def| def.name == name);
         if let Some(def) = def {
             if let Some(var_value) = self.variables.get(&def.name) {
-                return Ok(var_value.clone());
+                return self.coerce_int_enum(&def.var_type, var_value.clone(), pos);
             } else if let Some(default) = &def.default_value {
                 return Ok(default.clone());
             }
@@ -360,6 +490,38 @@ impl<'a, T> ContextBase<'a, T> {
         .into_error(pos))
     }
 
+    /// Converts the integers of a variable whose type is an enum to the items with these backing values.
+    ///
+    /// Only the values of the variables are converted, the literals of the query must be enum values.
+    fn coerce_int_enum(&self, ty: &ParsedType, value: Value, pos: Pos) -> Result<Value> {
+        match (ty, value) {
+            (ParsedType::NonNullType(ty), value) => self.coerce_int_enum(ty, value, pos),
+            (ParsedType::ListType(ty), Value::List(values)) => Ok(Value::List(
+                values
+                    .into_iter()
+                    .map(|value| self.coerce_int_enum(ty, value, pos))
+                    .collect::<Result<_>>()?,
+            )),
+            (ParsedType::NamedType(name), Value::Int(n)) => {
+                match self.registry.types.get(name.as_str()) {
+                    Some(registry::Type::Enum { enum_values, .. }) => enum_values
+                        .values()
+                        .find(|item| item.int_value.is_some() && item.int_value == n.as_i64())
+                        .map(|item| Value::Enum(item.name.to_string()))
+                        .ok_or_else(|| {
+                            QueryError::InvalidEnumValue {
+                                ty: name.clone(),
+                                value: Value::Int(n).to_string(),
+                            }
+                            .into_error(pos)
+                        }),
+                    _ => Ok(Value::Int(n)),
+                }
+            }
+            (_, value) => Ok(value),
+        }
+    }
+
     fn resolve_input_value(&self, mut value: Value, pos: Pos) -> Result<Value> {
         match value {
             Value::Variable(var_name) => self.var_value(&var_name, pos),
@@ -438,7 +600,7 @@ impl<'a, T> ContextBase<'a, T> {
                     }
                     .into_error(directive.position));
                 }
-            } else {
+            } else if !self.registry.directives.contains_key(&directive.name) {
                 return Err(QueryError::UnknownDirective {
                     name: directive.name.clone(),
                 }
@@ -467,6 +629,13 @@ impl<'a> ContextBase<'a, &'a SelectionSet> {
             data: self.data,
             ctx_data: self.ctx_data,
             fragments: self.fragments,
+            representation: self.representation,
+            request_extensions: self.request_extensions,
+            operation_name: self.operation_name,
+            operation_type: self.operation_type,
+            siblings: self.siblings.clone(),
+            errors: self.errors,
+            response_complete: self.response_complete,
         }
     }
 }
@@ -488,17 +657,30 @@ impl<'a> ContextBase<'a, &'a Field> {
         {
             Some(value) => {
                 let value = self.resolve_input_value(value, pos)?;
+                let value = self.transform_input_value::<T>(name, value, pos)?;
                 let res = InputValueType::parse(&value).ok_or_else(|| {
-                    QueryError::ExpectedType {
-                        expect: T::qualified_type_name(),
-                        actual: value,
+                    // report the path of the invalid element, such as a null in a non-null list
+                    match is_valid_input_value(
+                        self.registry,
+                        &T::qualified_type_name(),
+                        &value,
+                        QueryPathNode {
+                            parent: None,
+                            segment: QueryPathSegment::Name(name),
+                        },
+                    ) {
+                        Some(reason) => QueryError::InvalidArgumentValue { reason },
+                        None => QueryError::ExpectedType {
+                            expect: T::qualified_type_name(),
+                            actual: value,
+                        },
                     }
                     .into_error(pos)
                 })?;
                 Ok(res)
             }
             None => {
-                let value = default();
+                let value = self.transform_input_value::<T>(name, default(), pos)?;
                 let res = InputValueType::parse(&value).ok_or_else(|| {
                     QueryError::ExpectedType {
                         expect: T::qualified_type_name(),
@@ -511,6 +693,101 @@ impl<'a> ContextBase<'a, &'a Field> {
         }
     }
 
+    fn transform_input_value<T: InputValueType>(
+        &self,
+        name: &str,
+        value: Value,
+        pos: Pos,
+    ) -> Result<Value> {
+        transform_input_value(
+            self.registry,
+            &T::qualified_type_name(),
+            value,
+            QueryPathNode {
+                parent: None,
+                segment: QueryPathSegment::Name(name),
+            },
+        )
+        .map_err(|reason| QueryError::InvalidArgumentValue { reason }.into_error(pos))
+    }
+
+    /// Returns the type conditions of the fragments selected on the current field.
+    ///
+    /// An interface or union resolver can use it to skip computing the types that the client doesn't ask for.
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct QueryRoot;
+    ///
+    /// #[Object]
+    /// impl QueryRoot {
+    ///     #[field]
+    ///     async fn types(&self, ctx: &Context<'_>) -> Vec<String> {
+    ///         let mut types = ctx
+    ///             .look_ahead_types()
+    ///             .into_iter()
+    ///             .map(ToString::to_string)
+    ///             .collect::<Vec<_>>();
+    ///         types.sort();
+    ///         types
+    ///     }
+    /// }
+    /// ```
+    pub fn look_ahead_types(&self) -> HashSet<&'a str> {
+        let mut types = HashSet::new();
+        self.collect_type_conditions(&self.item.selection_set, &mut types);
+        types
+    }
+
+    fn collect_type_conditions(
+        &self,
+        selection_set: &'a SelectionSet,
+        types: &mut HashSet<&'a str>,
+    ) {
+        for selection in &selection_set.items {
+            match selection {
+                Selection::Field(_) => {}
+                Selection::FragmentSpread(fragment_spread) => {
+                    if self
+                        .is_skip(&fragment_spread.directives)
+                        .unwrap_or_default()
+                    {
+                        continue;
+                    }
+                    if let Some(fragment) = self.fragments.get(&fragment_spread.fragment_name) {
+                        let TypeCondition::On(name) = &fragment.type_condition;
+                        types.insert(name.as_str());
+                        self.collect_type_conditions(&fragment.selection_set, types);
+                    }
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    if self
+                        .is_skip(&inline_fragment.directives)
+                        .unwrap_or_default()
+                    {
+                        continue;
+                    }
+                    if let Some(TypeCondition::On(name)) = &inline_fragment.type_condition {
+                        types.insert(name.as_str());
+                    }
+                    self.collect_type_conditions(&inline_fragment.selection_set, types);
+                }
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn with_representation<'b>(
+        &'b self,
+        representation: &'b Value,
+    ) -> ContextBase<'b, &'b Field> {
+        ContextBase {
+            representation: Some(representation),
+            ..self.clone()
+        }
+    }
+
     #[doc(hidden)]
     pub fn result_name(&self) -> &str {
         self.item
@@ -518,4 +795,56 @@ impl<'a> ContextBase<'a, &'a Field> {
             .as_deref()
             .unwrap_or_else(|| self.item.name.as_str())
     }
+
+    /// Waits for the sibling field `name` of this field to be resolved and returns its value, as it appears in
+    /// the response.
+    ///
+    /// `name` is the response key of the sibling, its alias if it has one, otherwise its field name, so each aliased
+    /// copy of a field is a different sibling.
+    ///
+    /// It must be enabled with `SchemaBuilder::sibling_values`. The fields of an object are resolved concurrently,
+    /// so this waits for the sibling if it hasn't been resolved yet. Returns `None` if the sibling isn't selected
+    /// by the query or if its resolver failed, and an error if the sibling is waiting for this field, directly or
+    /// not, since neither of them could ever be resolved. The fields of a mutation are resolved one by one, they
+    /// can't get their siblings.
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct QueryRoot;
+    ///
+    /// #[Object]
+    /// impl QueryRoot {
+    ///     #[field]
+    ///     async fn price(&self) -> i32 {
+    ///         100
+    ///     }
+    ///
+    ///     #[field]
+    ///     async fn price_with_tax(&self, ctx: &Context<'_>) -> FieldResult<Option<f64>> {
+    ///         Ok(ctx.sibling_value("price").await?
+    ///             .and_then(|price| price.as_f64())
+    ///             .map(|price| price * 1.2))
+    ///     }
+    /// }
+    ///
+    /// let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+    ///     .sibling_values(true)
+    ///     .finish();
+    /// ```
+    pub async fn sibling_value(&self, name: &str) -> FieldResult<Option<serde_json::Value>> {
+        let siblings = match &self.siblings {
+            Some(siblings) => siblings,
+            None => {
+                return Err(
+                    "The sibling values are not available, see `SchemaBuilder::sibling_values`"
+                        .into(),
+                )
+            }
+        };
+        match siblings.get(self.result_name(), name)? {
+            SiblingValue::Ready(value) => Ok(value),
+            SiblingValue::Pending(rx) => Ok(rx.await.ok().flatten()),
+        }
+    }
 }
@@ -1,12 +1,41 @@
 use graphql_parser::query::{ParseError, Value};
 use graphql_parser::Pos;
 use std::fmt::Debug;
+use std::sync::Arc;
+
+/// The underlying error of a `FieldError`.
+pub type FieldErrorSource = Arc<dyn std::error::Error + Send + Sync>;
 
 /// FieldError type
+///
+/// The first field is the message and the second one the extensions. It is created with `FieldError::new`, and
+/// the underlying error can be set with `FieldError::with_source`.
 #[derive(Clone, Debug)]
-pub struct FieldError(pub String, pub Option<serde_json::Value>);
+pub struct FieldError(
+    pub String,
+    pub Option<serde_json::Value>,
+    Option<FieldErrorSource>,
+);
 
 impl FieldError {
+    /// Create an error with a message and optional extensions.
+    pub fn new(message: impl Into<String>, extensions: Option<serde_json::Value>) -> Self {
+        FieldError(message.into(), extensions, None)
+    }
+
+    /// Set the underlying error of this error.
+    ///
+    /// It is never sent to the client, but can be accessed with `std::error::Error::source` of the `Error`
+    /// returned by the query.
+    pub fn with_source<E: std::error::Error + Send + Sync + 'static>(self, source: E) -> Self {
+        FieldError(self.0, self.1, Some(Arc::new(source)))
+    }
+
+    /// Returns the underlying error of this error.
+    pub fn source(&self) -> Option<&FieldErrorSource> {
+        self.2.as_ref()
+    }
+
     #[doc(hidden)]
     pub fn into_error(self, pos: Pos) -> Error {
         Error::Query {
@@ -15,6 +44,7 @@ impl FieldError {
             err: QueryError::FieldError {
                 err: self.0,
                 extended_error: self.1,
+                cause: self.2,
             },
         }
     }
@@ -27,6 +57,7 @@ impl FieldError {
             err: QueryError::FieldError {
                 err: self.0,
                 extended_error: self.1,
+                cause: self.2,
             },
         }
     }
@@ -40,7 +71,7 @@ where
     E: std::fmt::Display + Send + Sync + 'static,
 {
     fn from(err: E) -> Self {
-        FieldError(format!("{}", err), None)
+        FieldError(format!("{}", err), None, None)
     }
 }
 
@@ -54,21 +85,21 @@ where
     where
         C: FnOnce(&Self) -> serde_json::Value,
     {
-        let name = self.extend().0;
+        let FieldError(name, base, source) = self.extend();
 
-        if let Some(mut base) = self.extend().1 {
+        if let Some(mut base) = base {
             let mut cb_res = cb(&self);
             if let Some(base_map) = base.as_object_mut() {
                 if let Some(cb_res_map) = cb_res.as_object_mut() {
                     base_map.append(cb_res_map);
                 }
-                return FieldError(name, Some(serde_json::json!(base_map)));
+                return FieldError(name, Some(serde_json::json!(base_map)), source);
             } else {
-                return FieldError(name, Some(cb_res));
+                return FieldError(name, Some(cb_res), source);
             }
         }
 
-        FieldError(name, Some(cb(&self)))
+        FieldError(name, Some(cb(&self)), source)
     }
 }
 
@@ -82,7 +113,7 @@ impl ErrorExtensions for FieldError {
 // not conflict with this implementation acting as a fallback.
 impl<E: std::fmt::Display> ErrorExtensions for &E {
     fn extend(&self) -> FieldError {
-        FieldError(format!("{}", self), None)
+        FieldError(format!("{}", self), None, None)
     }
 }
 
@@ -138,6 +169,12 @@ pub enum QueryError {
         actual: Value,
     },
 
+    #[error("Invalid value for argument {reason}.")]
+    InvalidArgumentValue {
+        /// The path of the invalid value and the expected type
+        reason: String,
+    },
+
     #[error("Cannot query field \"{field_name}\" on type \"{object}\".")]
     FieldNotFound {
         /// Field name
@@ -150,10 +187,19 @@ pub enum QueryError {
     #[error("Missing operation")]
     MissingOperation,
 
-    #[error("Unknown operation named \"{name}\"")]
+    #[error("Unknown operation named \"{name}\", available operations: [{}]", .available.join(", "))]
     UnknownOperationNamed {
         /// Operation name for query
         name: String,
+
+        /// Names of the operations in the document
+        available: Vec<String>,
+    },
+
+    #[error("The document contains multiple operations, an operation name must be provided, available operations: [{}]", .available.join(", "))]
+    AmbiguousOperation {
+        /// Names of the operations in the document
+        available: Vec<String>,
     },
 
     #[error("Type \"{object}\" must have a selection of subfields.")]
@@ -168,7 +214,7 @@ pub enum QueryError {
     #[error("Schema is not configured for subscriptions.")]
     NotConfiguredSubscriptions,
 
-    #[error("Invalid value for enum \"{ty}\".")]
+    #[error("Invalid value {value} for enum \"{ty}\".")]
     InvalidEnumValue {
         /// Enum type name
         ty: String,
@@ -224,10 +270,35 @@ pub enum QueryError {
     #[error("Too deep")]
     TooDeep,
 
+    #[error("Query not allowed")]
+    QueryNotAllowed,
+
+    #[error("The query timed out")]
+    Timeout,
+
+    #[error("PersistedQueryNotFound")]
+    PersistedQueryNotFound,
+
+    #[error("The hash of the persisted query doesn't match the query")]
+    PersistedQueryHashMismatch,
+
+    #[error("Subscriptions are not supported over HTTP, use a subscription transport instead")]
+    SubscriptionNotSupportedOverHttp,
+
+    #[error("Argument \"{arg_name}\" of field \"{field_name}\" is an upload, uploads are only supported in multipart requests")]
+    UploadNotSupportedOverSubscription {
+        /// Field name
+        field_name: String,
+
+        /// Argument name
+        arg_name: String,
+    },
+
     #[error("Failed to resolve field: {err}")]
     FieldError {
         err: String,
         extended_error: Option<serde_json::Value>,
+        cause: Option<FieldErrorSource>,
     },
 
     #[error("Entity not found")]
@@ -276,6 +347,47 @@ impl From<ParseError> for Error {
     }
 }
 
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Error)]
+pub enum SchemaError {
+    #[error("Type \"{type_name}\" is a possible type of \"{name}\", but it is not registered.")]
+    UnregisteredPossibleType { name: String, type_name: String },
+
+    #[error("Field \"{field_name}\" of type \"{type_name}\" is defined more than once.")]
+    DuplicateField {
+        type_name: String,
+        field_name: String,
+    },
+
+    #[error("Field \"{field_name}\" of type \"{type_name}\" can't start with \"__\", it is reserved for introspection.")]
+    ReservedFieldName {
+        type_name: String,
+        field_name: String,
+    },
+
+    #[error("Type \"{type_name}\" flattens \"{flatten_type}\", but it is not a registered object type of the same kind.")]
+    InvalidFlatten {
+        type_name: String,
+        flatten_type: String,
+    },
+
+    #[error("Extension name \"{name}\" is used by more than one extension.")]
+    DuplicateExtensionName { name: String },
+
+    #[error("The validator of \"{name}\" is invalid: {reason}")]
+    InvalidValidator { name: String, reason: String },
+
+    #[error("Type \"{type_name}\" is not a registered object type.")]
+    UnregisteredObjectType { type_name: String },
+
+    #[error("Field \"{field_name}\" of type \"{type_name}\" has type \"{ty}\", but it is not a registered output type.")]
+    InvalidFieldType {
+        type_name: String,
+        field_name: String,
+        ty: String,
+    },
+}
+
 #[allow(missing_docs)]
 #[derive(Debug, Error)]
 pub enum ParseRequestError {
@@ -285,6 +397,9 @@ pub enum ParseRequestError {
     #[error("Invalid request: {0}")]
     InvalidRequest(serde_json::Error),
 
+    #[error("Invalid UTF-8 in request body: {0}")]
+    InvalidUtf8(std::string::FromUtf8Error),
+
     #[error("Invalid files map: {0}")]
     InvalidFilesMap(serde_json::Error),
 
@@ -308,25 +423,91 @@ pub enum ParseRequestError {
 
     #[error("The file size is too large")]
     TooLarge,
+
+    #[error("The request is too large")]
+    RequestTooLarge,
+
+    #[error("Mutations are not allowed over GET requests")]
+    MutationNotAllowedOverGet,
 }
 
 #[allow(missing_docs)]
-#[derive(Debug, Error)]
+#[derive(Debug)]
 pub enum Error {
-    #[error("Parse error: {message}")]
     Parse {
         line: usize,
         column: usize,
         message: String,
     },
 
-    #[error("Query error: {err}")]
     Query {
         pos: Pos,
         path: Option<serde_json::Value>,
         err: QueryError,
     },
 
-    #[error("Rule error")]
-    Rule { errors: Vec<RuleError> },
+    Rule {
+        errors: Vec<RuleError>,
+    },
+}
+
+/// Renders a single line summary of the error, with its kind, position and path, for logging.
+///
+/// This is distinct from the error in the response, see `GQLResponse`.
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Parse error at {}:{}: {}",
+                line,
+                column,
+                message.trim().replace('\n', " ")
+            ),
+            Error::Query { pos, path, err } => {
+                write!(f, "Query error at {}:{}", pos.line, pos.column)?;
+                if let Some(serde_json::Value::Array(path)) = path {
+                    let path = path
+                        .iter()
+                        .map(|segment| match segment {
+                            serde_json::Value::String(s) => s.clone(),
+                            segment => segment.to_string(),
+                        })
+                        .collect::<Vec<_>>();
+                    write!(f, " (path: {})", path.join("."))?;
+                }
+                write!(f, ": {}", err)
+            }
+            Error::Rule { errors } => {
+                write!(f, "Rule error")?;
+                for (idx, err) in errors.iter().enumerate() {
+                    write!(f, "{}", if idx == 0 { ": " } else { "; " })?;
+                    write!(f, "{}", err.message)?;
+                    if let Some(pos) = err.locations.first() {
+                        write!(f, " at {}:{}", pos.line, pos.column)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Query {
+                err:
+                    QueryError::FieldError {
+                        cause: Some(cause), ..
+                    },
+                ..
+            } => Some(cause.as_ref()),
+            _ => None,
+        }
+    }
 }
@@ -1,8 +1,17 @@
 //! Extensions for schema
 
+#[cfg(feature = "chrono")]
+mod request_timing;
+mod response_size;
+#[cfg(feature = "chrono")]
 mod tracing;
 
 use crate::context::QueryPathNode;
+use crate::Variables;
+#[cfg(feature = "chrono")]
+pub use request_timing::RequestTiming;
+pub use response_size::ResponseSize;
+#[cfg(feature = "chrono")]
 pub use tracing::ApolloTracing;
 
 pub(crate) type BoxExtension = Box<dyn Extension>;
@@ -26,12 +35,18 @@ pub struct ResolveInfo<'a> {
 /// Represents a GraphQL extension
 #[allow(unused_variables)]
 pub trait Extension: Sync + Send + 'static {
-    /// Extension name.
+    /// Extension name, the result of the extension is written under this key of the response `extensions`.
+    ///
+    /// Each extension of a schema must have its own name, `SchemaBuilder::try_finish` returns an error if two
+    /// extensions have the same name.
     fn name(&self) -> &'static str;
 
     /// Called at the begin of the parse.
     fn parse_start(&self, query_source: &str) {}
 
+    /// Called after `parse_start`, with the variables exactly as they were received.
+    fn parse_variables(&self, variables: &Variables) {}
+
     /// Called at the end of the parse.
     fn parse_end(&self) {}
 
@@ -47,6 +62,9 @@ pub trait Extension: Sync + Send + 'static {
     /// Called at the end of the execution.
     fn execution_end(&self) {}
 
+    /// Called with the data of the response when the execution is successful.
+    fn execution_data(&self, data: &serde_json::Value) {}
+
     /// Called at the begin of the resolve field.
     fn resolve_field_start(&self, info: &ResolveInfo<'_>) {}
 
@@ -0,0 +1,55 @@
+use crate::extensions::Extension;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+
+struct Inner {
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+}
+
+impl Default for Inner {
+    fn default() -> Self {
+        Self {
+            start_time: Utc::now(),
+            end_time: Utc::now(),
+        }
+    }
+}
+
+/// Request timing extension
+///
+/// It only records the start time, end time and duration of the whole request, which is much cheaper than
+/// `ApolloTracing` that also records every resolver.
+/// The result is written under `requestTiming`, in the Apollo Tracing format with an empty list of resolvers,
+/// so it can be registered together with `ApolloTracing`.
+#[derive(Default)]
+pub struct RequestTiming {
+    inner: Mutex<Inner>,
+}
+
+impl Extension for RequestTiming {
+    fn name(&self) -> &'static str {
+        "requestTiming"
+    }
+
+    fn parse_start(&self, _query_source: &str) {
+        self.inner.lock().start_time = Utc::now();
+    }
+
+    fn execution_end(&self) {
+        self.inner.lock().end_time = Utc::now();
+    }
+
+    fn result(&self) -> Option<serde_json::Value> {
+        let inner = self.inner.lock();
+        Some(serde_json::json!({
+            "version": 1,
+            "startTime": inner.start_time.to_rfc3339(),
+            "endTime": inner.end_time.to_rfc3339(),
+            "duration": (inner.end_time - inner.start_time).num_nanoseconds(),
+            "execution": {
+                "resolvers": []
+            }
+        }))
+    }
+}
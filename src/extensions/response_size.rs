@@ -0,0 +1,38 @@
+use crate::extensions::Extension;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Response size extension
+///
+/// It counts the number of resolved fields and the size in bytes of the serialized `data`, and
+/// adds them to the `extensions` of the response as `{"responseSize": {"nodes": 3, "bytes": 42}}`,
+/// so the application can throttle or bill clients based on the output size. The extension is created
+/// for each query by the schema, the application reads the size from `QueryResponse::extensions`.
+///
+/// The bytes are measured by serializing the data once more, which has a cost for big responses.
+#[derive(Default)]
+pub struct ResponseSize {
+    nodes: AtomicUsize,
+    bytes: AtomicUsize,
+}
+
+impl Extension for ResponseSize {
+    fn name(&self) -> &'static str {
+        "responseSize"
+    }
+
+    fn resolve_field_end(&self, _resolve_id: usize) {
+        self.nodes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn execution_data(&self, data: &serde_json::Value) {
+        let bytes = serde_json::to_vec(data).map(|data| data.len()).unwrap_or(0);
+        self.bytes.store(bytes, Ordering::Relaxed);
+    }
+
+    fn result(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "nodes": self.nodes.load(Ordering::Relaxed),
+            "bytes": self.bytes.load(Ordering::Relaxed),
+        }))
+    }
+}
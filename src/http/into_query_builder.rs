@@ -1,7 +1,7 @@
 use crate::http::multipart::{Multipart, PartData};
 use crate::http::GQLRequest;
 use crate::query::{IntoQueryBuilder, IntoQueryBuilderOpts};
-use crate::{ParseRequestError, QueryBuilder};
+use crate::{BatchQueryBuilder, ParseRequestError, QueryBuilder};
 use futures::{AsyncRead, AsyncReadExt};
 use mime::Mime;
 use std::collections::HashMap;
@@ -13,85 +13,231 @@ where
     Body: AsyncRead + Send + Unpin,
 {
     async fn into_query_builder_opts(
-        mut self,
+        self,
         opts: &IntoQueryBuilderOpts,
     ) -> std::result::Result<QueryBuilder, ParseRequestError> {
-        if let Some(boundary) = self
-            .0
-            .and_then(|value| value.as_ref().parse::<Mime>().ok())
-            .and_then(|ct| {
-                if ct.essence_str() == mime::MULTIPART_FORM_DATA {
-                    ct.get_param("boundary")
-                        .map(|boundary| boundary.to_string())
-                } else {
-                    None
-                }
-            })
-        {
-            // multipart
-            let mut multipart = Multipart::parse(
-                self.1,
-                boundary.as_str(),
-                opts.temp_dir.as_deref(),
-                opts.max_file_size,
-                opts.max_num_files,
-            )
-            .await?;
-            let gql_request: GQLRequest = {
-                let part = multipart
-                    .remove("operations")
-                    .ok_or_else(|| ParseRequestError::MissingOperatorsPart)?;
-                let reader = part.create_reader()?;
-                serde_json::from_reader(reader).map_err(ParseRequestError::InvalidRequest)?
-            };
-            let mut map: HashMap<String, Vec<String>> = {
-                let part = multipart
-                    .remove("map")
-                    .ok_or_else(|| ParseRequestError::MissingMapPart)?;
-                let reader = part.create_reader()?;
-                serde_json::from_reader(reader).map_err(ParseRequestError::InvalidFilesMap)?
-            };
-
-            let mut builder = gql_request.into_query_builder().await?;
-
-            // read files
-            for part in &multipart.parts {
-                if let Some(name) = &part.name {
-                    if let Some(var_paths) = map.remove(name) {
-                        for var_path in var_paths {
-                            if let (Some(filename), PartData::File(path)) =
-                                (&part.filename, &part.data)
-                            {
-                                builder.set_upload(
-                                    &var_path,
-                                    &filename,
-                                    part.content_type.as_deref(),
-                                    path,
-                                );
-                            }
+        let mut builders = parse_request(self.0, self.1, opts, false).await?;
+        Ok(builders.remove(0))
+    }
+
+    async fn into_batch_query_builder_opts(
+        self,
+        opts: &IntoQueryBuilderOpts,
+    ) -> std::result::Result<BatchQueryBuilder, ParseRequestError> {
+        Ok(BatchQueryBuilder(
+            parse_request(self.0, self.1, opts, true).await?,
+        ))
+    }
+}
+
+/// Parses a request, it returns a single query builder unless `batch` is true and the body is a JSON array.
+async fn parse_request<CT, Body>(
+    content_type: Option<CT>,
+    body: Body,
+    opts: &IntoQueryBuilderOpts,
+    batch: bool,
+) -> std::result::Result<Vec<QueryBuilder>, ParseRequestError>
+where
+    CT: AsRef<str> + Send,
+    Body: AsyncRead + Send + Unpin,
+{
+    // read one more byte than the maximum size to know if the body is larger
+    let mut body = body.take(
+        opts.max_request_size
+            .map(|size| (size as u64).saturating_add(1))
+            .unwrap_or(std::u64::MAX),
+    );
+    let content_type = content_type.and_then(|value| value.as_ref().parse::<Mime>().ok());
+    if let Some(boundary) = content_type.as_ref().and_then(|ct| {
+        if ct.essence_str() == mime::MULTIPART_FORM_DATA {
+            ct.get_param("boundary")
+                .map(|boundary| boundary.to_string())
+        } else {
+            None
+        }
+    }) {
+        // multipart
+        let multipart = Multipart::parse(
+            &mut body,
+            boundary.as_str(),
+            opts.temp_dir.as_deref(),
+            opts.max_file_size,
+            opts.max_num_files,
+        )
+        .await;
+        if body.limit() == 0 {
+            return Err(ParseRequestError::RequestTooLarge);
+        }
+        let mut multipart = multipart?;
+        let gql_request: GQLRequest = {
+            let part = multipart
+                .remove("operations")
+                .ok_or_else(|| ParseRequestError::MissingOperatorsPart)?;
+            let reader = part.create_reader()?;
+            serde_json::from_reader(reader).map_err(ParseRequestError::InvalidRequest)?
+        };
+        let mut map: HashMap<String, Vec<String>> = {
+            let part = multipart
+                .remove("map")
+                .ok_or_else(|| ParseRequestError::MissingMapPart)?;
+            let reader = part.create_reader()?;
+            serde_json::from_reader(reader).map_err(ParseRequestError::InvalidFilesMap)?
+        };
+
+        let mut builder = gql_request.into_query_builder().await?;
+
+        // read files
+        for part in &multipart.parts {
+            if let Some(name) = &part.name {
+                if let Some(var_paths) = map.remove(name) {
+                    for var_path in var_paths {
+                        if let (Some(filename), PartData::File(path)) = (&part.filename, &part.data)
+                        {
+                            builder.set_upload(
+                                &var_path,
+                                &filename,
+                                part.content_type.as_deref(),
+                                path,
+                            );
                         }
                     }
                 }
             }
+        }
 
-            if !map.is_empty() {
-                return Err(ParseRequestError::MissingFiles);
-            }
+        if !map.is_empty() {
+            return Err(ParseRequestError::MissingFiles);
+        }
 
-            if let Some(temp_dir) = multipart.temp_dir {
-                builder.set_files_holder(temp_dir);
-            }
+        if let Some(temp_dir) = multipart.temp_dir {
+            builder.set_files_holder(temp_dir);
+        }
 
-            Ok(builder)
-        } else {
-            let mut data = Vec::new();
-            self.1
-                .read_to_end(&mut data)
-                .await
-                .map_err(ParseRequestError::Io)?;
-            let gql_request: GQLRequest =
+        Ok(vec![builder])
+    } else {
+        let mut data = Vec::new();
+        body.read_to_end(&mut data)
+            .await
+            .map_err(ParseRequestError::Io)?;
+        if body.limit() == 0 {
+            return Err(ParseRequestError::RequestTooLarge);
+        }
+        if content_type.as_ref().map(|ct| ct.essence_str()) == Some("application/graphql") {
+            // the whole body is the query
+            let query = String::from_utf8(data).map_err(ParseRequestError::InvalidUtf8)?;
+            return Ok(vec![QueryBuilder::new(query)]);
+        }
+        if batch && data.iter().find(|c| !c.is_ascii_whitespace()) == Some(&b'[') {
+            let gql_requests: Vec<GQLRequest> =
                 serde_json::from_slice(&data).map_err(ParseRequestError::InvalidRequest)?;
-            gql_request.into_query_builder().await
+            let mut builders = Vec::with_capacity(gql_requests.len());
+            for gql_request in gql_requests {
+                builders.push(gql_request.into_query_builder().await?);
+            }
+            return Ok(builders);
         }
+        let gql_request: GQLRequest =
+            serde_json::from_slice(&data).map_err(ParseRequestError::InvalidRequest)?;
+        Ok(vec![gql_request.into_query_builder().await?])
+    }
+}
+
+/// Builds a query builder from any `http::Request`, such as the requests of hyper-based frameworks.
+///
+/// The `Content-Type` header and the body are handled like the `(Option<content type>, body)` tuple.
+#[async_trait::async_trait]
+impl<Body> IntoQueryBuilder for http::Request<Body>
+where
+    Body: AsyncRead + Send + Unpin,
+{
+    async fn into_query_builder_opts(
+        self,
+        opts: &IntoQueryBuilderOpts,
+    ) -> std::result::Result<QueryBuilder, ParseRequestError> {
+        let content_type = self
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string);
+        (content_type, self.into_body())
+            .into_query_builder_opts(opts)
+            .await
+    }
+
+    async fn into_batch_query_builder_opts(
+        self,
+        opts: &IntoQueryBuilderOpts,
+    ) -> std::result::Result<BatchQueryBuilder, ParseRequestError> {
+        let content_type = self
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string);
+        (content_type, self.into_body())
+            .into_batch_query_builder_opts(opts)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[async_std::test]
+    async fn test_http_request() {
+        let body: &[u8] = br#"{"query": "{ a }", "operationName": "A"}"#;
+        let request = http::Request::builder()
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .unwrap();
+        let builder = request.into_query_builder().await.unwrap();
+        assert_eq!(builder.query_source, "{ a }");
+        assert_eq!(builder.operation_name.as_deref(), Some("A"));
+    }
+
+    #[async_std::test]
+    async fn test_batch_request() {
+        let body: &[u8] = br#" [{"query": "{ a }"}, {"query": "{ b }", "operationName": "B"}]"#;
+        let builders = (Some("application/json"), body)
+            .into_batch_query_builder()
+            .await
+            .unwrap();
+        assert_eq!(builders.len(), 2);
+        assert_eq!(builders[0].query_source, "{ a }");
+        assert_eq!(builders[1].query_source, "{ b }");
+        assert_eq!(builders[1].operation_name.as_deref(), Some("B"));
+
+        let body: &[u8] = br#"{"query": "{ a }"}"#;
+        let builders = (Some("application/json"), body)
+            .into_batch_query_builder()
+            .await
+            .unwrap();
+        assert_eq!(builders.len(), 1);
+        assert_eq!(builders[0].query_source, "{ a }");
+
+        let body: &[u8] = br#"[{"query": "{ a }"}]"#;
+        assert!((Some("application/json"), body)
+            .into_query_builder()
+            .await
+            .is_err());
+    }
+
+    #[async_std::test]
+    async fn test_graphql_body() {
+        let body: &[u8] = b"query A { a }";
+        let builder = (Some("application/graphql"), body)
+            .into_query_builder()
+            .await
+            .unwrap();
+        assert_eq!(builder.query_source, "query A { a }");
+        assert!(builder.operation_name.is_none());
+
+        let body: &[u8] = b"{ \xff }";
+        let err = (Some("application/graphql; charset=utf-8"), body)
+            .into_query_builder()
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(err, ParseRequestError::InvalidUtf8(_)));
     }
 }
@@ -22,7 +22,8 @@ use serde::{Serialize, Serializer};
 /// Deserializable GraphQL Request object
 #[derive(Deserialize, Clone, PartialEq, Debug)]
 pub struct GQLRequest {
-    /// Query source
+    /// Query source, can be omitted if the query is persisted, see `SchemaBuilder::persisted_query_cache`
+    #[serde(default)]
     pub query: String,
 
     /// Operation name for this query
@@ -31,6 +32,9 @@ pub struct GQLRequest {
 
     /// Variables for this query
     pub variables: Option<serde_json::Value>,
+
+    /// Extensions for this query, such as the persisted query hash or custom metadata
+    pub extensions: Option<serde_json::Value>,
 }
 
 #[async_trait::async_trait]
@@ -48,13 +52,125 @@ impl IntoQueryBuilder for GQLRequest {
                 builder = builder.variables(variables);
             }
         }
+        if let Some(extensions) = self.extensions {
+            builder = builder.extensions(extensions);
+        }
         Ok(builder)
     }
 }
 
+/// Media type of the GraphQL response defined by the GraphQL over HTTP specification
+pub const GRAPHQL_RESPONSE_MEDIA_TYPE: &str = "application/graphql-response+json";
+
+/// How a GraphQL response is sent over HTTP
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GQLResponseMode {
+    /// `application/json`, always responds with status code 200.
+    Json,
+
+    /// `application/graphql-response+json` as defined by the GraphQL over HTTP specification.
+    ///
+    /// Request errors (parse errors, validation errors and invalid variables) respond with status code 400 and
+    /// no `data`. Field errors respond with status code 200, with `"data": null` and the errors.
+    GraphQLResponse,
+}
+
+impl Default for GQLResponseMode {
+    fn default() -> Self {
+        GQLResponseMode::Json
+    }
+}
+
+impl GQLResponseMode {
+    /// Negotiates the mode from the `Accept` header of the request.
+    ///
+    /// `GraphQLResponse` is used if the client accepts `application/graphql-response+json`, otherwise `Json`.
+    pub fn from_accept(accept: Option<&str>) -> Self {
+        let accepts_graphql_response = accept
+            .map(|accept| {
+                accept.split(',').any(|media_type| {
+                    media_type.split(';').next().unwrap_or_default().trim()
+                        == GRAPHQL_RESPONSE_MEDIA_TYPE
+                })
+            })
+            .unwrap_or_default();
+        if accepts_graphql_response {
+            GQLResponseMode::GraphQLResponse
+        } else {
+            GQLResponseMode::Json
+        }
+    }
+}
+
 /// Serializable GraphQL Response object
 pub struct GQLResponse(pub Result<QueryResponse>);
 
+impl GQLResponse {
+    /// Sends this response over HTTP in the specified mode.
+    pub fn with_mode(self, mode: GQLResponseMode) -> GQLHttpResponse {
+        GQLHttpResponse {
+            response: self,
+            mode,
+        }
+    }
+}
+
+/// Serializable GraphQL Response object, with the mode it is sent over HTTP in
+pub struct GQLHttpResponse {
+    /// The response
+    pub response: GQLResponse,
+
+    /// How the response is sent over HTTP
+    pub mode: GQLResponseMode,
+}
+
+impl GQLHttpResponse {
+    /// Returns the `Content-Type` of this response.
+    pub fn content_type(&self) -> &'static str {
+        match self.mode {
+            GQLResponseMode::Json => "application/json",
+            GQLResponseMode::GraphQLResponse => GRAPHQL_RESPONSE_MEDIA_TYPE,
+        }
+    }
+
+    /// Returns the HTTP status code of this response.
+    pub fn status_code(&self) -> u16 {
+        if self.mode == GQLResponseMode::Json || self.has_data() {
+            200
+        } else {
+            400
+        }
+    }
+
+    /// Returns `true` if the response has `data`, a field error responds with `"data": null`.
+    fn has_data(&self) -> bool {
+        match &self.response.0 {
+            Ok(_)
+            | Err(Error::Query {
+                err: QueryError::FieldError { .. },
+                ..
+            }) => true,
+            Err(_) => false,
+        }
+    }
+}
+
+impl Serialize for GQLHttpResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match &self.response.0 {
+            Err(err) if self.mode == GQLResponseMode::GraphQLResponse && self.has_data() => {
+                let mut map = serializer.serialize_map(None)?;
+                map.serialize_key("data")?;
+                map.serialize_value(&serde_json::Value::Null)?;
+                map.serialize_key("errors")?;
+                map.serialize_value(&GQLError(err))?;
+                map.end()
+            }
+            _ => self.response.serialize(serializer),
+        }
+    }
+}
+
 impl Serialize for GQLResponse {
     fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
         match &self.0 {
@@ -62,6 +178,10 @@ impl Serialize for GQLResponse {
                 let mut map = serializer.serialize_map(None)?;
                 map.serialize_key("data")?;
                 map.serialize_value(&res.data)?;
+                if !res.errors.is_empty() {
+                    map.serialize_key("errors")?;
+                    map.serialize_value(&GQLErrors(&res.errors))?;
+                }
                 if res.extensions.is_some() {
                     map.serialize_key("extensions")?;
                     map.serialize_value(&res.extensions)?;
@@ -78,6 +198,52 @@ impl Serialize for GQLResponse {
     }
 }
 
+/// Serializable response of a batch request
+///
+/// The queries of a batch are executed independently, and their responses are serialized as a JSON array
+/// in the same order.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::http::{GQLBatchResponse, GQLResponse};
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     #[field]
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// #[async_std::main]
+/// async fn main() {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let body: &[u8] = br#"[{"query": "{ value }"}, {"query": "{ a: value }"}]"#;
+///     let builders = (Some("application/json"), body).into_batch_query_builder().await.unwrap();
+///     let responses = builders.execute(&schema).await;
+///     let resp = GQLBatchResponse(responses.into_iter().map(GQLResponse).collect());
+///     assert_eq!(
+///         serde_json::to_value(&resp).unwrap(),
+///         serde_json::json!([{ "data": { "value": 10 } }, { "data": { "a": 10 } }])
+///     );
+/// }
+/// ```
+pub struct GQLBatchResponse(pub Vec<GQLResponse>);
+
+impl Serialize for GQLBatchResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for resp in &self.0 {
+            seq.serialize_element(resp)?;
+        }
+        seq.end()
+    }
+}
+
 /// Serializable error type
 pub struct GQLError<'a>(pub &'a Error);
 
@@ -104,6 +270,7 @@ impl<'a> Serialize for GQLError<'a> {
                 if let QueryError::FieldError {
                     err,
                     extended_error,
+                    ..
                 } = err
                 {
                     let mut map = serde_json::Map::new();
@@ -145,6 +312,25 @@ impl<'a> Serialize for GQLError<'a> {
     }
 }
 
+struct GQLErrors<'a>(&'a [Error]);
+
+impl<'a> Serialize for GQLErrors<'a> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(None)?;
+        for err in self.0 {
+            if let Ok(serde_json::Value::Array(errors)) = serde_json::to_value(GQLError(err)) {
+                for error in errors {
+                    seq.serialize_element(&error)?;
+                }
+            }
+        }
+        seq.end()
+    }
+}
+
 struct GQLErrorPos<'a>(&'a Pos);
 
 impl<'a> Serialize for GQLErrorPos<'a> {
@@ -162,6 +348,7 @@ impl<'a> Serialize for GQLErrorPos<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::OperationType;
     use graphql_parser::Pos;
     use serde_json::json;
 
@@ -173,6 +360,20 @@ mod tests {
         .unwrap();
         assert!(request.variables.is_none());
         assert!(request.operation_name.is_none());
+        assert!(request.extensions.is_none());
+        assert_eq!(request.query, "{ a b c }");
+    }
+
+    #[test]
+    fn test_request_with_extensions() {
+        let request: GQLRequest = serde_json::from_value(json! ({
+            "query": "{ a b c }",
+            "extensions": {
+                "traceId": "abc"
+            }
+        }))
+        .unwrap();
+        assert_eq!(request.extensions, Some(json!({ "traceId": "abc" })));
         assert_eq!(request.query, "{ a b c }");
     }
 
@@ -214,9 +415,13 @@ mod tests {
     #[test]
     fn test_response_data() {
         let resp = GQLResponse(Ok(QueryResponse {
+            operation_name: None,
+            operation_type: OperationType::Query,
             data: json!({"ok": true}),
             extensions: None,
             cache_control: Default::default(),
+            errors: Vec::new(),
+            canonical_query: None,
         }));
         assert_eq!(
             serde_json::to_value(resp).unwrap(),
@@ -241,6 +446,7 @@ mod tests {
                 extended_error: Some(json!({
                     "code": "MY_TEST_CODE"
                 })),
+                cause: None,
             },
         };
 
@@ -260,6 +466,96 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_response_mode() {
+        let ok = || {
+            GQLResponse(Ok(QueryResponse {
+                operation_name: None,
+                operation_type: OperationType::Query,
+                data: json!({"ok": true}),
+                extensions: None,
+                cache_control: Default::default(),
+                errors: Vec::new(),
+                canonical_query: None,
+            }))
+        };
+        let field_error = || {
+            GQLResponse(Err(Error::Query {
+                pos: Pos { line: 1, column: 3 },
+                path: Some(json!(["value"])),
+                err: QueryError::FieldError {
+                    err: "MyErrorMessage".to_owned(),
+                    extended_error: None,
+                    cause: None,
+                },
+            }))
+        };
+        let parse_error = || {
+            GQLResponse(Err(Error::Parse {
+                line: 1,
+                column: 1,
+                message: "Unexpected end of input".to_owned(),
+            }))
+        };
+        let rule_error = || GQLResponse(Err(Error::Rule { errors: Vec::new() }));
+        let var_error = || {
+            GQLResponse(Err(Error::Query {
+                pos: Pos { line: 1, column: 3 },
+                path: None,
+                err: QueryError::VarNotDefined {
+                    var_name: "id".to_owned(),
+                },
+            }))
+        };
+        let json = GQLResponseMode::Json;
+        let spec = GQLResponseMode::GraphQLResponse;
+
+        assert_eq!(ok().with_mode(json).content_type(), "application/json");
+        assert_eq!(
+            ok().with_mode(spec).content_type(),
+            "application/graphql-response+json"
+        );
+
+        assert_eq!(ok().with_mode(json).status_code(), 200);
+        assert_eq!(field_error().with_mode(json).status_code(), 200);
+        assert_eq!(parse_error().with_mode(json).status_code(), 200);
+
+        assert_eq!(ok().with_mode(spec).status_code(), 200);
+        assert_eq!(field_error().with_mode(spec).status_code(), 200);
+        assert_eq!(parse_error().with_mode(spec).status_code(), 400);
+        assert_eq!(rule_error().with_mode(spec).status_code(), 400);
+        assert_eq!(var_error().with_mode(spec).status_code(), 400);
+
+        assert_eq!(
+            serde_json::to_value(ok().with_mode(spec)).unwrap(),
+            json!({ "data": { "ok": true } })
+        );
+        assert_eq!(
+            serde_json::to_value(field_error().with_mode(spec)).unwrap(),
+            json!({
+                "data": null,
+                "errors": [{
+                    "message": "MyErrorMessage",
+                    "locations": [{"line": 1, "column": 3}],
+                    "path": ["value"]
+                }]
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(field_error().with_mode(json)).unwrap(),
+            serde_json::to_value(field_error()).unwrap()
+        );
+
+        assert_eq!(GQLResponseMode::from_accept(None), json);
+        assert_eq!(GQLResponseMode::from_accept(Some("application/json")), json);
+        assert_eq!(
+            GQLResponseMode::from_accept(Some(
+                "application/graphql-response+json;charset=utf-8, application/json;q=0.9"
+            )),
+            spec
+        );
+    }
+
     #[test]
     fn test_response_error_with_pos() {
         let resp = GQLResponse(Err(Error::Query {
@@ -81,15 +81,22 @@ mod context;
 mod error;
 mod model;
 mod mutation_resolver;
+mod plan;
 mod query;
 mod resolver;
 mod scalars;
 mod schema;
+mod siblings;
 mod subscription;
 mod types;
 mod validation;
+mod value;
 
 pub mod extensions;
+pub mod middleware;
+pub mod persisted_query;
+pub mod query_cache;
+pub mod transformers;
 pub mod validators;
 
 #[doc(hidden)]
@@ -110,23 +117,33 @@ pub use context::{
     Context, ContextBase, Data, Environment, QueryPathNode, QueryPathSegment, Variables,
 };
 pub use error::{
-    Error, ErrorExtensions, FieldError, FieldResult, ParseRequestError, QueryError, ResultExt,
+    Error, ErrorExtensions, FieldError, FieldErrorSource, FieldResult, ParseRequestError,
+    QueryError, ResultExt, SchemaError,
 };
 pub use graphql_parser::query::Value;
 pub use graphql_parser::Pos;
-pub use query::{IntoQueryBuilder, IntoQueryBuilderOpts, QueryBuilder, QueryResponse};
-pub use registry::CacheControl;
-pub use scalars::{Any, ID};
+pub use model::__DirectiveLocation as DirectiveLocation;
+pub use plan::{ExecutionPlan, PlanField};
+pub use query::{
+    query_hash, BatchQueryBuilder, IntoQueryBuilder, IntoQueryBuilderOpts, OperationType,
+    QueryBuilder, QueryResponse,
+};
+pub use registry::{CacheControl, Directive};
+#[cfg(feature = "coordinates")]
+pub use scalars::Coordinates;
+pub use scalars::{Any, Base64, UnixTimestamp, UnixTimestampMillis, ID};
 pub use schema::Schema;
 pub use subscription::{
     SimpleBroker, SubscriptionStream, SubscriptionStreams, SubscriptionTransport,
     WebSocketTransport,
 };
 pub use types::{
-    Connection, DataSource, EmptyEdgeFields, EmptyMutation, EmptySubscription, QueryOperation,
-    Upload,
+    Connection, ConnectionNameType, CursorSigningKey, DataSource, DefaultConnectionName,
+    DefaultEdgeName, EdgeNameType, EmptyEdgeFields, EmptyMutation, EmptySubscription, ListIter,
+    QueryOperation, Upload,
 };
 pub use validation::ValidationMode;
+pub use value::{merge_value, parse_value};
 
 /// Result type, are actually `anyhow::Result<T>`
 pub type Result<T> = std::result::Result<T, Error>;
@@ -139,7 +156,7 @@ pub mod registry;
 #[doc(hidden)]
 pub use base::{BoxFieldFuture, InputObjectType, InputValueType, ObjectType, OutputValueType};
 #[doc(hidden)]
-pub use resolver::{collect_fields, do_resolve};
+pub use resolver::{collect_fields, do_resolve, Fields};
 #[doc(hidden)]
 pub use subscription::SubscriptionType;
 #[doc(hidden)]
@@ -155,6 +172,7 @@ pub use types::{EnumItem, EnumType};
 /// | desc          | Object description        | string   | Y        |
 /// | cache_control | Object cache control      | [`CacheControl`](struct.CacheControl.html) | Y        |
 /// | extends       | Add fields to an entity that's defined in another service | bool | Y |
+/// | rename_all    | Naming convention of the fields and arguments without a `name`, one of `camelCase` (the default), `snake_case`, `SCREAMING_SNAKE_CASE` and `PascalCase` | string | Y |
 ///
 /// # Field parameters
 ///
@@ -167,6 +185,7 @@ pub use types::{EnumItem, EnumType};
 /// | external      | Mark a field as owned by another service. This allows service A to use fields from service B while also knowing at runtime the types of that field. | bool | Y |
 /// | provides      | Annotate the expected returned fieldset from a field on a base type that is guaranteed to be selectable by the gateway. | string | Y |
 /// | requires      | Annotate the required input fieldset from a base type for a resolver. It is used to develop a query plan where the required fields may not be needed by the client, but the service may need additional information from other services. | string | Y |
+/// | complexity    | Expression of the complexity of the field, such as `"child_complexity * first as usize"`. The arguments of the field can be used with their Rust types, and `child_complexity` is the `usize` complexity of its selection set. The default is `1 + child_complexity`. | string | Y |
 ///
 /// # Field argument parameters
 ///
@@ -187,6 +206,7 @@ pub use types::{EnumItem, EnumType};
 /// - Object and &Object
 /// - Enum
 /// - FieldResult<T, E>, such as `FieldResult<i32, E>`
+/// - Vec<FieldResult<T>>, such as `Vec<FieldResult<i32>>`, a failed element resolves to `null` and its error is added to the response
 ///
 /// # Context
 ///
@@ -255,6 +275,7 @@ pub use async_graphql_derive::Object;
 /// Define a GraphQL object
 ///
 /// Similar to `Object`, but defined on a structure that automatically generates getters for all fields.
+/// A field of type `FieldResult<T>` has the GraphQL type of `T`, and resolves to a field error if it holds an error.
 ///
 /// # Macro parameters
 ///
@@ -263,6 +284,7 @@ pub use async_graphql_derive::Object;
 /// | name          | Object name               | string   | Y        |
 /// | desc          | Object description        | string   | Y        |
 /// | cache_control | Object cache control      | [`CacheControl`](struct.CacheControl.html) | Y        |
+/// | rename_all    | Naming convention of the fields without a `name`, one of `camelCase` (the default), `snake_case`, `SCREAMING_SNAKE_CASE` and `PascalCase` | string | Y |
 ///
 /// # Field parameters
 ///
@@ -272,6 +294,8 @@ pub use async_graphql_derive::Object;
 /// | desc          | Field description         | string   | Y        |
 /// | deprecation   | Field deprecation reason  | string   | Y        |
 /// | cache_control | Field cache control       | [`CacheControl`](struct.CacheControl.html) | Y        |
+/// | complexity    | Expression of the complexity of the field, `child_complexity` is the `usize` complexity of its selection set. The default is `1 + child_complexity`. | string | Y |
+/// | flatten       | Merge the fields of a nested object into this object, the field must be an object and can't have other attributes. The fields of the nested object are only known when the schema is built, so a field name defined by both objects is reported by `SchemaBuilder::try_finish`, not at compile time | bool | Y |
 ///
 /// # Examples
 ///
@@ -303,6 +327,7 @@ pub use async_graphql_derive::SimpleObject;
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Enum name                 | string   | Y        |
 /// | desc        | Enum description          | string   | Y        |
+/// | rename_items | Naming convention of the items without a `name`, one of `SCREAMING_SNAKE_CASE` (the default), `camelCase`, `snake_case` and `PascalCase` | string | Y |
 ///
 /// # Item parameters
 ///
@@ -359,6 +384,8 @@ pub use async_graphql_derive::Enum;
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Object name               | string   | Y        |
 /// | desc        | Object description        | string   | Y        |
+/// | oneof       | Exactly one field must be provided, all fields should be `Option<T>` | bool     | Y        |
+/// | rename_all  | Naming convention of the fields without a `name`, one of `camelCase` (the default), `snake_case`, `SCREAMING_SNAKE_CASE` and `PascalCase` | string | Y |
 ///
 /// # Field parameters
 ///
@@ -368,6 +395,8 @@ pub use async_graphql_derive::Enum;
 /// | desc        | Field description         | string   | Y        |
 /// | default     | Field default value       | string   | Y        |
 /// | validator   | Input value validator     | [`InputValueValidator`](validators/trait.InputValueValidator.html) | Y        |
+/// | transformer | Input value transformer, applied to the provided or default value before it is validated and parsed | [`InputValueTransformer`](transformers/trait.InputValueTransformer.html) | Y        |
+/// | flatten     | Merge the fields of a nested input object into this object, the field must be an input object and can't have other attributes. A field name defined by both objects is reported by `SchemaBuilder::try_finish` | bool     | Y        |
 ///
 /// # Examples
 ///
@@ -530,12 +559,16 @@ pub use async_graphql_derive::Union;
 /// Starting with the third parameter is one or more filtering conditions, The filter condition is the parameter of the field.
 /// The filter function should be synchronous.
 ///
+/// The `Context` of a field gives access to the global data of the `Schema` and the data created from the init payload
+/// of the connection (see `WebSocketTransport::new`), clone what the filter needs into the stream to authorize each message.
+///
 /// # Macro parameters
 ///
 /// | Attribute   | description               | Type     | Optional |
 /// |-------------|---------------------------|----------|----------|
 /// | name        | Object name               | string   | Y        |
 /// | desc        | Object description        | string   | Y        |
+/// | rename_all  | Naming convention of the fields and arguments without a `name`, one of `camelCase` (the default), `snake_case`, `SCREAMING_SNAKE_CASE` and `PascalCase` | string | Y |
 ///
 /// # Field parameters
 ///
@@ -577,5 +610,45 @@ pub use async_graphql_derive::Union;
 /// ```
 pub use async_graphql_derive::Subscription;
 
+/// Define a GraphQL scalar that wraps an existing scalar
+///
+/// It works on a tuple struct with exactly one field, parsing and serialization are delegated to the inner scalar,
+/// so you don't need to implement `Scalar` by hand.
+///
+/// # Macro parameters
+///
+/// | Attribute   | description               | Type     | Optional |
+/// |-------------|---------------------------|----------|----------|
+/// | name        | Scalar name, default is the name of the struct | string   | Y        |
+/// | desc        | Scalar description        | string   | Y        |
+/// | transparent | Use the name and description of the inner scalar | bool     | Y        |
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[NewtypeScalar]
+/// struct UserId(i32);
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     #[field]
+///     async fn user_id(&self, id: UserId) -> UserId {
+///         id
+///     }
+/// }
+///
+/// #[async_std::main]
+/// async fn main() {
+///     let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+///     let res = schema.execute("{ userId(id: 10) }").await.unwrap().data;
+///     assert_eq!(res, serde_json::json!({ "userId": 10 }));
+/// }
+/// ```
+pub use async_graphql_derive::NewtypeScalar;
+
 /// Define a DataSource
 pub use async_graphql_derive::DataSource;
@@ -0,0 +1,70 @@
+//! Field middlewares
+//!
+//! Guards can only reject a field and extensions can only observe the resolution, a middleware wraps the
+//! resolution of every field so it can change the result, e.g. to retry a resolver, return a fallback value
+//! or mask a value.
+
+use crate::{Context, Result};
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+pub(crate) type BoxMiddleware = Arc<dyn Middleware>;
+
+pub(crate) type FieldResolveFn<'a> =
+    dyn Fn() -> BoxFuture<'a, Result<serde_json::Value>> + Send + Sync + 'a;
+
+/// The rest of the middleware chain, ending with the resolver of the field
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    middlewares: &'a [BoxMiddleware],
+    resolver: &'a FieldResolveFn<'a>,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(middlewares: &'a [BoxMiddleware], resolver: &'a FieldResolveFn<'a>) -> Self {
+        Next {
+            middlewares,
+            resolver,
+        }
+    }
+
+    /// Calls the next middleware, or the resolver of the field if this is the last one.
+    ///
+    /// It can be called more than once, each call resolves the field again.
+    pub async fn run(&self, ctx: &Context<'_>) -> Result<serde_json::Value> {
+        match self.middlewares.split_first() {
+            Some((middleware, middlewares)) => {
+                middleware
+                    .resolve(ctx, Next::new(middlewares, self.resolver))
+                    .await
+            }
+            None => (self.resolver)().await,
+        }
+    }
+}
+
+/// Represents a field middleware
+///
+/// Middlewares are registered with `SchemaBuilder::middleware`, and are called in the order they were registered
+/// for every field of the queries and mutations.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::middleware::{Middleware, Next};
+///
+/// struct Fallback;
+///
+/// #[async_trait::async_trait]
+/// impl Middleware for Fallback {
+///     async fn resolve(&self, ctx: &Context<'_>, next: Next<'_>) -> Result<serde_json::Value> {
+///         next.run(ctx).await.or_else(|_| Ok(serde_json::Value::Null))
+///     }
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait Middleware: Send + Sync + 'static {
+    /// Resolves the field, call `next.run(ctx)` to continue with the rest of the chain.
+    async fn resolve(&self, ctx: &Context<'_>, next: Next<'_>) -> Result<serde_json::Value>;
+}
@@ -7,7 +7,7 @@ use async_graphql_derive::{Enum, Object};
     desc = "A Directive can be adjacent to many parts of the GraphQL language, a __DirectiveLocation describes one such possible adjacencies."
 )]
 #[derive(Debug)]
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, missing_docs)]
 pub enum __DirectiveLocation {
     #[item(desc = "Location adjacent to a query operation.")]
     QUERY,
@@ -94,6 +94,11 @@ impl<'a> __Directive<'a> {
         &self.directive.locations
     }
 
+    #[field]
+    async fn is_repeatable(&self) -> bool {
+        self.directive.is_repeatable
+    }
+
     #[field]
     async fn args(&self) -> Vec<__InputValue<'a>> {
         self.directive
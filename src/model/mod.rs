@@ -2,6 +2,7 @@ mod directive;
 mod enum_value;
 mod field;
 mod input_value;
+mod introspection_query;
 mod kind;
 mod schema;
 mod r#type;
@@ -10,6 +11,7 @@ pub use directive::{__Directive, __DirectiveLocation};
 pub use enum_value::__EnumValue;
 pub use field::__Field;
 pub use input_value::__InputValue;
+pub use introspection_query::INTROSPECTION_QUERY;
 pub use kind::__TypeKind;
 pub use r#type::__Type;
 pub use schema::__Schema;
@@ -163,16 +163,16 @@ impl<'a> __Type<'a> {
         #[arg(default = "false")] include_deprecated: bool,
     ) -> Option<Vec<__EnumValue<'a>>> {
         if let TypeDetail::Named(registry::Type::Enum { enum_values, .. }) = &self.detail {
-            Some(
-                enum_values
-                    .values()
-                    .filter(|field| include_deprecated || field.deprecation.is_none())
-                    .map(|value| __EnumValue {
-                        registry: self.registry,
-                        value,
-                    })
-                    .collect(),
-            )
+            let mut enum_values = enum_values
+                .values()
+                .filter(|value| include_deprecated || value.deprecation.is_none())
+                .map(|value| __EnumValue {
+                    registry: self.registry,
+                    value,
+                })
+                .collect_vec();
+            enum_values.sort_by(|a, b| a.value.name.cmp(b.value.name));
+            Some(enum_values)
         } else {
             None
         }
@@ -181,15 +181,24 @@ impl<'a> __Type<'a> {
     #[field]
     async fn input_fields(&self) -> Option<Vec<__InputValue<'a>>> {
         if let TypeDetail::Named(registry::Type::InputObject { input_fields, .. }) = &self.detail {
-            Some(
-                input_fields
-                    .values()
-                    .map(|input_value| __InputValue {
-                        registry: self.registry,
-                        input_value,
-                    })
-                    .collect(),
-            )
+            let mut input_fields = input_fields
+                .values()
+                .map(|input_value| __InputValue {
+                    registry: self.registry,
+                    input_value,
+                })
+                .collect_vec();
+            input_fields.sort_by(|a, b| a.input_value.name.cmp(b.input_value.name));
+            Some(input_fields)
+        } else {
+            None
+        }
+    }
+
+    #[field]
+    async fn is_one_of(&self) -> Option<bool> {
+        if let TypeDetail::Named(registry::Type::InputObject { oneof, .. }) = &self.detail {
+            Some(*oneof)
         } else {
             None
         }
@@ -0,0 +1,66 @@
+//! Automatic persisted queries
+//!
+//! **Reference:** <https://www.apollographql.com/docs/apollo-server/performance/apq/>
+//!
+//! Clients send the SHA-256 hash of the query in `extensions.persistedQuery.sha256Hash` instead of the query source.
+//! If the hash is unknown, the query fails with `QueryError::PersistedQueryNotFound`, and the client sends the hash
+//! again with the query source, which is stored in the cache and executed.
+
+use lru::LruCache;
+use parking_lot::Mutex;
+
+/// Storage of the persisted queries, keyed by the hash of the query
+///
+/// It is registered with `SchemaBuilder::persisted_query_cache`.
+#[async_trait::async_trait]
+pub trait PersistedQueryCache: Sync + Send + 'static {
+    /// Gets the query source of the hash, returns `None` if the hash is unknown.
+    async fn get(&self, hash: &str) -> Option<String>;
+
+    /// Stores the query source of the hash.
+    async fn set(&self, hash: String, query_source: String);
+}
+
+/// In-memory persisted query cache which keeps the least recently used queries
+pub struct LruPersistedQueryCache(Mutex<LruCache<String, String>>);
+
+impl LruPersistedQueryCache {
+    /// Create a cache that keeps at most `cap` queries.
+    pub fn new(cap: usize) -> Self {
+        Self(Mutex::new(LruCache::new(cap)))
+    }
+}
+
+impl Default for LruPersistedQueryCache {
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+#[async_trait::async_trait]
+impl PersistedQueryCache for LruPersistedQueryCache {
+    async fn get(&self, hash: &str) -> Option<String> {
+        self.0.lock().get(&hash.to_string()).cloned()
+    }
+
+    async fn set(&self, hash: String, query_source: String) {
+        self.0.lock().put(hash, query_source);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[async_std::test]
+    async fn test_lru_cache() {
+        let cache = LruPersistedQueryCache::new(2);
+        cache.set("a".to_string(), "{ a }".to_string()).await;
+        cache.set("b".to_string(), "{ b }".to_string()).await;
+        assert_eq!(cache.get("a").await.as_deref(), Some("{ a }"));
+        cache.set("c".to_string(), "{ c }".to_string()).await;
+        assert_eq!(cache.get("a").await.as_deref(), Some("{ a }"));
+        assert_eq!(cache.get("b").await, None);
+        assert_eq!(cache.get("c").await.as_deref(), Some("{ c }"));
+    }
+}
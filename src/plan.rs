@@ -0,0 +1,130 @@
+use crate::registry::TypeName;
+use crate::validation::field_complexity;
+use crate::{ContextSelectionSet, OperationType, Result};
+use graphql_parser::query::{Selection, TypeCondition};
+
+/// A field of an execution plan, see `QueryBuilder::plan`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanField {
+    /// Response key of the field, its alias or its name
+    pub name: String,
+
+    /// Name of the field in the schema
+    pub field_name: String,
+
+    /// Type of the object or interface that defines the field
+    pub parent_type: String,
+
+    /// GraphQL type of the field, such as `[Item!]!`
+    pub ty: String,
+
+    /// Complexity of the field including its selection set, see `SchemaBuilder::limit_complexity`
+    pub complexity: usize,
+
+    /// Guards that are checked before resolving the field
+    ///
+    /// `__schema` and `__type` have the `"introspection"` guard if `SchemaBuilder::introspection_guard` is set.
+    pub guards: Vec<&'static str>,
+
+    /// Fields of the selection set, the fields of the fragments are listed with the type of their condition
+    pub fields: Vec<PlanField>,
+}
+
+/// Execution plan of a query, see `QueryBuilder::plan`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionPlan {
+    /// Name of the planned operation, `None` if the operation is anonymous
+    pub operation_name: Option<String>,
+
+    /// Type of the planned operation
+    pub operation_type: OperationType,
+
+    /// Complexity of the operation, the sum of the complexities of its fields
+    pub complexity: usize,
+
+    /// Fields of the operation
+    pub fields: Vec<PlanField>,
+}
+
+/// Plans the fields of the selection set of `ctx`, whose type is `type_name`.
+///
+/// The fields are collected from the registry like `calculate_complexity` does, there are no values to resolve
+/// them with. The fields skipped by `@skip` or `@include` are not listed.
+pub(crate) fn plan_fields(
+    ctx: &ContextSelectionSet<'_>,
+    type_name: &str,
+    introspection_guard: bool,
+) -> Result<Vec<PlanField>> {
+    let ty = ctx.registry.types.get(type_name);
+    let mut fields = Vec::new();
+
+    for selection in &ctx.item.items {
+        match selection {
+            Selection::Field(field) => {
+                if ctx.is_skip(&field.directives)? {
+                    continue;
+                }
+
+                let registry_field = ty.and_then(|ty| ty.field_by_name(&field.name));
+                let children = match registry_field {
+                    Some(registry_field) => plan_fields(
+                        &ctx.with_selection_set(&field.selection_set),
+                        TypeName::concrete_typename(&registry_field.ty),
+                        introspection_guard,
+                    )?,
+                    None => Vec::new(),
+                };
+                let child_complexity = children.iter().map(|field| field.complexity).sum();
+                let is_introspection = type_name == ctx.registry.query_type
+                    && (field.name == "__schema" || field.name == "__type");
+
+                fields.push(PlanField {
+                    name: field.alias.clone().unwrap_or_else(|| field.name.clone()),
+                    field_name: field.name.clone(),
+                    parent_type: type_name.to_string(),
+                    ty: match registry_field {
+                        Some(registry_field) => registry_field.ty.clone(),
+                        // `__typename` is the only field of a validated query that isn't in the registry.
+                        None => "String!".to_string(),
+                    },
+                    complexity: field_complexity(ctx, field, registry_field, child_complexity)?,
+                    guards: if is_introspection && introspection_guard {
+                        vec!["introspection"]
+                    } else {
+                        Vec::new()
+                    },
+                    fields: children,
+                });
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                if ctx.is_skip(&fragment_spread.directives)? {
+                    continue;
+                }
+                if let Some(fragment) = ctx.fragments.get(&fragment_spread.fragment_name) {
+                    let TypeCondition::On(name) = &fragment.type_condition;
+                    fields.extend(plan_fields(
+                        &ctx.with_selection_set(&fragment.selection_set),
+                        name,
+                        introspection_guard,
+                    )?);
+                }
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                if ctx.is_skip(&inline_fragment.directives)? {
+                    continue;
+                }
+                let name = match &inline_fragment.type_condition {
+                    Some(TypeCondition::On(name)) => name.as_str(),
+                    None => type_name,
+                };
+                fields.extend(plan_fields(
+                    &ctx.with_selection_set(&inline_fragment.selection_set),
+                    name,
+                    introspection_guard,
+                )?);
+            }
+        }
+    }
+
+    Ok(fields)
+}
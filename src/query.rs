@@ -1,19 +1,30 @@
-use crate::context::Data;
+use crate::context::{Data, ResponseCompleteCallback};
 use crate::error::ParseRequestError;
+use crate::extensions::BoxExtension;
 use crate::mutation_resolver::do_mutation_resolve;
+use crate::plan::{plan_fields, ExecutionPlan};
+use crate::query_cache::CachedQuery;
 use crate::registry::CacheControl;
-use crate::validation::{check_rules, CheckResult};
+use crate::validation::{calculate_complexity, check_rules, CheckResult};
+use crate::value::parse_query_source;
 use crate::{do_resolve, ContextBase, Error, Result, Schema};
 use crate::{ObjectType, QueryError, Variables};
+use futures::future::Either;
+use futures_timer::Delay;
 use graphql_parser::query::{
-    Definition, Document, OperationDefinition, SelectionSet, VariableDefinition,
+    Definition, Document, OperationDefinition, Selection, SelectionSet, VariableDefinition,
 };
-use graphql_parser::{parse_query, Pos};
+use graphql_parser::Pos;
 use itertools::Itertools;
+use parking_lot::Mutex;
+use sha2::{Digest, Sha256};
 use std::any::Any;
 use std::collections::HashMap;
+use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicUsize;
+use std::sync::Arc;
+use std::time::Duration;
 use tempdir::TempDir;
 
 /// IntoQueryBuilder options
@@ -29,6 +40,12 @@ pub struct IntoQueryBuilderOpts {
 
     /// Maximum number of files.
     pub max_num_files: Option<usize>,
+
+    /// Maximum size of the request body.
+    ///
+    /// The body is never read beyond this size, integrations may also reject larger requests before reading them,
+    /// e.g. with the `Content-Length` header.
+    pub max_request_size: Option<usize>,
 }
 
 #[allow(missing_docs)]
@@ -42,10 +59,135 @@ pub trait IntoQueryBuilder: Sized {
         self,
         opts: &IntoQueryBuilderOpts,
     ) -> std::result::Result<QueryBuilder, ParseRequestError>;
+
+    /// Creates a query builder for each query of a batch request, whose body is a JSON array of requests.
+    ///
+    /// A request that isn't a batch yields a single query builder.
+    async fn into_batch_query_builder(
+        self,
+    ) -> std::result::Result<BatchQueryBuilder, ParseRequestError> {
+        self.into_batch_query_builder_opts(&Default::default())
+            .await
+    }
+
+    /// Similar to `into_batch_query_builder`, but you can set the options `IntoQueryBuilderOpts`.
+    async fn into_batch_query_builder_opts(
+        self,
+        opts: &IntoQueryBuilderOpts,
+    ) -> std::result::Result<BatchQueryBuilder, ParseRequestError> {
+        Ok(BatchQueryBuilder(vec![
+            self.into_query_builder_opts(opts).await?,
+        ]))
+    }
+}
+
+/// Returns the hash of the query source, the lowercase hex-encoded SHA-256 digest.
+///
+/// This is the hash used to register queries in the safelist, see `SchemaBuilder::safelist`.
+pub fn query_hash(query_source: &str) -> String {
+    format!("{:x}", Sha256::digest(query_source.as_bytes()))
+}
+
+/// Returns the canonical form of the query document, see `QueryBuilder::canonical_query`.
+///
+/// The document is printed from its AST with the selections, arguments and definitions sorted, so the formatting
+/// and the order of the source don't change the result.
+fn canonical_query(document: &Document) -> String {
+    fn sort_selection_set(selection_set: &mut SelectionSet) {
+        for selection in &mut selection_set.items {
+            match selection {
+                Selection::Field(field) => {
+                    field.arguments.sort_by(|(a, _), (b, _)| a.cmp(b));
+                    sort_selection_set(&mut field.selection_set);
+                }
+                Selection::FragmentSpread(_) => {}
+                Selection::InlineFragment(inline_fragment) => {
+                    sort_selection_set(&mut inline_fragment.selection_set)
+                }
+            }
+        }
+        selection_set
+            .items
+            .sort_by_cached_key(|selection| match selection {
+                Selection::Field(field) => field.to_string(),
+                Selection::FragmentSpread(fragment_spread) => fragment_spread.to_string(),
+                Selection::InlineFragment(inline_fragment) => inline_fragment.to_string(),
+            });
+    }
+
+    let mut document = document.clone();
+    for definition in &mut document.definitions {
+        match definition {
+            Definition::Operation(OperationDefinition::SelectionSet(selection_set)) => {
+                sort_selection_set(selection_set)
+            }
+            Definition::Operation(OperationDefinition::Query(query)) => {
+                sort_selection_set(&mut query.selection_set)
+            }
+            Definition::Operation(OperationDefinition::Mutation(mutation)) => {
+                sort_selection_set(&mut mutation.selection_set)
+            }
+            Definition::Operation(OperationDefinition::Subscription(subscription)) => {
+                sort_selection_set(&mut subscription.selection_set)
+            }
+            Definition::Fragment(fragment) => sort_selection_set(&mut fragment.selection_set),
+        }
+    }
+    document
+        .definitions
+        .sort_by_cached_key(|definition| definition.to_string());
+    document.to_string()
+}
+
+/// Sorts the keys of every object in `value` alphabetically, see `SchemaBuilder::sort_response_keys`.
+fn sort_object_keys(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries = std::mem::take(map).into_iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            *map = serde_json::Map::with_capacity(entries.len());
+            for (key, mut value) in entries {
+                sort_object_keys(&mut value);
+                map.insert(key, value);
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(sort_object_keys),
+        _ => {}
+    }
+}
+
+/// Collects the results of the extensions under their names, see `Extension::name`.
+fn extension_results(extensions: &[BoxExtension]) -> serde_json::Map<String, serde_json::Value> {
+    let mut results = serde_json::Map::new();
+    for extension in extensions {
+        if let Some(result) = extension.result() {
+            results.insert(extension.name().to_string(), result);
+        }
+    }
+    results
+}
+
+/// Type of a GraphQL operation
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OperationType {
+    /// Query
+    Query,
+
+    /// Mutation
+    Mutation,
+
+    /// Subscription
+    Subscription,
 }
 
 /// Query response
 pub struct QueryResponse {
+    /// Name of the executed operation, `None` if the operation is anonymous
+    pub operation_name: Option<String>,
+
+    /// Type of the executed operation
+    pub operation_type: OperationType,
+
     /// Data of query result
     pub data: serde_json::Value,
 
@@ -54,6 +196,12 @@ pub struct QueryResponse {
 
     /// Cache control value
     pub cache_control: CacheControl,
+
+    /// Errors of the fields that failed without failing the whole query, such as elements of `Vec<FieldResult<T>>`
+    pub errors: Vec<Error>,
+
+    /// Canonical form of the query, only if it's requested with `QueryBuilder::canonical_query`
+    pub canonical_query: Option<String>,
 }
 
 /// Query builder
@@ -63,6 +211,11 @@ pub struct QueryBuilder {
     pub(crate) variables: Variables,
     pub(crate) ctx_data: Option<Data>,
     pub(crate) files_holder: Option<TempDir>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) extensions: Option<serde_json::Value>,
+    pub(crate) canonical_query: bool,
+    /// The query is sent by the schema itself, it isn't checked against the safelist.
+    pub(crate) internal: bool,
 }
 
 impl QueryBuilder {
@@ -74,6 +227,10 @@ impl QueryBuilder {
             variables: Default::default(),
             ctx_data: None,
             files_holder: None,
+            timeout: None,
+            extensions: None,
+            canonical_query: false,
+            internal: false,
         }
     }
 
@@ -104,6 +261,36 @@ impl QueryBuilder {
         self
     }
 
+    /// Set the maximum duration of the execution, default is no timeout.
+    ///
+    /// If the query takes longer, its execution is cancelled and `execute` returns `QueryError::Timeout`.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        QueryBuilder {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Specify the `extensions` of the request, you access it with `Context::request_extensions`.
+    pub fn extensions(self, extensions: serde_json::Value) -> Self {
+        QueryBuilder {
+            extensions: Some(extensions),
+            ..self
+        }
+    }
+
+    /// Return the canonical form of the query in `QueryResponse::canonical_query`, default is `false`.
+    ///
+    /// The canonical form is printed from the parsed query with normalized whitespace, and the fields, arguments and
+    /// definitions sorted. Equivalent queries with a different formatting or order have the same canonical form, it
+    /// can be used to build stable cache keys.
+    pub fn canonical_query(self, enable: bool) -> Self {
+        QueryBuilder {
+            canonical_query: enable,
+            ..self
+        }
+    }
+
     /// Set file holder
     pub fn set_files_holder(&mut self, files_holder: TempDir) {
         self.files_holder = Some(files_holder);
@@ -121,11 +308,106 @@ impl QueryBuilder {
             .set_upload(var_path, filename, content_type, path);
     }
 
+    /// Returns the execution plan of the query instead of executing it.
+    ///
+    /// The query is parsed and validated like `execute` does, but no resolver is called, so it has no side effects.
+    /// The plan lists the fields that would be resolved with their types, complexities and guards, the complexity and
+    /// depth limits of the schema are not checked.
+    pub fn plan<Query, Mutation, Subscription>(
+        self,
+        schema: &Schema<Query, Mutation, Subscription>,
+    ) -> Result<ExecutionPlan> {
+        let document = parse_query_source(&self.query_source)?;
+        check_rules(
+            &schema.0.registry,
+            &document,
+            schema.0.validation_mode,
+            schema.0.query.disable_introspection,
+        )?;
+        let variables = match &schema.0.variables_preprocessor {
+            Some(variables_preprocessor) => variables_preprocessor(self.variables),
+            None => self.variables,
+        };
+
+        let resolve_id = AtomicUsize::default();
+        let fragments = document
+            .definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                Definition::Fragment(fragment) => Some((fragment.name.clone(), fragment.clone())),
+                Definition::Operation(_) => None,
+            })
+            .collect::<HashMap<_, _>>();
+        let (selection_set, variable_definitions, operation_name, operation_type) =
+            current_operation(&document, self.operation_name.as_deref())
+                .map_err(|err| err.into_error(Pos::default()))?;
+
+        let ctx = ContextBase {
+            path_node: None,
+            resolve_id: &resolve_id,
+            extensions: &[],
+            item: selection_set,
+            variables: &variables,
+            variable_definitions,
+            registry: &schema.0.registry,
+            data: &schema.0.data,
+            ctx_data: self.ctx_data.as_ref(),
+            fragments: &fragments,
+            representation: None,
+            request_extensions: self.extensions.as_ref(),
+            operation_name,
+            operation_type,
+            siblings: None,
+            errors: None,
+            response_complete: None,
+        };
+
+        let root_type = match operation_type {
+            OperationType::Query => Some(&schema.0.registry.query_type),
+            OperationType::Mutation => schema.0.registry.mutation_type.as_ref(),
+            OperationType::Subscription => schema.0.registry.subscription_type.as_ref(),
+        };
+        let fields = match root_type {
+            Some(root_type) => plan_fields(
+                &ctx,
+                root_type,
+                schema.0.query.introspection_guard.is_some(),
+            )?,
+            None => Vec::new(),
+        };
+        Ok(ExecutionPlan {
+            operation_name: operation_name.map(ToString::to_string),
+            operation_type,
+            complexity: fields.iter().map(|field| field.complexity).sum(),
+            fields,
+        })
+    }
+
     /// Execute the query.
     pub async fn execute<Query, Mutation, Subscription>(
         self,
         schema: &Schema<Query, Mutation, Subscription>,
     ) -> Result<QueryResponse>
+    where
+        Query: ObjectType + Send + Sync,
+        Mutation: ObjectType + Send + Sync,
+    {
+        match self.timeout {
+            Some(timeout) => {
+                let execute = Box::pin(self.execute_inner(schema));
+                match futures::future::select(execute, Delay::new(timeout)).await {
+                    Either::Left((res, _)) => res,
+                    Either::Right(_) => Err(QueryError::Timeout.into_error(Pos::default())),
+                }
+            }
+            None => self.execute_inner(schema).await,
+        }
+    }
+
+    async fn execute_inner<Query, Mutation, Subscription>(
+        mut self,
+        schema: &Schema<Query, Mutation, Subscription>,
+    ) -> Result<QueryResponse>
     where
         Query: ObjectType + Send + Sync,
         Mutation: ObjectType + Send + Sync,
@@ -138,29 +420,91 @@ impl QueryBuilder {
             .map(|factory| factory())
             .collect_vec();
 
+        // resolve persisted query
+        if let Some(cache) = &schema.0.persisted_query_cache {
+            let hash = self
+                .extensions
+                .as_ref()
+                .and_then(|extensions| extensions.get("persistedQuery"))
+                .and_then(|persisted_query| persisted_query.get("sha256Hash"))
+                .and_then(|hash| hash.as_str())
+                .map(ToString::to_string);
+            if let Some(hash) = hash {
+                if self.query_source.is_empty() {
+                    match cache.get(&hash).await {
+                        Some(query_source) => self.query_source = query_source,
+                        None => {
+                            return Err(
+                                QueryError::PersistedQueryNotFound.into_error(Pos::default())
+                            )
+                        }
+                    }
+                } else if query_hash(&self.query_source) == hash {
+                    cache.set(hash, self.query_source.clone()).await;
+                } else {
+                    return Err(QueryError::PersistedQueryHashMismatch.into_error(Pos::default()));
+                }
+            }
+        }
+
+        // check safelist
+        if let Some(safelist) = schema.0.safelist.as_ref().filter(|_| !self.internal) {
+            if !safelist.contains(&query_hash(&self.query_source)) {
+                return Err(QueryError::QueryNotAllowed.into_error(Pos::default()));
+            }
+        }
+
         // parse query source
-        extensions
-            .iter()
-            .for_each(|e| e.parse_start(&self.query_source));
-        let document = parse_query(&self.query_source).map_err(Into::<Error>::into)?;
+        extensions.iter().for_each(|e| {
+            e.parse_start(&self.query_source);
+            e.parse_variables(&self.variables);
+        });
+        let cached_query = match &schema.0.query_cache {
+            Some(query_cache) => query_cache.get(&self.query_source).await,
+            None => None,
+        };
+        let parsed = match cached_query {
+            Some(cached_query) => Either::Left(cached_query),
+            None => Either::Right(parse_query_source(&self.query_source)?),
+        };
         extensions.iter().for_each(|e| e.parse_end());
 
+        // preprocess variables
+        if let Some(variables_preprocessor) = &schema.0.variables_preprocessor {
+            self.variables = variables_preprocessor(std::mem::take(&mut self.variables));
+        }
+
         // check rules
         extensions.iter().for_each(|e| e.validation_start());
+        let cached_query = match parsed {
+            Either::Left(cached_query) => cached_query,
+            Either::Right(document) => {
+                let check_result = check_rules(
+                    &schema.0.registry,
+                    &document,
+                    schema.0.validation_mode,
+                    schema.0.query.disable_introspection,
+                )?;
+                let cached_query = Arc::new(CachedQuery {
+                    document,
+                    check_result,
+                });
+                if let Some(query_cache) = &schema.0.query_cache {
+                    query_cache
+                        .set(&self.query_source, cached_query.clone())
+                        .await;
+                }
+                cached_query
+            }
+        };
         let CheckResult {
             cache_control,
-            complexity,
             depth,
-        } = check_rules(&schema.0.registry, &document, schema.0.validation_mode)?;
+        } = cached_query.check_result;
+        let document = &cached_query.document;
         extensions.iter().for_each(|e| e.validation_end());
 
         // check limit
-        if let Some(limit_complexity) = schema.0.complexity {
-            if complexity > limit_complexity {
-                return Err(QueryError::TooComplex.into_error(Pos::default()));
-            }
-        }
-
         if let Some(limit_depth) = schema.0.depth {
             if depth > limit_depth {
                 return Err(QueryError::TooDeep.into_error(Pos::default()));
@@ -169,15 +513,12 @@ impl QueryBuilder {
 
         // execute
         let resolve_id = AtomicUsize::default();
+        let errors = Mutex::new(Vec::new());
+        let response_complete = Mutex::new(Vec::new());
         let mut fragments = HashMap::new();
-        let (selection_set, variable_definitions, is_query) =
-            current_operation(&document, self.operation_name.as_deref()).ok_or_else(|| {
-                Error::Query {
-                    pos: Pos::default(),
-                    path: None,
-                    err: QueryError::MissingOperation,
-                }
-            })?;
+        let (selection_set, variable_definitions, operation_name, operation_type) =
+            current_operation(document, self.operation_name.as_deref())
+                .map_err(|err| err.into_error(Pos::default()))?;
 
         for definition in &document.definitions {
             if let Definition::Fragment(fragment) = &definition {
@@ -196,73 +537,188 @@ impl QueryBuilder {
             data: &schema.0.data,
             ctx_data: self.ctx_data.as_ref(),
             fragments: &fragments,
+            representation: None,
+            request_extensions: self.extensions.as_ref(),
+            operation_name,
+            operation_type,
+            siblings: None,
+            errors: Some(&errors),
+            response_complete: Some(&response_complete),
         };
 
+        // check complexity, it depends on the arguments so it's computed with the variables of this query
+        if let Some(limit_complexity) = schema.0.complexity {
+            let root_type = match operation_type {
+                OperationType::Query => Some(&schema.0.registry.query_type),
+                _ => schema.0.registry.mutation_type.as_ref(),
+            };
+            let complexity = match root_type {
+                Some(root_type) => calculate_complexity(&ctx, root_type)?,
+                None => 0,
+            };
+            if complexity > limit_complexity {
+                return Err(QueryError::TooComplex.into_error(Pos::default()));
+            }
+        }
+
         extensions.iter().for_each(|e| e.execution_start());
-        let data = if is_query {
-            do_resolve(&ctx, &schema.0.query).await?
+        let data = if operation_type == OperationType::Query {
+            do_resolve(&ctx, &schema.0.query).await
         } else {
-            do_mutation_resolve(&ctx, &schema.0.mutation).await?
+            do_mutation_resolve(&ctx, &schema.0.mutation).await
         };
+        let mut data = match data {
+            Ok(data) => data,
+            Err(err) => {
+                run_response_complete(response_complete);
+                return Err(err);
+            }
+        };
+        if schema.0.registry.sort_response_keys {
+            sort_object_keys(&mut data);
+        }
         extensions.iter().for_each(|e| e.execution_end());
+        extensions.iter().for_each(|e| e.execution_data(&data));
 
         let res = QueryResponse {
+            operation_name: operation_name.map(ToString::to_string),
+            operation_type,
             data,
             extensions: if !extensions.is_empty() {
-                Some(
-                    extensions
-                        .iter()
-                        .filter_map(|e| e.result().map(|res| (e.name().to_string(), res)))
-                        .collect::<serde_json::Map<_, _>>(),
-                )
+                Some(extension_results(&extensions))
             } else {
                 None
             },
             cache_control,
+            errors: errors.into_inner(),
+            canonical_query: if self.canonical_query {
+                Some(canonical_query(document))
+            } else {
+                None
+            },
         };
+        run_response_complete(response_complete);
         Ok(res)
     }
 }
 
+/// Query builders of a batch request, see `IntoQueryBuilder::into_batch_query_builder`
+pub struct BatchQueryBuilder(pub Vec<QueryBuilder>);
+
+impl Deref for BatchQueryBuilder {
+    type Target = Vec<QueryBuilder>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl BatchQueryBuilder {
+    /// Execute the queries concurrently, returns their results in the order of the batch.
+    ///
+    /// The queries are independent, the errors of a query only appear in its own result.
+    pub async fn execute<Query, Mutation, Subscription>(
+        self,
+        schema: &Schema<Query, Mutation, Subscription>,
+    ) -> Vec<Result<QueryResponse>>
+    where
+        Query: ObjectType + Send + Sync,
+        Mutation: ObjectType + Send + Sync,
+    {
+        futures::future::join_all(self.0.into_iter().map(|builder| builder.execute(schema))).await
+    }
+}
+
+fn run_response_complete(response_complete: Mutex<Vec<ResponseCompleteCallback>>) {
+    for f in response_complete.into_inner() {
+        f();
+    }
+}
+
+fn get_operation_name(operation: &OperationDefinition) -> Option<&str> {
+    match operation {
+        OperationDefinition::SelectionSet(_) => None,
+        OperationDefinition::Query(query) => query.name.as_deref(),
+        OperationDefinition::Mutation(mutation) => mutation.name.as_deref(),
+        OperationDefinition::Subscription(subscription) => subscription.name.as_deref(),
+    }
+}
+
+/// Returns the names of all the named operations in the document.
+pub(crate) fn operation_names(document: &Document) -> Vec<String> {
+    document
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            Definition::Operation(operation) => {
+                get_operation_name(operation).map(ToString::to_string)
+            }
+            Definition::Fragment(_) => None,
+        })
+        .collect()
+}
+
+#[allow(clippy::type_complexity)]
 fn current_operation<'a>(
     document: &'a Document,
     operation_name: Option<&str>,
-) -> Option<(&'a SelectionSet, &'a [VariableDefinition], bool)> {
-    for definition in &document.definitions {
-        match definition {
-            Definition::Operation(operation_definition) => match operation_definition {
-                OperationDefinition::SelectionSet(s) => {
-                    return Some((s, &[], true));
-                }
-                OperationDefinition::Query(query)
-                    if query.name.is_none()
-                        || operation_name.is_none()
-                        || query.name.as_deref() == operation_name.as_deref() =>
-                {
-                    return Some((&query.selection_set, &query.variable_definitions, true));
-                }
-                OperationDefinition::Mutation(mutation)
-                    if mutation.name.is_none()
-                        || operation_name.is_none()
-                        || mutation.name.as_deref() == operation_name.as_deref() =>
-                {
-                    return Some((
-                        &mutation.selection_set,
-                        &mutation.variable_definitions,
-                        false,
-                    ));
-                }
-                OperationDefinition::Subscription(subscription)
-                    if subscription.name.is_none()
-                        || operation_name.is_none()
-                        || subscription.name.as_deref() == operation_name.as_deref() =>
-                {
-                    return None;
-                }
-                _ => {}
-            },
-            Definition::Fragment(_) => {}
+) -> std::result::Result<
+    (
+        &'a SelectionSet,
+        &'a [VariableDefinition],
+        Option<&'a str>,
+        OperationType,
+    ),
+    QueryError,
+> {
+    let operations = document
+        .definitions
+        .iter()
+        .filter_map(|definition| match definition {
+            Definition::Operation(operation) => Some(operation),
+            Definition::Fragment(_) => None,
+        })
+        .collect_vec();
+
+    let operation = match (operation_name, operations.as_slice()) {
+        (_, []) => return Err(QueryError::MissingOperation),
+        // A document with a single anonymous operation ignores the operation name.
+        (_, [operation]) if get_operation_name(operation).is_none() => *operation,
+        (Some(name), _) => match operations
+            .iter()
+            .find(|operation| get_operation_name(operation) == Some(name))
+        {
+            Some(operation) => *operation,
+            None => {
+                return Err(QueryError::UnknownOperationNamed {
+                    name: name.to_string(),
+                    available: operation_names(document),
+                })
+            }
+        },
+        (None, [operation]) => *operation,
+        (None, _) => {
+            return Err(QueryError::AmbiguousOperation {
+                available: operation_names(document),
+            })
         }
+    };
+
+    let name = get_operation_name(operation);
+    match operation {
+        OperationDefinition::SelectionSet(s) => Ok((s, &[], name, OperationType::Query)),
+        OperationDefinition::Query(query) => Ok((
+            &query.selection_set,
+            &query.variable_definitions,
+            name,
+            OperationType::Query,
+        )),
+        OperationDefinition::Mutation(mutation) => Ok((
+            &mutation.selection_set,
+            &mutation.variable_definitions,
+            name,
+            OperationType::Mutation,
+        )),
+        OperationDefinition::Subscription(_) => Err(QueryError::SubscriptionNotSupportedOverHttp),
     }
-    None
 }
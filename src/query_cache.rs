@@ -0,0 +1,57 @@
+//! Parsed query cache
+//!
+//! Parsing and validating a query is done again for every request, a query cache stores the result for the
+//! query source so that a hot query is parsed and validated only once. Since the same query source always
+//! parses the same way, the cached queries never need to be invalidated.
+
+use crate::query_hash;
+use crate::validation::CheckResult;
+use graphql_parser::query::Document;
+use lru::LruCache;
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// A parsed and validated query
+pub struct CachedQuery {
+    pub(crate) document: Document,
+    pub(crate) check_result: CheckResult,
+}
+
+/// Storage of the parsed and validated queries, keyed by the query source
+///
+/// It is registered with `SchemaBuilder::query_cache`.
+#[async_trait::async_trait]
+pub trait QueryCache: Sync + Send + 'static {
+    /// Gets the cached query of the query source, returns `None` if the query source is not cached.
+    async fn get(&self, query_source: &str) -> Option<Arc<CachedQuery>>;
+
+    /// Stores the cached query of the query source.
+    async fn set(&self, query_source: &str, query: Arc<CachedQuery>);
+}
+
+/// In-memory query cache which keeps the least recently used queries, keyed by the hash of the query source
+pub struct LruQueryCache(Mutex<LruCache<String, Arc<CachedQuery>>>);
+
+impl LruQueryCache {
+    /// Create a cache that keeps at most `cap` queries.
+    pub fn new(cap: usize) -> Self {
+        Self(Mutex::new(LruCache::new(cap)))
+    }
+}
+
+impl Default for LruQueryCache {
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryCache for LruQueryCache {
+    async fn get(&self, query_source: &str) -> Option<Arc<CachedQuery>> {
+        self.0.lock().get(&query_hash(query_source)).cloned()
+    }
+
+    async fn set(&self, query_source: &str, query: Arc<CachedQuery>) {
+        self.0.lock().put(query_hash(query_source), query);
+    }
+}
@@ -1,6 +1,10 @@
+use crate::middleware::BoxMiddleware;
+use crate::transformers::InputValueTransformer;
 use crate::validators::InputValueValidator;
-use crate::{model, Any, Type as _, Value};
+use crate::{model, Any, Context, FieldResult, SchemaError, Type as _, Value};
+use futures::future::BoxFuture;
 use graphql_parser::query::Type as ParsedType;
+use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 use std::sync::Arc;
@@ -94,6 +98,7 @@ pub struct InputValue {
     pub ty: String,
     pub default_value: Option<&'static str>,
     pub validator: Option<Arc<dyn InputValueValidator>>,
+    pub transformer: Option<Arc<dyn InputValueTransformer>>,
 }
 
 #[derive(Clone)]
@@ -107,6 +112,7 @@ pub struct Field {
     pub external: bool,
     pub requires: Option<&'static str>,
     pub provides: Option<&'static str>,
+    pub compute_complexity: Option<ComputeComplexityFn>,
 }
 
 #[derive(Clone)]
@@ -114,6 +120,7 @@ pub struct EnumValue {
     pub name: &'static str,
     pub description: Option<&'static str>,
     pub deprecation: Option<&'static str>,
+    pub int_value: Option<i64>,
 }
 
 /// Cache control values
@@ -197,6 +204,7 @@ pub enum Type {
         name: String,
         description: Option<&'static str>,
         is_valid: fn(value: &Value) -> bool,
+        accepted_forms: &'static [&'static str],
     },
     Object {
         name: String,
@@ -228,6 +236,7 @@ pub enum Type {
         name: String,
         description: Option<&'static str>,
         input_fields: HashMap<String, InputValue>,
+        oneof: bool,
     },
 }
 
@@ -289,6 +298,13 @@ impl Type {
         }
     }
 
+    pub fn is_output(&self) -> bool {
+        match self {
+            Type::InputObject { .. } => false,
+            _ => true,
+        }
+    }
+
     pub fn is_possible_type(&self, type_name: &str) -> bool {
         match self {
             Type::Interface { possible_types, .. } => possible_types.contains(type_name),
@@ -330,8 +346,16 @@ pub struct Directive {
     pub description: Option<&'static str>,
     pub locations: Vec<model::__DirectiveLocation>,
     pub args: HashMap<&'static str, InputValue>,
+    pub is_repeatable: bool,
 }
 
+/// Computes the complexity of a field from its arguments and the complexity of its selection set.
+pub type ComputeComplexityFn = fn(&Context<'_>, usize) -> crate::Result<usize>;
+
+pub type FieldResolver = Box<
+    dyn for<'a> Fn(&'a Context<'_>) -> BoxFuture<'a, FieldResult<serde_json::Value>> + Send + Sync,
+>;
+
 pub struct Registry {
     pub types: HashMap<String, Type>,
     pub directives: HashMap<String, Directive>,
@@ -339,9 +363,79 @@ pub struct Registry {
     pub query_type: String,
     pub mutation_type: Option<String>,
     pub subscription_type: Option<String>,
+    pub field_resolvers: HashMap<String, HashMap<String, FieldResolver>>,
+    pub federation_link: Option<String>,
+    pub auto_typename: bool,
+    pub sort_response_keys: bool,
+    pub sibling_values: bool,
+    pub ignore_deprecated_complexity: bool,
+    pub(crate) middlewares: Vec<BoxMiddleware>,
+    pub schema_errors: Vec<SchemaError>,
 }
 
 impl Registry {
+    /// Records an error if `name` is already in `fields` or starts with `__`, it is returned by `Registry::check`.
+    pub fn check_field_name<T>(
+        &mut self,
+        type_name: &str,
+        fields: &HashMap<String, T>,
+        name: &str,
+    ) {
+        if name.starts_with("__") {
+            self.schema_errors.push(SchemaError::ReservedFieldName {
+                type_name: type_name.to_string(),
+                field_name: name.to_string(),
+            });
+        } else if fields.contains_key(name) {
+            self.schema_errors.push(SchemaError::DuplicateField {
+                type_name: type_name.to_string(),
+                field_name: name.to_string(),
+            });
+        }
+    }
+
+    /// Returns the fields of the object type `name`, which are listed in `type_name` by a `#[field(flatten)]` field.
+    ///
+    /// Records an error if `name` is not a registered object type, such as a type that flattens itself.
+    pub fn flattened_fields(&mut self, type_name: &str, name: &str) -> HashMap<String, Field> {
+        match self.types.get(name) {
+            // the placeholder of a type being registered has an empty name, see `create_type`
+            Some(Type::Object {
+                name: object_name,
+                fields,
+                ..
+            }) if !object_name.is_empty() => fields.clone(),
+            _ => {
+                self.schema_errors.push(SchemaError::InvalidFlatten {
+                    type_name: type_name.to_string(),
+                    flatten_type: name.to_string(),
+                });
+                Default::default()
+            }
+        }
+    }
+
+    /// Returns the fields of the input object type `name`, which are listed in `type_name` by a `#[field(flatten)]`
+    /// field.
+    ///
+    /// Records an error if `name` is not a registered input object type, such as a type that flattens itself.
+    pub fn flattened_input_fields(
+        &mut self,
+        type_name: &str,
+        name: &str,
+    ) -> HashMap<String, InputValue> {
+        match self.types.get(name) {
+            Some(Type::InputObject { input_fields, .. }) => input_fields.clone(),
+            _ => {
+                self.schema_errors.push(SchemaError::InvalidFlatten {
+                    type_name: type_name.to_string(),
+                    flatten_type: name.to_string(),
+                });
+                Default::default()
+            }
+        }
+    }
+
     pub fn create_type<T: crate::Type, F: FnMut(&mut Registry) -> Type>(
         &mut self,
         mut f: F,
@@ -396,6 +490,117 @@ impl Registry {
         }
     }
 
+    pub fn override_field(
+        &mut self,
+        type_name: &str,
+        field_name: &str,
+        ty: &str,
+        resolver: FieldResolver,
+    ) {
+        if !self
+            .concrete_type_by_name(ty)
+            .map(|ty| ty.is_output())
+            .unwrap_or_default()
+        {
+            self.schema_errors.push(SchemaError::InvalidFieldType {
+                type_name: type_name.to_string(),
+                field_name: field_name.to_string(),
+                ty: ty.to_string(),
+            });
+            return;
+        }
+        let fields = match self.types.get_mut(type_name) {
+            Some(Type::Object { fields, .. }) => fields,
+            _ => {
+                self.schema_errors
+                    .push(SchemaError::UnregisteredObjectType {
+                        type_name: type_name.to_string(),
+                    });
+                return;
+            }
+        };
+        fields
+            .entry(field_name.to_string())
+            .and_modify(|field| field.ty = ty.to_string())
+            .or_insert_with(|| Field {
+                name: field_name.to_string(),
+                description: None,
+                args: Default::default(),
+                ty: ty.to_string(),
+                deprecation: None,
+                cache_control: Default::default(),
+                external: false,
+                requires: None,
+                compute_complexity: None,
+                provides: None,
+            });
+        self.field_resolvers
+            .entry(type_name.to_string())
+            .or_default()
+            .insert(field_name.to_string(), resolver);
+    }
+
+    /// Checks that every possible type of the interfaces and unions is registered, and that the validators of the
+    /// arguments and input fields are valid.
+    ///
+    /// The types are checked in the order of their names, so the same error is reported for the same schema.
+    pub fn check(&self) -> std::result::Result<(), SchemaError> {
+        if let Some(err) = self.schema_errors.first() {
+            return Err(err.clone());
+        }
+        for ty in self.types.values().sorted_by(|a, b| a.name().cmp(b.name())) {
+            if let Some(possible_types) = ty.possible_types() {
+                for type_name in possible_types.iter().sorted() {
+                    if !self.types.contains_key(type_name) {
+                        return Err(SchemaError::UnregisteredPossibleType {
+                            name: ty.name().to_string(),
+                            type_name: type_name.clone(),
+                        });
+                    }
+                }
+            }
+
+            let mut input_values = Vec::new();
+            if let Some(fields) = ty.fields() {
+                for field in fields.values().sorted_by(|a, b| a.name.cmp(&b.name)) {
+                    for arg in field.args.values().sorted_by(|a, b| a.name.cmp(b.name)) {
+                        input_values
+                            .push((format!("{}.{}({})", ty.name(), field.name, arg.name), arg));
+                    }
+                }
+            }
+            if let Type::InputObject { input_fields, .. } = ty {
+                for field in input_fields.values().sorted_by(|a, b| a.name.cmp(b.name)) {
+                    input_values.push((format!("{}.{}", ty.name(), field.name), field));
+                }
+            }
+            for (name, input_value) in input_values {
+                if let Some(validator) = &input_value.validator {
+                    if let Err(reason) = validator.check() {
+                        return Err(SchemaError::InvalidValidator { name, reason });
+                    }
+                }
+            }
+        }
+        for directive in self
+            .directives
+            .values()
+            .sorted_by(|a, b| a.name.cmp(b.name))
+        {
+            for arg in directive.args.values().sorted_by(|a, b| a.name.cmp(b.name)) {
+                if let Some(validator) = &arg.validator {
+                    if let Err(reason) = validator.check() {
+                        return Err(SchemaError::InvalidValidator {
+                            name: format!("@{}({})", directive.name, arg.name),
+                            reason,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub fn concrete_type_by_name(&self, type_name: &str) -> Option<&Type> {
         self.types.get(TypeName::concrete_typename(type_name))
     }
@@ -488,15 +693,282 @@ impl Registry {
         }
     }
 
+    /// Returns the federation directives used by the types, in the order of the federation specification.
+    fn federation_directives(&self) -> Vec<&'static str> {
+        let mut has_key = false;
+        let mut has_external = false;
+        let mut has_requires = false;
+        let mut has_provides = false;
+
+        for ty in self.types.values() {
+            let (fields, keys) = match ty {
+                Type::Object { fields, keys, .. } => (fields, keys),
+                Type::Interface { fields, keys, .. } => (fields, keys),
+                _ => continue,
+            };
+            if ty.name().starts_with("__") || ty.name() == "_Service" {
+                continue;
+            }
+            has_key |= keys
+                .as_ref()
+                .map(|keys| !keys.is_empty())
+                .unwrap_or_default();
+            for field in fields.values() {
+                has_external |= field.external;
+                has_requires |= field.requires.is_some();
+                has_provides |= field.provides.is_some();
+            }
+        }
+
+        vec![
+            ("@key", has_key),
+            ("@external", has_external),
+            ("@requires", has_requires),
+            ("@provides", has_provides),
+        ]
+        .into_iter()
+        .filter(|(_, used)| *used)
+        .map(|(name, _)| name)
+        .collect()
+    }
+
     pub fn create_federation_sdl(&self) -> String {
         let mut sdl = String::new();
+        if let Some(url) = &self.federation_link {
+            let imports = self
+                .federation_directives()
+                .into_iter()
+                .map(|name| format!("\"{}\"", name))
+                .collect::<Vec<_>>();
+            writeln!(
+                sdl,
+                "extend schema @link(url: \"{}\", import: [{}])",
+                url,
+                imports.join(", ")
+            )
+            .ok();
+            writeln!(sdl).ok();
+        }
         for ty in self.types.values() {
             self.create_federation_type(ty, &mut sdl);
         }
         sdl
     }
 
-    fn has_entities(&self) -> bool {
+    fn write_sdl_description(sdl: &mut String, description: Option<&str>, indent: &str) {
+        if let Some(description) = description {
+            writeln!(
+                sdl,
+                "{}\"\"\"{}\"\"\"",
+                indent,
+                description.replace("\"\"\"", "\\\"\"\"")
+            )
+            .ok();
+        }
+    }
+
+    fn write_sdl_deprecation(sdl: &mut String, deprecation: Option<&str>) {
+        if let Some(reason) = deprecation {
+            write!(
+                sdl,
+                " @deprecated(reason: {})",
+                serde_json::to_string(reason).unwrap()
+            )
+            .ok();
+        }
+    }
+
+    fn write_sdl_input_value(sdl: &mut String, input_value: &InputValue) {
+        write!(sdl, "{}: {}", input_value.name, input_value.ty).ok();
+        if let Some(default_value) = input_value.default_value {
+            write!(sdl, " = {}", default_value).ok();
+        }
+    }
+
+    fn write_sdl_args(sdl: &mut String, args: &HashMap<&'static str, InputValue>) {
+        if args.is_empty() {
+            return;
+        }
+        write!(sdl, "(").ok();
+        for (idx, arg) in args.values().sorted_by_key(|arg| arg.name).enumerate() {
+            if idx > 0 {
+                write!(sdl, ", ").ok();
+            }
+            Self::write_sdl_input_value(sdl, arg);
+        }
+        write!(sdl, ")").ok();
+    }
+
+    fn write_sdl_fields(sdl: &mut String, fields: &HashMap<String, Field>) {
+        writeln!(sdl, " {{").ok();
+        for field in fields.values().sorted_by(|a, b| a.name.cmp(&b.name)) {
+            if field.name.starts_with("__") {
+                continue;
+            }
+            Self::write_sdl_description(sdl, field.description, "\t");
+            write!(sdl, "\t{}", field.name).ok();
+            Self::write_sdl_args(sdl, &field.args);
+            write!(sdl, ": {}", field.ty).ok();
+            Self::write_sdl_deprecation(sdl, field.deprecation);
+            writeln!(sdl).ok();
+        }
+        writeln!(sdl, "}}").ok();
+    }
+
+    fn write_sdl_type(&self, ty: &Type, sdl: &mut String) {
+        if ty.name().starts_with("__") {
+            return;
+        }
+        match ty {
+            Type::Scalar {
+                name, description, ..
+            } => {
+                if ["Int", "Float", "String", "Boolean", "ID"].contains(&name.as_str()) {
+                    return;
+                }
+                Self::write_sdl_description(sdl, *description, "");
+                writeln!(sdl, "scalar {}", name).ok();
+            }
+            Type::Object {
+                name,
+                description,
+                fields,
+                ..
+            } => {
+                if fields.keys().all(|name| name.starts_with("__")) {
+                    return;
+                }
+                Self::write_sdl_description(sdl, *description, "");
+                write!(sdl, "type {}", name).ok();
+                if let Some(implements) = self.implements.get(name) {
+                    if !implements.is_empty() {
+                        write!(
+                            sdl,
+                            " implements {}",
+                            implements.iter().sorted().join(" & ")
+                        )
+                        .ok();
+                    }
+                }
+                Self::write_sdl_fields(sdl, fields);
+            }
+            Type::Interface {
+                name,
+                description,
+                fields,
+                ..
+            } => {
+                Self::write_sdl_description(sdl, *description, "");
+                write!(sdl, "interface {}", name).ok();
+                Self::write_sdl_fields(sdl, fields);
+            }
+            Type::Union {
+                name,
+                description,
+                possible_types,
+            } => {
+                Self::write_sdl_description(sdl, *description, "");
+                writeln!(
+                    sdl,
+                    "union {} = {}",
+                    name,
+                    possible_types.iter().sorted().join(" | ")
+                )
+                .ok();
+            }
+            Type::Enum {
+                name,
+                description,
+                enum_values,
+            } => {
+                Self::write_sdl_description(sdl, *description, "");
+                writeln!(sdl, "enum {} {{", name).ok();
+                for value in enum_values.values().sorted_by_key(|value| value.name) {
+                    Self::write_sdl_description(sdl, value.description, "\t");
+                    write!(sdl, "\t{}", value.name).ok();
+                    Self::write_sdl_deprecation(sdl, value.deprecation);
+                    writeln!(sdl).ok();
+                }
+                writeln!(sdl, "}}").ok();
+            }
+            Type::InputObject {
+                name,
+                description,
+                input_fields,
+                oneof,
+            } => {
+                Self::write_sdl_description(sdl, *description, "");
+                write!(sdl, "input {}", name).ok();
+                if *oneof {
+                    write!(sdl, " @oneOf").ok();
+                }
+                writeln!(sdl, " {{").ok();
+                for field in input_fields.values().sorted_by_key(|field| field.name) {
+                    Self::write_sdl_description(sdl, field.description, "\t");
+                    write!(sdl, "\t").ok();
+                    Self::write_sdl_input_value(sdl, field);
+                    writeln!(sdl).ok();
+                }
+                writeln!(sdl, "}}").ok();
+            }
+        }
+    }
+
+    /// Returns the SDL of the whole schema, see `Schema::sdl`.
+    pub fn create_sdl(&self) -> String {
+        let mut sdl = String::new();
+
+        writeln!(sdl, "schema {{").ok();
+        writeln!(sdl, "\tquery: {}", self.query_type).ok();
+        if let Some(mutation_type) = &self.mutation_type {
+            writeln!(sdl, "\tmutation: {}", mutation_type).ok();
+        }
+        if let Some(subscription_type) = &self.subscription_type {
+            writeln!(sdl, "\tsubscription: {}", subscription_type).ok();
+        }
+        writeln!(sdl, "}}").ok();
+
+        for directive in self
+            .directives
+            .values()
+            .sorted_by_key(|directive| directive.name)
+        {
+            if ["include", "skip"].contains(&directive.name) {
+                continue;
+            }
+            writeln!(sdl).ok();
+            Self::write_sdl_description(&mut sdl, directive.description, "");
+            write!(sdl, "directive @{}", directive.name).ok();
+            Self::write_sdl_args(&mut sdl, &directive.args);
+            if directive.is_repeatable {
+                write!(sdl, " repeatable").ok();
+            }
+            writeln!(
+                sdl,
+                " on {}",
+                directive
+                    .locations
+                    .iter()
+                    .map(|location| format!("{:?}", location))
+                    .join(" | ")
+            )
+            .ok();
+        }
+
+        for ty in self.types.values().sorted_by(|a, b| a.name().cmp(b.name())) {
+            let len = sdl.len();
+            writeln!(sdl).ok();
+            self.write_sdl_type(ty, &mut sdl);
+            if sdl.len() == len + 1 {
+                // The type isn't printed, such as a built-in scalar.
+                sdl.truncate(len);
+            }
+        }
+
+        sdl
+    }
+
+    pub fn has_entities(&self) -> bool {
         self.types.values().any(|ty| match ty {
             Type::Object {
                 keys: Some(keys), ..
@@ -537,13 +1009,32 @@ impl Registry {
         );
     }
 
-    pub fn create_federation_types(&mut self) {
-        if !self.has_entities() {
-            return;
-        }
-
-        Any::create_type_info(self);
+    /// Returns `true` if a field, an argument or an input field of the schema has the type `name`.
+    fn is_type_referenced(&self, name: &str) -> bool {
+        let is_input_referenced = |values: &HashMap<&'static str, InputValue>| {
+            values
+                .values()
+                .any(|value| TypeName::concrete_typename(&value.ty) == name)
+        };
+        self.types.values().any(|ty| match ty {
+            Type::Object { fields, .. } | Type::Interface { fields, .. } => {
+                fields.values().any(|field| {
+                    TypeName::concrete_typename(&field.ty) == name
+                        || is_input_referenced(&field.args)
+                })
+            }
+            Type::InputObject { input_fields, .. } => input_fields
+                .values()
+                .any(|field| TypeName::concrete_typename(&field.ty) == name),
+            _ => false,
+        }) || self
+            .directives
+            .values()
+            .any(|directive| is_input_referenced(&directive.args))
+    }
 
+    /// Adds `_service`, and `_entities` if there are types with keys, to the query root.
+    pub fn create_federation_types(&mut self) {
         self.types.insert(
             "_Service".to_string(),
             Type::Object {
@@ -562,6 +1053,7 @@ impl Registry {
                             cache_control: Default::default(),
                             external: false,
                             requires: None,
+                            compute_complexity: None,
                             provides: None,
                         },
                     );
@@ -573,7 +1065,12 @@ impl Registry {
             },
         );
 
-        self.create_entity_type();
+        // an empty `_Entity` union is invalid
+        let has_entities = self.has_entities();
+        if has_entities {
+            Any::create_type_info(self);
+            self.create_entity_type();
+        }
 
         let query_root = self.types.get_mut(&self.query_type).unwrap();
         if let Type::Object { fields, .. } = query_root {
@@ -588,10 +1085,15 @@ impl Registry {
                     cache_control: Default::default(),
                     external: false,
                     requires: None,
+                    compute_complexity: None,
                     provides: None,
                 },
             );
 
+            if !has_entities {
+                return;
+            }
+
             fields.insert(
                 "_entities".to_string(),
                 Field {
@@ -607,6 +1109,7 @@ impl Registry {
                                 ty: "[_Any!]!".to_string(),
                                 default_value: None,
                                 validator: None,
+                                transformer: None,
                             },
                         );
                         args
@@ -616,9 +1119,26 @@ impl Registry {
                     cache_control: Default::default(),
                     external: false,
                     requires: None,
+                    compute_complexity: None,
                     provides: None,
                 },
             );
         }
     }
+
+    pub fn remove_federation_types(&mut self) {
+        self.types.remove("_Service");
+        self.types.remove("_Entity");
+
+        let query_root = self.types.get_mut(&self.query_type).unwrap();
+        if let Type::Object { fields, .. } = query_root {
+            fields.remove("_service");
+            fields.remove("_entities");
+        }
+
+        // the schema may use the `Any` scalar itself
+        if !self.is_type_referenced(&Any::type_name()) {
+            self.types.remove(Any::type_name().as_ref());
+        }
+    }
 }
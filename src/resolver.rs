@@ -1,27 +1,157 @@
 use crate::base::BoxFieldFuture;
 use crate::extensions::ResolveInfo;
-use crate::{ContextSelectionSet, Error, ObjectType, QueryError, Result};
+use crate::middleware::Next;
+use crate::{Context, ContextBase, ContextSelectionSet, Error, ObjectType, QueryError, Result};
+use futures::future::BoxFuture;
 use futures::{future, TryFutureExt};
-use graphql_parser::query::{Selection, TypeCondition};
-use std::iter::FromIterator;
+use graphql_parser::query::{Field, Selection, TypeCondition};
+use std::borrow::Cow;
+use std::collections::HashMap;
 
 #[allow(missing_docs)]
 pub async fn do_resolve<'a, T: ObjectType + Send + Sync>(
     ctx: &'a ContextSelectionSet<'a>,
     root: &'a T,
 ) -> Result<serde_json::Value> {
-    let mut futures = Vec::new();
-    collect_fields(ctx, root, &mut futures)?;
-    let res = futures::future::try_join_all(futures).await?;
-    let map = serde_json::Map::from_iter(res);
+    let mut fields = Fields::default();
+    if ctx.registry.sibling_values {
+        let ctx = ContextBase {
+            siblings: Some(Default::default()),
+            ..ctx.clone()
+        };
+        collect_fields(&ctx, root, &mut fields)?;
+    } else {
+        collect_fields(ctx, root, &mut fields)?;
+    }
+    let res = futures::future::try_join_all(fields.into_futures()).await?;
+    let mut map = serde_json::Map::with_capacity(res.len() + ctx.registry.auto_typename as usize);
+    for (name, value) in res {
+        map.insert(name, value);
+    }
+    insert_auto_typename(ctx, root, &mut map);
     Ok(map.into())
 }
 
+/// Adds `__typename` to the result of an object if `SchemaBuilder::auto_typename` is enabled.
+///
+/// The root operation type has no path, it is skipped since its result is the `data` of the response.
+fn insert_auto_typename<T: ObjectType + Send + Sync>(
+    ctx: &ContextSelectionSet<'_>,
+    root: &T,
+    map: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    if ctx.registry.auto_typename && ctx.path_node.is_some() && !map.contains_key("__typename") {
+        map.insert(
+            "__typename".to_string(),
+            root.introspection_type_name().to_string().into(),
+        );
+    }
+}
+
+type FieldFn<'a> = Box<dyn FnOnce(Cow<'a, Field>) -> BoxFieldFuture<'a> + Send + 'a>;
+
+/// The fields collected from a selection set, grouped by response key.
+///
+/// The fields that have the same response key (e.g. a field selected directly and through a fragment) are
+/// resolved once, with the merged sub-selections of all of them.
+#[derive(Default)]
+pub struct Fields<'a> {
+    names: HashMap<&'a str, usize>,
+    items: Vec<(Vec<&'a Field>, FieldFn<'a>)>,
+}
+
+impl<'a> Fields<'a> {
+    fn add(
+        &mut self,
+        field: &'a Field,
+        resolve: impl FnOnce(Cow<'a, Field>) -> BoxFieldFuture<'a> + Send + 'a,
+    ) {
+        let name = field
+            .alias
+            .as_deref()
+            .unwrap_or_else(|| field.name.as_str());
+        match self.names.get(name) {
+            Some(idx) => self.items[*idx].0.push(field),
+            None => {
+                self.names.insert(name, self.items.len());
+                self.items.push((vec![field], Box::new(resolve)));
+            }
+        }
+    }
+
+    /// Returns the number of response keys.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if no field was collected.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the futures that resolve the fields, in the order of their first selection.
+    pub fn into_futures(self) -> Vec<BoxFieldFuture<'a>> {
+        self.items
+            .into_iter()
+            .map(|(fields, resolve)| {
+                if fields.len() == 1 {
+                    resolve(Cow::Borrowed(fields[0]))
+                } else {
+                    let mut field = fields[0].clone();
+                    for other in &fields[1..] {
+                        field
+                            .selection_set
+                            .items
+                            .extend(other.selection_set.items.iter().cloned());
+                    }
+                    resolve(Cow::Owned(field))
+                }
+            })
+            .collect()
+    }
+}
+
+/// Resolves a field through the middlewares registered with `SchemaBuilder::middleware`.
+pub(crate) async fn resolve_field<T: ObjectType + Send + Sync>(
+    ctx: &Context<'_>,
+    root: &T,
+    field: &Field,
+) -> Result<serde_json::Value> {
+    if ctx.registry.middlewares.is_empty() {
+        return resolve_field_inner(ctx, root, field).await;
+    }
+
+    let resolver =
+        || Box::pin(resolve_field_inner(ctx, root, field)) as BoxFuture<Result<serde_json::Value>>;
+    Next::new(&ctx.registry.middlewares, &resolver)
+        .run(ctx)
+        .await
+}
+
+/// Resolves a field, using the resolver registered with `SchemaBuilder::override_field` if there is one.
+async fn resolve_field_inner<T: ObjectType + Send + Sync>(
+    ctx: &Context<'_>,
+    root: &T,
+    field: &Field,
+) -> Result<serde_json::Value> {
+    match ctx
+        .registry
+        .field_resolvers
+        .get(T::type_name().as_ref())
+        .and_then(|fields| fields.get(field.name.as_str()))
+    {
+        Some(resolver) => resolver(ctx).await.map_err(|err| {
+            err.into_error_with_path(field.position, ctx.path_node.as_ref().unwrap().to_json())
+        }),
+        None => root.resolve_field(ctx, field).await,
+    }
+}
+
 #[allow(missing_docs)]
 pub fn collect_fields<'a, T: ObjectType + Send + Sync>(
     ctx: &ContextSelectionSet<'a>,
     root: &'a T,
-    futures: &mut Vec<BoxFieldFuture<'a>>,
+    fields: &mut Fields<'a>,
 ) -> Result<()> {
     if ctx.items.is_empty() {
         return Err(Error::Query {
@@ -42,20 +172,31 @@ pub fn collect_fields<'a, T: ObjectType + Send + Sync>(
 
                 if field.name.as_str() == "__typename" {
                     // Get the typename
-                    let ctx_field = ctx.with_field(field);
-                    let field_name = ctx_field.result_name().to_string();
-                    futures.push(Box::pin(
-                        future::ok::<serde_json::Value, Error>(
-                            root.introspection_type_name().to_string().into(),
+                    fields.add(field, move |field| {
+                        let field_name = field.alias.clone().unwrap_or_else(|| field.name.clone());
+                        Box::pin(
+                            future::ok::<serde_json::Value, Error>(
+                                root.introspection_type_name().to_string().into(),
+                            )
+                            .map_ok(move |value| (field_name, value)),
                         )
-                        .map_ok(move |value| (field_name, value)),
-                    ));
+                    });
                     continue;
                 }
 
-                futures.push(Box::pin({
-                    let ctx = ctx.clone();
-                    async move {
+                if let Some(siblings) = &ctx.siblings {
+                    siblings.register(
+                        field
+                            .alias
+                            .as_deref()
+                            .unwrap_or_else(|| field.name.as_str()),
+                    );
+                }
+
+                let ctx = ctx.clone();
+                fields.add(field, move |field| {
+                    Box::pin(async move {
+                        let field = &*field;
                         let ctx_field = ctx.with_field(field);
                         let field_name = ctx_field.result_name().to_string();
                         let resolve_id = ctx_field.get_resolve_id();
@@ -92,10 +233,11 @@ pub fn collect_fields<'a, T: ObjectType + Send + Sync>(
                                 .for_each(|e| e.resolve_field_start(&resolve_info));
                         }
 
-                        let res = root
-                            .resolve_field(&ctx_field, field)
-                            .map_ok(move |value| (field_name, value))
-                            .await?;
+                        let res = resolve_field(&ctx_field, root, field).await;
+                        if let Some(siblings) = &ctx_field.siblings {
+                            siblings.resolved(&field_name, res.as_ref().ok());
+                        }
+                        let res = (field_name, res?);
 
                         if !ctx_field.extensions.is_empty() {
                             ctx_field
@@ -105,8 +247,8 @@ pub fn collect_fields<'a, T: ObjectType + Send + Sync>(
                         }
 
                         Ok(res)
-                    }
-                }))
+                    })
+                });
             }
             Selection::FragmentSpread(fragment_spread) => {
                 if ctx.is_skip(&fragment_spread.directives)? {
@@ -114,10 +256,12 @@ pub fn collect_fields<'a, T: ObjectType + Send + Sync>(
                 }
 
                 if let Some(fragment) = ctx.fragments.get(fragment_spread.fragment_name.as_str()) {
-                    collect_fields(
+                    let TypeCondition::On(name) = &fragment.type_condition;
+                    root.collect_inline_fields(
+                        name,
+                        fragment_spread.position,
                         &ctx.with_selection_set(&fragment.selection_set),
-                        root,
-                        futures,
+                        fields,
                     )?;
                 } else {
                     return Err(Error::Query {
@@ -139,13 +283,13 @@ pub fn collect_fields<'a, T: ObjectType + Send + Sync>(
                         name,
                         inline_fragment.position,
                         &ctx.with_selection_set(&inline_fragment.selection_set),
-                        futures,
+                        fields,
                     )?;
                 } else {
                     collect_fields(
                         &ctx.with_selection_set(&inline_fragment.selection_set),
                         root,
-                        futures,
+                        fields,
                     )?;
                 }
             }
@@ -0,0 +1,57 @@
+use crate::{impl_scalar_internal, Result, Scalar, Value};
+use std::ops::{Deref, DerefMut};
+
+/// Binary data encoded as a base64 string
+///
+/// The input/output is a string in standard base64 format with padding.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Base64(pub Vec<u8>);
+
+impl Deref for Base64 {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Base64 {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Vec<u8>> for Base64 {
+    fn from(value: Vec<u8>) -> Self {
+        Base64(value)
+    }
+}
+
+impl From<Base64> for Vec<u8> {
+    fn from(value: Base64) -> Self {
+        value.0
+    }
+}
+
+impl Scalar for Base64 {
+    fn type_name() -> &'static str {
+        "Base64"
+    }
+
+    fn description() -> Option<&'static str> {
+        Some("The `Base64` scalar type represents binary data as a base64 string.")
+    }
+
+    fn parse(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(s) => Some(Base64(base64::decode(s).ok()?)),
+            _ => None,
+        }
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value> {
+        Ok(base64::encode(&self.0).into())
+    }
+}
+
+impl_scalar_internal!(Base64);
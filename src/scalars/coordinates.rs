@@ -0,0 +1,71 @@
+use crate::{impl_scalar_internal, Result, Scalar, Value};
+
+/// A geographic position, with a latitude and a longitude in degrees
+///
+/// The output is an object `{ "lat": 48.85, "lng": 2.35 }`. The input is the same object, or a GeoJSON point
+/// `{ "type": "Point", "coordinates": [2.35, 48.85] }` whose longitude comes first. The latitude must be in
+/// `[-90, 90]` and the longitude in `[-180, 180]`.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Coordinates {
+    /// Latitude in degrees
+    pub lat: f64,
+
+    /// Longitude in degrees
+    pub lng: f64,
+}
+
+impl Coordinates {
+    /// Returns the coordinates if the latitude and the longitude are in their ranges.
+    pub fn new(lat: f64, lng: f64) -> Option<Self> {
+        if (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lng) {
+            Some(Coordinates { lat, lng })
+        } else {
+            None
+        }
+    }
+}
+
+fn to_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Int(n) => n.as_i64().map(|n| n as f64),
+        Value::Float(n) => Some(*n),
+        _ => None,
+    }
+}
+
+impl Scalar for Coordinates {
+    fn type_name() -> &'static str {
+        "Coordinates"
+    }
+
+    fn description() -> Option<&'static str> {
+        Some("The `Coordinates` scalar type represents a geographic position as an object `{ lat, lng }` in degrees.")
+    }
+
+    fn parse(value: &Value) -> Option<Self> {
+        let obj = match value {
+            Value::Object(obj) => obj,
+            _ => return None,
+        };
+        match obj.get("type") {
+            Some(Value::String(ty)) if ty == "Point" => match obj.get("coordinates")? {
+                Value::List(coordinates) if coordinates.len() == 2 => {
+                    Coordinates::new(to_f64(&coordinates[1])?, to_f64(&coordinates[0])?)
+                }
+                _ => None,
+            },
+            Some(_) => None,
+            None => Coordinates::new(to_f64(obj.get("lat")?)?, to_f64(obj.get("lng")?)?),
+        }
+    }
+
+    fn accepted_forms() -> &'static [&'static str] {
+        &["{ lat, lng } object", "GeoJSON point"]
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value> {
+        Ok(serde_json::json!({ "lat": self.lat, "lng": self.lng }))
+    }
+}
+
+impl_scalar_internal!(Coordinates);
@@ -1,5 +1,5 @@
 use crate::{impl_scalar_internal, Result, Scalar, Value};
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 
 /// Implement the DateTime<Utc> scalar
 ///
@@ -9,9 +9,13 @@ impl Scalar for DateTime<Utc> {
         "DateTime"
     }
 
+    fn description() -> Option<&'static str> {
+        Some("A date-time string in RFC3339 format, such as `2020-01-01T12:00:00Z`.")
+    }
+
     fn parse(value: &Value) -> Option<Self> {
         match value {
-            Value::String(s) => Some(Utc.datetime_from_str(&s, "%+").ok()?),
+            Value::String(s) => Some(DateTime::parse_from_rfc3339(&s).ok()?.with_timezone(&Utc)),
             _ => None,
         }
     }
@@ -22,3 +26,29 @@ impl Scalar for DateTime<Utc> {
 }
 
 impl_scalar_internal!(DateTime<Utc>);
+
+/// Implement the NaiveDate scalar
+///
+/// The input/output is a string in RFC3339 full-date format, such as `2020-01-01`.
+impl Scalar for NaiveDate {
+    fn type_name() -> &'static str {
+        "NaiveDate"
+    }
+
+    fn description() -> Option<&'static str> {
+        Some("A date string in RFC3339 full-date format, such as `2020-01-01`.")
+    }
+
+    fn parse(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(s) => Some(NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()?),
+            _ => None,
+        }
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value> {
+        Ok(self.format("%Y-%m-%d").to_string().into())
+    }
+}
+
+impl_scalar_internal!(NaiveDate);
@@ -1,5 +1,6 @@
 use crate::{impl_scalar_internal, Result, Scalar, Value};
 use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
 
 /// ID scalar
 ///
@@ -7,6 +8,13 @@ use std::ops::{Deref, DerefMut};
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
 pub struct ID(String);
 
+impl ID {
+    /// Parse the ID as `T`, such as a `u64` or an `Uuid`.
+    pub fn parse<T: FromStr>(&self) -> std::result::Result<T, T::Err> {
+        self.0.parse()
+    }
+}
+
 impl std::fmt::Display for ID {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
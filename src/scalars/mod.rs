@@ -1,27 +1,37 @@
 mod any;
+mod base64;
 mod bool;
 mod chrono_tz;
-mod datetime;
 mod floats;
 mod id;
 mod integers;
 mod string;
+mod timestamp;
 mod url;
 
 #[cfg(feature = "bson")]
 mod bson;
+#[cfg(feature = "coordinates")]
+mod coordinates;
+#[cfg(feature = "chrono")]
+mod datetime;
 #[cfg(feature = "uuid")]
 mod uuid;
 
+pub use self::base64::Base64;
 pub use any::Any;
 pub use id::ID;
+pub use timestamp::{UnixTimestamp, UnixTimestampMillis};
+
+#[cfg(feature = "coordinates")]
+pub use coordinates::Coordinates;
 
 #[cfg(test)]
 mod tests {
     use super::ID;
     use crate::Type;
     use bson::oid::ObjectId;
-    use chrono::{DateTime, Utc};
+    use chrono::{DateTime, NaiveDate, Utc};
     use uuid::Uuid;
 
     #[test]
@@ -50,6 +60,9 @@ mod tests {
             "DateTime!"
         );
 
+        assert_eq!(<NaiveDate as Type>::type_name(), "NaiveDate");
+        assert_eq!(<NaiveDate as Type>::qualified_type_name(), "NaiveDate!");
+
         #[cfg(feature = "uuid")]
         {
             assert_eq!(<Uuid as Type>::type_name(), "UUID");
@@ -49,6 +49,7 @@ impl<'a> Type for &'a str {
                 Value::String(_) => true,
                 _ => false,
             },
+            accepted_forms: &[],
         })
     }
 }
@@ -0,0 +1,86 @@
+use crate::{impl_scalar_internal, FieldError, Pos, Result, Scalar, Value};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+macro_rules! impl_timestamp_scalars {
+    ($($(#[$meta:meta])* $name:ident($unit:literal, $from_unit:path, $as_unit:ident);)*) => {
+        $(
+        $(#[$meta])*
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+        pub struct $name(pub SystemTime);
+
+        impl From<SystemTime> for $name {
+            fn from(value: SystemTime) -> Self {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for SystemTime {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl Scalar for $name {
+            fn type_name() -> &'static str {
+                stringify!($name)
+            }
+
+            fn description() -> Option<&'static str> {
+                Some(concat!("The `", stringify!($name), "` scalar type represents a point in time as the number of ", $unit, " since the Unix epoch."))
+            }
+
+            fn parse(value: &Value) -> Option<Self> {
+                match value {
+                    Value::Int(n) => {
+                        let n = n.as_i64()?;
+                        if n < 0 {
+                            return None;
+                        }
+                        UNIX_EPOCH.checked_add($from_unit(n as u64)).map($name)
+                    }
+                    _ => None,
+                }
+            }
+
+            fn to_json(&self) -> Result<serde_json::Value> {
+                let n = self
+                    .0
+                    .duration_since(UNIX_EPOCH)
+                    .ok()
+                    .and_then(|duration| {
+                        let n = duration.$as_unit();
+                        if n <= i64::max_value() as _ {
+                            Some(n as i64)
+                        } else {
+                            None
+                        }
+                    })
+                    .ok_or_else(|| {
+                        FieldError::new(
+                            concat!("The time can't be represented as ", stringify!($name)),
+                            None,
+                        )
+                        .into_error(Pos::default())
+                    })?;
+                Ok(n.into())
+            }
+        }
+
+        impl_scalar_internal!($name);
+        )*
+    };
+}
+
+impl_timestamp_scalars! {
+    /// UnixTimestamp scalar
+    ///
+    /// A std-only timestamp that maps `SystemTime` to/from the number of seconds since the Unix epoch,
+    /// use `UnixTimestampMillis` for milliseconds. Negative or out of range values are rejected.
+    UnixTimestamp("seconds", Duration::from_secs, as_secs);
+
+    /// UnixTimestampMillis scalar
+    ///
+    /// A std-only timestamp that maps `SystemTime` to/from the number of milliseconds since the Unix epoch.
+    /// Negative or out of range values are rejected.
+    UnixTimestampMillis("milliseconds", Duration::from_millis, as_millis);
+}
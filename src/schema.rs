@@ -1,22 +1,28 @@
 use crate::context::Data;
 use crate::extensions::{BoxExtension, Extension};
-use crate::model::__DirectiveLocation;
-use crate::query::QueryBuilder;
+use crate::middleware::Middleware;
+use crate::model::{__DirectiveLocation, INTROSPECTION_QUERY};
+use crate::persisted_query::PersistedQueryCache;
+use crate::query::{operation_names, query_hash, QueryBuilder};
+use crate::query_cache::QueryCache;
 use crate::registry::{Directive, InputValue, Registry};
 use crate::subscription::{create_connection, create_subscription_stream, SubscriptionTransport};
-use crate::types::QueryRoot;
+use crate::types::{QueryRoot, INTROSPECTION_CACHE_SIZE};
 use crate::validation::{check_rules, ValidationMode};
+use crate::value::parse_query_source;
 use crate::{
-    Environment, Error, ObjectType, Pos, QueryError, QueryResponse, Result, SubscriptionStream,
-    SubscriptionType, Type, Variables,
+    Context, Environment, FieldResult, ObjectType, Pos, QueryError, QueryResponse, Result,
+    SchemaError, SubscriptionStream, SubscriptionType, Type, Variables,
 };
 use bytes::Bytes;
 use futures::channel::mpsc;
+use futures::future::BoxFuture;
 use futures::Stream;
-use graphql_parser::parse_query;
 use graphql_parser::query::{Definition, OperationDefinition};
+use lru::LruCache;
+use parking_lot::Mutex;
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
@@ -29,7 +35,11 @@ pub(crate) struct SchemaInner<Query, Mutation, Subscription> {
     pub(crate) data: Data,
     pub(crate) complexity: Option<usize>,
     pub(crate) depth: Option<usize>,
+    pub(crate) safelist: Option<HashSet<String>>,
     pub(crate) extensions: Vec<Box<dyn Fn() -> BoxExtension + Send + Sync>>,
+    pub(crate) variables_preprocessor: Option<Box<dyn Fn(Variables) -> Variables + Send + Sync>>,
+    pub(crate) persisted_query_cache: Option<Box<dyn PersistedQueryCache>>,
+    pub(crate) query_cache: Option<Box<dyn QueryCache>>,
 }
 
 /// Schema builder
@@ -45,23 +55,190 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
     }
 
     /// Disable introspection query
+    ///
+    /// Queries selecting `__schema` or `__type` fail the validation, `__typename` is still allowed.
     pub fn disable_introspection(mut self) -> Self {
         self.0.query.disable_introspection = true;
         self
     }
 
+    /// Only allow introspection queries when the guard returns `Ok`.
+    ///
+    /// The guard is called before resolving `__schema` or `__type`, the error it returns is the error of the field.
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct Developer;
+    ///
+    /// struct QueryRoot;
+    ///
+    /// #[Object]
+    /// impl QueryRoot {}
+    ///
+    /// let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+    ///     .introspection_guard(|ctx| match ctx.data_opt::<Developer>() {
+    ///         Some(_) => Ok(()),
+    ///         None => Err("Forbidden".into()),
+    ///     })
+    ///     .finish();
+    /// ```
+    pub fn introspection_guard<F>(mut self, guard: F) -> Self
+    where
+        F: Fn(&Context<'_>) -> FieldResult<()> + Send + Sync + 'static,
+    {
+        self.0.query.introspection_guard = Some(Box::new(guard));
+        self
+    }
+
     /// Set limit complexity, Default no limit.
+    ///
+    /// The complexity of a field is `1` plus the complexity of its selection set, unless the field has a
+    /// `complexity` expression, see `Object`.
     pub fn limit_complexity(mut self, complexity: usize) -> Self {
         self.0.complexity = Some(complexity);
         self
     }
 
+    /// Don't count the deprecated fields in the complexity, their selection sets are still counted, default is `false`.
+    ///
+    /// Clients migrating away from the deprecated fields are not rejected by the complexity limit because of them.
+    pub fn ignore_deprecated_complexity(mut self, enable: bool) -> Self {
+        self.0.registry.ignore_deprecated_complexity = enable;
+        self
+    }
+
     /// Set limit complexity, Default no limit.
     pub fn limit_depth(mut self, depth: usize) -> Self {
         self.0.depth = Some(depth);
         self
     }
 
+    /// Only allow queries whose hash is in the safelist, any other query is rejected with `QueryError::QueryNotAllowed`.
+    ///
+    /// The hash of a query is computed with `query_hash`. Calling it multiple times extends the safelist.
+    pub fn safelist<I, T>(mut self, hashes: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.0
+            .safelist
+            .get_or_insert_with(Default::default)
+            .extend(hashes.into_iter().map(Into::into));
+        self
+    }
+
+    /// Enable or disable the federation types (`_service`, `_entities` and their types).
+    ///
+    /// By default, they are only added if there are types with keys.
+    pub fn enable_federation(mut self, enable: bool) -> Self {
+        if enable {
+            self.0.registry.create_federation_types();
+        } else {
+            self.0.registry.remove_federation_types();
+        }
+        self
+    }
+
+    /// Render an `extend schema @link(...)` preamble at the top of the federation SDL, as required by Apollo Federation 2.
+    ///
+    /// `url` is the url of the federation specification, such as `https://specs.apollo.dev/federation/v2.0`.
+    /// The federation directives used by the types are imported.
+    pub fn federation_link<T: Into<String>>(mut self, url: T) -> Self {
+        self.0.registry.federation_link = Some(url.into());
+        self
+    }
+
+    /// Add `__typename` to the result of every object except the root operation type, even if the query doesn't
+    /// select it, default is `false`.
+    ///
+    /// This is for clients whose cache requires `__typename` everywhere, such as Apollo Client.
+    pub fn auto_typename(mut self, enable: bool) -> Self {
+        self.0.registry.auto_typename = enable;
+        self
+    }
+
+    /// Sort the keys of the objects in the response alphabetically, default is `false`.
+    ///
+    /// The GraphQL specification requires the keys to be in the order of the selection set, this is for snapshot tests
+    /// whose results must not depend on the order of the query.
+    pub fn sort_response_keys(mut self, enable: bool) -> Self {
+        self.0.registry.sort_response_keys = enable;
+        self
+    }
+
+    /// Allow the fields to get the values of their sibling fields with `Context::sibling_value`, default is `false`.
+    ///
+    /// The value of every field is kept until all the fields of its object are resolved, so it's disabled by default.
+    pub fn sibling_values(mut self, enable: bool) -> Self {
+        self.0.registry.sibling_values = enable;
+        self
+    }
+
+    /// Register a custom directive, it appears in the introspection and the validation checks where it is used.
+    ///
+    /// The executor ignores the directive, a field middleware can find it in the `directives` of the field
+    /// being resolved to change the result of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_graphql::*;
+    ///
+    /// struct QueryRoot;
+    ///
+    /// #[Object]
+    /// impl QueryRoot {}
+    ///
+    /// let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+    ///     .register_directive(Directive {
+    ///         name: "uppercase",
+    ///         description: Some("Converts the result of the field to uppercase."),
+    ///         locations: vec![DirectiveLocation::FIELD],
+    ///         args: Default::default(),
+    ///         is_repeatable: false,
+    ///     })
+    ///     .finish();
+    /// ```
+    pub fn register_directive(mut self, directive: Directive) -> Self {
+        self.0.registry.add_directive(directive);
+        self
+    }
+
+    /// Add a field middleware, see `Middleware`.
+    pub fn middleware<M: Middleware>(mut self, middleware: M) -> Self {
+        self.0.registry.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Override the resolver of a field, or add a new field to an object type that has been registered.
+    ///
+    /// `ty` is the qualified type name of the field, such as `Int!` or `[String]`.
+    /// The value returned by the resolver is not checked against `ty`, a mismatch will produce a response
+    /// that doesn't conform to the schema, so use it with care.
+    ///
+    /// If `type_name` is not a registered object type, or `ty` is not a registered output type, the error is
+    /// returned by `SchemaBuilder::try_finish`.
+    pub fn override_field<F>(
+        mut self,
+        type_name: &str,
+        field_name: &str,
+        ty: &str,
+        resolver: F,
+    ) -> Self
+    where
+        F: for<'a> Fn(&'a Context<'_>) -> BoxFuture<'a, FieldResult<serde_json::Value>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.0
+            .registry
+            .override_field(type_name, field_name, ty, Box::new(resolver));
+        self
+    }
+
     /// Add an extension
     pub fn extension<F: Fn() -> E + Send + Sync + 'static, E: Extension>(
         mut self,
@@ -73,6 +250,35 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
         self
     }
 
+    /// Set a callback that receives the variables of every query and subscription and returns the variables to use.
+    ///
+    /// It is called after the variables have been parsed and before they are checked against the types of the
+    /// arguments, so it can be used to coerce the incoming values, such as trimming strings or normalizing case.
+    pub fn variables_preprocessor<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Variables) -> Variables + Send + Sync + 'static,
+    {
+        self.0.variables_preprocessor = Some(Box::new(f));
+        self
+    }
+
+    /// Enable automatic persisted queries, the queries are stored in `cache`, see `persisted_query`.
+    ///
+    /// `LruPersistedQueryCache` is an in-memory cache that can be used if there is a single server.
+    pub fn persisted_query_cache<C: PersistedQueryCache>(mut self, cache: C) -> Self {
+        self.0.persisted_query_cache = Some(Box::new(cache));
+        self
+    }
+
+    /// Cache the parsed and validated queries in `cache`, so a query that is in the cache isn't parsed and
+    /// validated again, see `query_cache`.
+    ///
+    /// `LruQueryCache` is an in-memory cache of the least recently used queries.
+    pub fn query_cache<C: QueryCache>(mut self, cache: C) -> Self {
+        self.0.query_cache = Some(Box::new(cache));
+        self
+    }
+
     /// Add a global data that can be accessed in the `Schema`, you access it with `Context::data`.
     pub fn data<D: Any + Send + Sync>(mut self, data: D) -> Self {
         self.0.data.insert(data);
@@ -89,6 +295,25 @@ impl<Query: ObjectType, Mutation: ObjectType, Subscription: SubscriptionType>
     pub fn finish(self) -> Schema<Query, Mutation, Subscription> {
         Schema(Arc::new(self.0))
     }
+
+    /// Build schema, returns an error if the schema is invalid, such as an interface whose implementer is not registered,
+    /// a type with two fields of the same name, a field whose name starts with `__`, an invalid field override, or two
+    /// extensions with the same name.
+    pub fn try_finish(
+        self,
+    ) -> std::result::Result<Schema<Query, Mutation, Subscription>, SchemaError> {
+        self.0.registry.check()?;
+        let mut extension_names = HashSet::new();
+        for factory in &self.0.extensions {
+            let name = factory().name();
+            if !extension_names.insert(name) {
+                return Err(SchemaError::DuplicateExtensionName {
+                    name: name.to_string(),
+                });
+            }
+        }
+        Ok(self.finish())
+    }
 }
 
 /// GraphQL schema
@@ -133,6 +358,14 @@ where
             } else {
                 Some(Subscription::type_name().to_string())
             },
+            field_resolvers: Default::default(),
+            federation_link: None,
+            auto_typename: false,
+            sort_response_keys: false,
+            sibling_values: false,
+            ignore_deprecated_complexity: false,
+            middlewares: Default::default(),
+            schema_errors: Default::default(),
         };
 
         registry.add_directive(Directive {
@@ -151,9 +384,11 @@ where
                     ty: "Boolean!".to_string(),
                     default_value: None,
                     validator: None,
+                    transformer: None,
                 });
                 args
-            }
+            },
+            is_repeatable: false,
         });
 
         registry.add_directive(Directive {
@@ -172,9 +407,11 @@ where
                     ty: "Boolean!".to_string(),
                     default_value: None,
                     validator: None,
+                    transformer: None,
                 });
                 args
-            }
+            },
+            is_repeatable: false,
         });
 
         // register scalars
@@ -192,13 +429,17 @@ where
         }
 
         // federation
-        registry.create_federation_types();
+        if registry.has_entities() {
+            registry.create_federation_types();
+        }
 
         SchemaBuilder(SchemaInner {
             validation_mode: ValidationMode::Strict,
             query: QueryRoot {
                 inner: query,
                 disable_introspection: false,
+                introspection_guard: None,
+                introspection_cache: Mutex::new(LruCache::new(INTROSPECTION_CACHE_SIZE)),
             },
             mutation,
             subscription,
@@ -206,7 +447,11 @@ where
             data: Default::default(),
             complexity: None,
             depth: None,
+            safelist: None,
             extensions: Default::default(),
+            variables_preprocessor: None,
+            persisted_query_cache: None,
+            query_cache: None,
         })
     }
 
@@ -229,11 +474,55 @@ where
         &self.0.registry
     }
 
+    /// Returns the SDL of the whole schema.
+    ///
+    /// It has the `schema` definition, the custom directives and all the types except the introspection types and
+    /// the built-in scalars, with their descriptions, arguments, default values and deprecations. Everything is
+    /// sorted by name, so the SDL of a schema doesn't change between builds.
+    pub fn sdl(&self) -> String {
+        self.0.registry.create_sdl()
+    }
+
+    /// Returns the complexity limit, `None` (the default) means there is no limit.
+    pub fn complexity_limit(&self) -> Option<usize> {
+        self.0.complexity
+    }
+
+    /// Returns the depth limit, `None` (the default) means there is no limit.
+    pub fn depth_limit(&self) -> Option<usize> {
+        self.0.depth
+    }
+
+    /// Returns the validation mode, the default is `ValidationMode::Strict`.
+    pub fn validation_mode(&self) -> ValidationMode {
+        self.0.validation_mode
+    }
+
+    /// Returns whether the introspection queries are disabled, they are enabled by default.
+    pub fn introspection_disabled(&self) -> bool {
+        self.0.query.disable_introspection
+    }
+
+    /// Returns whether only the queries in the safelist are allowed, the default is to allow any query.
+    pub fn has_safelist(&self) -> bool {
+        self.0.safelist.is_some()
+    }
+
     /// Execute query without create the `QueryBuilder`.
     pub async fn execute(&self, query_source: &str) -> Result<QueryResponse> {
         QueryBuilder::new(query_source).execute(self).await
     }
 
+    /// Execute the standard introspection query and returns the result as JSON.
+    ///
+    /// The result is the same as the `data` that GraphQL tools get when they introspect the schema.
+    /// The introspection query is executed even if it isn't in the safelist, see `SchemaBuilder::safelist`.
+    pub async fn export_introspection_json(&self) -> Result<serde_json::Value> {
+        let mut builder = QueryBuilder::new(INTROSPECTION_QUERY);
+        builder.internal = true;
+        Ok(builder.execute(self).await?.data)
+    }
+
     /// Create subscription stream, typically called inside the `SubscriptionTransport::handle_request` method
     pub async fn create_subscription_stream(
         &self,
@@ -242,12 +531,24 @@ where
         variables: Variables,
         ctx_data: Option<Arc<Data>>,
     ) -> Result<impl Stream<Item = serde_json::Value> + Send> {
-        let document = parse_query(source).map_err(Into::<Error>::into)?;
-        check_rules(&self.0.registry, &document, self.0.validation_mode)?;
+        if let Some(safelist) = &self.0.safelist {
+            if !safelist.contains(&query_hash(source)) {
+                return Err(QueryError::QueryNotAllowed.into_error(Pos::default()));
+            }
+        }
+
+        let document = parse_query_source(source)?;
+        check_rules(
+            &self.0.registry,
+            &document,
+            self.0.validation_mode,
+            self.0.query.disable_introspection,
+        )?;
 
         let mut fragments = HashMap::new();
         let mut subscription = None;
 
+        let available = operation_names(&document);
         for definition in document.definitions {
             match definition {
                 Definition::Operation(OperationDefinition::Subscription(s)) => {
@@ -266,14 +567,21 @@ where
         let subscription = subscription.ok_or(if let Some(name) = operation_name {
             QueryError::UnknownOperationNamed {
                 name: name.to_string(),
+                available,
             }
             .into_error(Pos::default())
         } else {
             QueryError::MissingOperation.into_error(Pos::default())
         })?;
 
+        let variables = match &self.0.variables_preprocessor {
+            Some(variables_preprocessor) => variables_preprocessor(variables),
+            None => variables,
+        };
+
         let resolve_id = AtomicUsize::default();
         let environment = Arc::new(Environment {
+            operation_name: subscription.name.clone(),
             variables,
             variable_definitions: subscription.variable_definitions,
             fragments,
@@ -0,0 +1,86 @@
+use crate::FieldResult;
+use futures::channel::oneshot;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+
+enum State {
+    Pending(Vec<oneshot::Sender<Option<serde_json::Value>>>),
+    Resolved(serde_json::Value),
+    Failed,
+}
+
+pub(crate) enum SiblingValue {
+    Ready(Option<serde_json::Value>),
+    Pending(oneshot::Receiver<Option<serde_json::Value>>),
+}
+
+#[derive(Default)]
+struct Inner {
+    fields: HashMap<String, State>,
+
+    /// The field that each field is waiting for, used to reject the waits that would never end.
+    waiting: HashMap<String, String>,
+}
+
+/// The values of the fields of an object by response key, see `Context::sibling_value`.
+#[derive(Default)]
+pub(crate) struct Siblings(Mutex<Inner>);
+
+impl Siblings {
+    /// Registers a selected field, it must be done before any field is resolved.
+    pub(crate) fn register(&self, name: &str) {
+        self.0
+            .lock()
+            .fields
+            .entry(name.to_string())
+            .or_insert_with(|| State::Pending(Vec::new()));
+    }
+
+    /// Sets the value of a field, `None` if its resolver failed, and wakes up the fields waiting for it.
+    pub(crate) fn resolved(&self, name: &str, value: Option<&serde_json::Value>) {
+        let mut inner = self.0.lock();
+        if let Some(state) = inner.fields.get_mut(name) {
+            if let State::Pending(senders) = state {
+                for sender in std::mem::take(senders) {
+                    sender.send(value.cloned()).ok();
+                }
+                *state = match value {
+                    Some(value) => State::Resolved(value.clone()),
+                    None => State::Failed,
+                };
+            }
+        }
+        inner.waiting.retain(|_, target| target != name);
+    }
+
+    /// Gets the value of the field `name` for the field `waiter`.
+    pub(crate) fn get(&self, waiter: &str, name: &str) -> FieldResult<SiblingValue> {
+        let mut inner = self.0.lock();
+
+        let mut target = name;
+        loop {
+            if target == waiter {
+                return Err(format!(
+                    "The field \"{}\" can't wait for \"{}\", it is waiting for \"{}\"",
+                    waiter, name, waiter
+                )
+                .into());
+            }
+            match inner.waiting.get(target) {
+                Some(next) => target = next,
+                None => break,
+            }
+        }
+
+        match inner.fields.get_mut(name) {
+            Some(State::Pending(senders)) => {
+                let (tx, rx) = oneshot::channel();
+                senders.push(tx);
+                inner.waiting.insert(waiter.to_string(), name.to_string());
+                Ok(SiblingValue::Pending(rx))
+            }
+            Some(State::Resolved(value)) => Ok(SiblingValue::Ready(Some(value.clone()))),
+            Some(State::Failed) | None => Ok(SiblingValue::Ready(None)),
+        }
+    }
+}
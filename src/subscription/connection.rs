@@ -3,9 +3,11 @@ use bytes::Bytes;
 use futures::channel::mpsc;
 use futures::task::{AtomicWaker, Context, Poll};
 use futures::Stream;
+use futures_timer::Delay;
 use slab::Slab;
 use std::future::Future;
 use std::pin::Pin;
+use std::time::Duration;
 
 /// Use to hold all subscription stream for the `SubscriptionConnection`
 pub struct SubscriptionStreams {
@@ -21,8 +23,16 @@ impl SubscriptionStreams {
         self.streams.insert(Box::pin(stream))
     }
 
+    /// Removes a stream, does nothing if the stream has already ended.
     pub fn remove(&mut self, id: usize) {
-        self.streams.remove(id);
+        if self.streams.contains(id) {
+            self.streams.remove(id);
+        }
+    }
+
+    /// Removes all the streams, they are dropped so their resources are released.
+    pub fn clear(&mut self) {
+        self.streams.clear();
     }
 }
 
@@ -50,6 +60,27 @@ pub trait SubscriptionTransport: Send + Sync + Unpin + 'static {
 
     /// When a response message is generated, you can convert the message to the format you want here.
     fn handle_response(&mut self, id: usize, value: serde_json::Value) -> Option<Bytes>;
+
+    /// Called when `handle_request` returns an error, the returned `Bytes` are sent to the client before the
+    /// connection is closed.
+    ///
+    /// Default is `None`, the connection is closed without sending anything.
+    fn handle_error(&mut self, err: Self::Error) -> Option<Bytes> {
+        let _ = err;
+        None
+    }
+
+    /// The interval after which a keep-alive message is sent if nothing else has been sent to the client.
+    ///
+    /// Default is `None`, no keep-alive message is sent.
+    fn keep_alive_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Creates the keep-alive message sent to the client.
+    fn keep_alive_message(&mut self) -> Option<Bytes> {
+        None
+    }
 }
 
 pub fn create_connection<Query, Mutation, Subscription, T: SubscriptionTransport>(
@@ -65,6 +96,9 @@ where
     Subscription: SubscriptionType + Sync + Send + 'static,
 {
     let (tx_bytes, rx_bytes) = mpsc::channel(8);
+    let keep_alive = transport
+        .keep_alive_interval()
+        .map(|interval| (interval, Delay::new(interval)));
     (
         tx_bytes,
         SubscriptionStream {
@@ -76,6 +110,8 @@ where
             rx_bytes,
             handle_request_fut: None,
             waker: AtomicWaker::new(),
+            keep_alive,
+            closed: false,
         },
     )
 }
@@ -97,20 +133,23 @@ pub struct SubscriptionStream<Query, Mutation, Subscription, T: SubscriptionTran
     rx_bytes: mpsc::Receiver<Bytes>,
     handle_request_fut: Option<HandleRequestBoxFut<T>>,
     waker: AtomicWaker,
+    keep_alive: Option<(Duration, Delay)>,
+    closed: bool,
 }
 
-impl<Query, Mutation, Subscription, T> Stream
-    for SubscriptionStream<Query, Mutation, Subscription, T>
+impl<Query, Mutation, Subscription, T> SubscriptionStream<Query, Mutation, Subscription, T>
 where
     Query: ObjectType + Send + Sync + 'static,
     Mutation: ObjectType + Send + Sync + 'static,
     Subscription: SubscriptionType + Send + Sync + 'static,
     T: SubscriptionTransport,
 {
-    type Item = Bytes;
+    fn poll_message(&mut self, cx: &mut Context<'_>) -> Poll<Option<Bytes>> {
+        let this = self;
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let this = &mut *self;
+        if this.closed {
+            return Poll::Ready(None);
+        }
 
         loop {
             // receive bytes
@@ -123,7 +162,11 @@ where
                         }
                         continue;
                     }
-                    Poll::Ready(Err(_)) => return Poll::Ready(None),
+                    Poll::Ready(Err(err)) => {
+                        this.handle_request_fut = None;
+                        this.closed = true;
+                        return Poll::Ready(this.transport.handle_error(err));
+                    }
                     Poll::Pending => {}
                 }
             } else {
@@ -176,3 +219,40 @@ where
         }
     }
 }
+
+impl<Query, Mutation, Subscription, T> Stream
+    for SubscriptionStream<Query, Mutation, Subscription, T>
+where
+    Query: ObjectType + Send + Sync + 'static,
+    Mutation: ObjectType + Send + Sync + 'static,
+    Subscription: SubscriptionType + Send + Sync + 'static,
+    T: SubscriptionTransport,
+{
+    type Item = Bytes;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        match this.poll_message(cx) {
+            Poll::Ready(Some(bytes)) => {
+                // Any outgoing data resets the keep-alive timer.
+                if let Some((interval, delay)) = &mut this.keep_alive {
+                    delay.reset(*interval);
+                }
+                Poll::Ready(Some(bytes))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => {
+                if let Some((interval, delay)) = &mut this.keep_alive {
+                    while Pin::new(&mut *delay).poll(cx).is_ready() {
+                        delay.reset(*interval);
+                        if let Some(bytes) = this.transport.keep_alive_message() {
+                            return Poll::Ready(Some(bytes));
+                        }
+                    }
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
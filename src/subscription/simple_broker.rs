@@ -11,9 +11,13 @@ use std::sync::Mutex;
 
 static SUBSCRIBERS: Lazy<Mutex<HashMap<TypeId, Box<dyn Any + Send>>>> = Lazy::new(Default::default);
 
-struct Senders<T>(Slab<UnboundedSender<T>>);
+struct Senders<T> {
+    all: Slab<UnboundedSender<T>>,
+    topics: HashMap<String, Slab<UnboundedSender<T>>>,
+}
 
-struct BrokerStream<T: Sync + Send + Clone + 'static>(usize, UnboundedReceiver<T>);
+/// The topic of the stream, or `None` if it receives the messages published with `SimpleBroker::publish`.
+struct BrokerStream<T: Sync + Send + Clone + 'static>(Option<String>, usize, UnboundedReceiver<T>);
 
 fn with_senders<T, F, R>(f: F) -> R
 where
@@ -21,15 +25,30 @@ where
     F: FnOnce(&mut Senders<T>) -> R,
 {
     let mut map = SUBSCRIBERS.lock().unwrap();
-    let senders = map
-        .entry(TypeId::of::<Senders<T>>())
-        .or_insert_with(|| Box::new(Senders::<T>(Default::default())));
+    let senders = map.entry(TypeId::of::<Senders<T>>()).or_insert_with(|| {
+        Box::new(Senders::<T> {
+            all: Default::default(),
+            topics: Default::default(),
+        })
+    });
     f(senders.downcast_mut::<Senders<T>>().unwrap())
 }
 
 impl<T: Sync + Send + Clone + 'static> Drop for BrokerStream<T> {
     fn drop(&mut self) {
-        with_senders::<T, _, _>(|senders| senders.0.remove(self.0));
+        with_senders::<T, _, _>(|senders| match &self.0 {
+            Some(topic) => {
+                if let Some(topic_senders) = senders.topics.get_mut(topic) {
+                    topic_senders.remove(self.1);
+                    if topic_senders.is_empty() {
+                        senders.topics.remove(topic);
+                    }
+                }
+            }
+            None => {
+                senders.all.remove(self.1);
+            }
+        });
     }
 }
 
@@ -37,18 +56,21 @@ impl<T: Sync + Send + Clone + 'static> Stream for BrokerStream<T> {
     type Item = T;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.1.poll_next_unpin(cx)
+        self.2.poll_next_unpin(cx)
     }
 }
 
 /// A simple broker based on memory
+///
+/// Messages can also be published to a topic, e.g. a chat room, with `SimpleBroker::publish_to`, they are only
+/// received by the streams created with `SimpleBroker::subscribe_to` for the same topic.
 pub struct SimpleBroker<T>(PhantomData<T>);
 
 impl<T: Sync + Send + Clone + 'static> SimpleBroker<T> {
     /// Publish a message that all subscription streams can receive.
     pub fn publish(msg: T) {
         with_senders::<T, _, _>(|senders| {
-            for (_, sender) in senders.0.iter_mut() {
+            for (_, sender) in senders.all.iter_mut() {
                 sender.start_send(msg.clone()).ok();
             }
         });
@@ -58,8 +80,29 @@ impl<T: Sync + Send + Clone + 'static> SimpleBroker<T> {
     pub fn subscribe() -> impl Stream<Item = T> {
         with_senders::<T, _, _>(|senders| {
             let (tx, rx) = mpsc::unbounded();
-            let id = senders.0.insert(tx);
-            BrokerStream(id, rx)
+            let id = senders.all.insert(tx);
+            BrokerStream(None, id, rx)
+        })
+    }
+
+    /// Publish a message that the subscription streams of the topic `key` can receive.
+    pub fn publish_to<K: AsRef<str>>(key: K, msg: T) {
+        with_senders::<T, _, _>(|senders| {
+            if let Some(topic_senders) = senders.topics.get_mut(key.as_ref()) {
+                for (_, sender) in topic_senders.iter_mut() {
+                    sender.start_send(msg.clone()).ok();
+                }
+            }
+        });
+    }
+
+    /// Subscribe to the message of the specified type published to the topic `key` and returns a `Stream`.
+    pub fn subscribe_to<K: Into<String>>(key: K) -> impl Stream<Item = T> {
+        let topic = key.into();
+        with_senders::<T, _, _>(|senders| {
+            let (tx, rx) = mpsc::unbounded();
+            let id = senders.topics.entry(topic.clone()).or_default().insert(tx);
+            BrokerStream(Some(topic), id, rx)
         })
     }
 }
@@ -1,7 +1,7 @@
 use crate::context::Environment;
-use crate::{Context, ContextSelectionSet, ObjectType, Result, Schema, Type};
+use crate::{Context, ContextSelectionSet, ObjectType, QueryError, Result, Schema, Type};
 use futures::{Future, Stream};
-use graphql_parser::query::{Selection, TypeCondition};
+use graphql_parser::query::{Field, Selection, TypeCondition};
 use std::pin::Pin;
 use std::sync::Arc;
 
@@ -27,6 +27,37 @@ pub trait SubscriptionType: Type {
         Self: Send + Sync + 'static + Sized;
 }
 
+/// Uploaded files are only delivered by multipart requests, reject the fields that take an `Upload`
+/// argument instead of resolving them with a missing file.
+fn check_upload_arguments<Subscription: Type>(
+    ctx: &ContextSelectionSet<'_>,
+    field: &Field,
+) -> Result<()> {
+    let registry_field = ctx
+        .registry
+        .types
+        .get(Subscription::type_name().as_ref())
+        .and_then(|ty| ty.field_by_name(&field.name));
+    if let Some(registry_field) = registry_field {
+        for (name, _) in &field.arguments {
+            let is_upload = registry_field
+                .args
+                .get(name.as_str())
+                .and_then(|arg| ctx.registry.concrete_type_by_name(&arg.ty))
+                .map(|ty| ty.name() == "Upload")
+                .unwrap_or_default();
+            if is_upload {
+                return Err(QueryError::UploadNotSupportedOverSubscription {
+                    field_name: field.name.clone(),
+                    arg_name: name.clone(),
+                }
+                .into_error(field.position));
+            }
+        }
+    }
+    Ok(())
+}
+
 type BoxCreateStreamFuture<'a> = Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>;
 
 pub fn create_subscription_stream<'a, Query, Mutation, Subscription>(
@@ -47,6 +78,7 @@ where
                     if ctx.is_skip(&field.directives)? {
                         continue;
                     }
+                    check_upload_arguments::<Subscription>(ctx, field)?;
                     streams.push(
                         schema
                             .0
@@ -1,12 +1,13 @@
 use crate::context::Data;
 use crate::http::{GQLError, GQLRequest, GQLResponse};
 use crate::{
-    FieldError, FieldResult, ObjectType, QueryResponse, Schema, SubscriptionStreams,
+    FieldError, FieldResult, ObjectType, OperationType, QueryResponse, Schema, SubscriptionStreams,
     SubscriptionTransport, SubscriptionType, Variables,
 };
 use bytes::Bytes;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Serialize, Deserialize)]
 struct OperationMessage {
@@ -21,16 +22,35 @@ struct OperationMessage {
 }
 
 /// WebSocket transport
-#[derive(Default)]
+///
+/// A `ka` (keep-alive) message is sent every 30 seconds when nothing else is sent to the client,
+/// use `WebSocketTransport::keep_alive_interval` to change it.
 pub struct WebSocketTransport {
     id_to_sid: HashMap<String, usize>,
     sid_to_id: HashMap<usize, String>,
     data: Arc<Data>,
     init_context_data: Option<Box<dyn Fn(serde_json::Value) -> FieldResult<Data> + Send + Sync>>,
+    keep_alive_interval: Option<Duration>,
+    init_failed: bool,
+}
+
+impl Default for WebSocketTransport {
+    fn default() -> Self {
+        WebSocketTransport {
+            id_to_sid: Default::default(),
+            sid_to_id: Default::default(),
+            data: Default::default(),
+            init_context_data: None,
+            keep_alive_interval: Some(Duration::from_secs(30)),
+            init_failed: false,
+        }
+    }
 }
 
 impl WebSocketTransport {
     /// Creates a websocket transport and sets the function that converts the `payload` of the `connect_init` message to `Data`.
+    ///
+    /// If the function returns an error, a `connection_error` message with the error is sent and the connection is closed.
     pub fn new<F: Fn(serde_json::Value) -> FieldResult<Data> + Send + Sync + 'static>(
         init_context_data: F,
     ) -> Self {
@@ -39,6 +59,12 @@ impl WebSocketTransport {
             ..WebSocketTransport::default()
         }
     }
+
+    /// Sets the interval of the keep-alive messages, `None` to disable them.
+    pub fn keep_alive_interval(mut self, interval: Option<Duration>) -> Self {
+        self.keep_alive_interval = interval;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -61,7 +87,13 @@ impl SubscriptionTransport for WebSocketTransport {
                 "connection_init" => {
                     if let Some(payload) = msg.payload {
                         if let Some(init_context_data) = &self.init_context_data {
-                            self.data = Arc::new(init_context_data(payload)?);
+                            match init_context_data(payload) {
+                                Ok(data) => self.data = Arc::new(data),
+                                Err(err) => {
+                                    self.init_failed = true;
+                                    return Err(err);
+                                }
+                            }
                         }
                     }
                     Ok(Some(
@@ -93,8 +125,13 @@ impl SubscriptionTransport for WebSocketTransport {
                             {
                                 Ok(stream) => {
                                     let stream_id = streams.add(stream);
-                                    self.id_to_sid.insert(id.clone(), stream_id);
-                                    self.sid_to_id.insert(stream_id, id);
+                                    // The stream id of a subscription that has ended can be reused.
+                                    if let Some(stale_id) =
+                                        self.sid_to_id.insert(stream_id, id.clone())
+                                    {
+                                        self.id_to_sid.remove(&stale_id);
+                                    }
+                                    self.id_to_sid.insert(id, stream_id);
                                     Ok(None)
                                 }
                                 Err(err) => Ok(Some(
@@ -125,7 +162,14 @@ impl SubscriptionTransport for WebSocketTransport {
                     }
                     Ok(None)
                 }
-                "connection_terminate" => Err("connection_terminate".into()),
+                "connection_terminate" => {
+                    // Unsubscribe all the streams before closing the connection, so that the resources
+                    // they hold (e.g. `SimpleBroker` subscriptions) are released right away.
+                    self.id_to_sid.clear();
+                    self.sid_to_id.clear();
+                    streams.clear();
+                    Err("connection_terminate".into())
+                }
                 _ => Err("Unknown op".into()),
             },
             Err(err) => Err(err.into()),
@@ -140,9 +184,13 @@ impl SubscriptionTransport for WebSocketTransport {
                     id: Some(id.clone()),
                     payload: Some(
                         serde_json::to_value(GQLResponse(Ok(QueryResponse {
+                            operation_name: None,
+                            operation_type: OperationType::Subscription,
                             data: value,
                             extensions: None,
                             cache_control: Default::default(),
+                            errors: Vec::new(),
+                            canonical_query: None,
                         })))
                         .unwrap(),
                     ),
@@ -154,4 +202,39 @@ impl SubscriptionTransport for WebSocketTransport {
             None
         }
     }
+
+    fn handle_error(&mut self, err: Self::Error) -> Option<Bytes> {
+        if !self.init_failed {
+            return None;
+        }
+        let mut payload = serde_json::json!({ "message": err.0 });
+        if let Some(extensions) = err.1 {
+            payload["extensions"] = extensions;
+        }
+        Some(
+            serde_json::to_vec(&OperationMessage {
+                ty: "connection_error".to_string(),
+                id: None,
+                payload: Some(payload),
+            })
+            .unwrap()
+            .into(),
+        )
+    }
+
+    fn keep_alive_interval(&self) -> Option<Duration> {
+        self.keep_alive_interval
+    }
+
+    fn keep_alive_message(&mut self) -> Option<Bytes> {
+        Some(
+            serde_json::to_vec(&OperationMessage {
+                ty: "ka".to_string(),
+                id: None,
+                payload: None,
+            })
+            .unwrap()
+            .into(),
+        )
+    }
 }
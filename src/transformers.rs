@@ -0,0 +1,41 @@
+//! Input value transformers
+
+use crate::Value;
+
+/// Input value transformer
+///
+/// Declared on an input object field with `#[field(transformer(..))]`, it rewrites the value of the field
+/// before the value is validated and parsed. It is applied once, when the argument that contains the field is
+/// parsed, to the value provided by the client as well as to the default value of the field.
+///
+/// A transformer only exists on the Rust side, it is not a GraphQL directive and does not appear in the schema.
+/// The arguments of the transformer are the fields of the type that implements this trait.
+///
+/// ```rust
+/// use async_graphql::*;
+/// use async_graphql::transformers::InputValueTransformer;
+///
+/// struct Trim {}
+///
+/// impl InputValueTransformer for Trim {
+///     fn transform(&self, value: Value) -> Value {
+///         match value {
+///             Value::String(s) => Value::String(s.trim().to_string()),
+///             value => value,
+///         }
+///     }
+/// }
+///
+/// #[InputObject]
+/// struct MyInput {
+///     #[field(transformer(Trim))]
+///     name: String,
+/// }
+/// ```
+pub trait InputValueTransformer
+where
+    Self: Sync + Send,
+{
+    /// Transform the input value.
+    fn transform(&self, value: Value) -> Value;
+}
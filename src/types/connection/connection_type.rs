@@ -1,5 +1,8 @@
 use crate::types::connection::edge::Edge;
 use crate::types::connection::page_info::PageInfo;
+use crate::types::connection::{
+    ConnectionNameType, DefaultConnectionName, DefaultEdgeName, EdgeNameType,
+};
 use crate::{
     do_resolve, registry, Context, ContextSelectionSet, Error, ObjectType, OutputValueType, Pos,
     QueryError, Result, Type,
@@ -9,6 +12,7 @@ use inflector::Inflector;
 use itertools::Itertools;
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::marker::PhantomData;
 
 /// Connection type
 ///
@@ -16,13 +20,22 @@ use std::collections::HashMap;
 /// If the `T` type is `OutputValueType`, you can return the value as a field function directly,
 /// otherwise you can use the `Connection::map` function to convert to a type that implements `OutputValueType`.
 /// `E` is an extension object type that extends the edge fields.
-pub struct Connection<T, E: ObjectType + Sync + Send> {
+/// `C` and `D` define the names of the connection and edge types, by default `{T}Connection` and `{T}Edge`.
+pub struct Connection<
+    T,
+    E: ObjectType + Sync + Send,
+    C: ConnectionNameType = DefaultConnectionName,
+    D: EdgeNameType = DefaultEdgeName,
+> {
     total_count: Option<usize>,
     page_info: PageInfo,
     nodes: Vec<(String, E, T)>,
+    _mark: PhantomData<(C, D)>,
 }
 
-impl<T, E: ObjectType + Sync + Send> Connection<T, E> {
+impl<T, E: ObjectType + Sync + Send, C: ConnectionNameType, D: EdgeNameType>
+    Connection<T, E, C, D>
+{
     /// Create a connection object.
     pub fn new(
         total_count: Option<usize>,
@@ -39,11 +52,12 @@ impl<T, E: ObjectType + Sync + Send> Connection<T, E> {
                 end_cursor: nodes.last().map(|(cursor, _, _)| cursor.clone()),
             },
             nodes,
+            _mark: PhantomData,
         }
     }
 
     /// Convert node type.
-    pub fn map<O, F>(self, mut f: F) -> Connection<O, E>
+    pub fn map<O, F>(self, mut f: F) -> Connection<O, E, C, D>
     where
         F: FnMut(T) -> O,
     {
@@ -55,13 +69,46 @@ impl<T, E: ObjectType + Sync + Send> Connection<T, E> {
                 .into_iter()
                 .map(|(cursor, edge_type, node)| (cursor, edge_type, f(node)))
                 .collect(),
+            _mark: PhantomData,
+        }
+    }
+
+    pub(crate) fn map_cursors<F>(mut self, f: F) -> Self
+    where
+        F: Fn(String) -> String,
+    {
+        self.page_info.start_cursor = self.page_info.start_cursor.map(&f);
+        self.page_info.end_cursor = self.page_info.end_cursor.map(&f);
+        self.nodes = self
+            .nodes
+            .into_iter()
+            .map(|(cursor, edge_type, node)| (f(cursor), edge_type, node))
+            .collect();
+        self
+    }
+
+    /// Change the names of the connection and edge types.
+    ///
+    /// `DataSource::query` returns a connection with the default names, use this to rename it.
+    pub fn rename<NC: ConnectionNameType, ND: EdgeNameType>(self) -> Connection<T, E, NC, ND> {
+        Connection {
+            total_count: self.total_count,
+            page_info: self.page_info,
+            nodes: self.nodes,
+            _mark: PhantomData,
         }
     }
 }
 
-impl<T: OutputValueType + Send + Sync, E: ObjectType + Sync + Send> Type for Connection<T, E> {
+impl<
+        T: OutputValueType + Send + Sync,
+        E: ObjectType + Sync + Send,
+        C: ConnectionNameType,
+        D: EdgeNameType,
+    > Type for Connection<T, E, C, D>
+{
     fn type_name() -> Cow<'static, str> {
-        Cow::Owned(format!("{}Connection", T::type_name()))
+        Cow::Owned(C::type_name::<T>())
     }
 
     fn create_type_info(registry: &mut registry::Registry) -> String {
@@ -82,6 +129,7 @@ impl<T: OutputValueType + Send + Sync, E: ObjectType + Sync + Send> Type for Con
                         cache_control: Default::default(),
                         external: false,
                         requires: None,
+                        compute_complexity: None,
                         provides: None
                     },
                 );
@@ -92,11 +140,12 @@ impl<T: OutputValueType + Send + Sync, E: ObjectType + Sync + Send> Type for Con
                         name: "edges".to_string(),
                         description: Some("A list of edges."),
                         args: Default::default(),
-                        ty: <Option::<Vec<Option<Edge<T,E>>>> as Type>::create_type_info(registry),
+                        ty: <Option::<Vec<Option<Edge<T,E,D>>>> as Type>::create_type_info(registry),
                         deprecation: None,
                         cache_control: Default::default(),
                         external: false,
                         requires: None,
+                        compute_complexity: None,
                         provides: None
                     },
                 );
@@ -112,6 +161,7 @@ impl<T: OutputValueType + Send + Sync, E: ObjectType + Sync + Send> Type for Con
                         cache_control: Default::default(),
                         external: false,
                         requires: None,
+                        compute_complexity: None,
                         provides: None
                     },
                 );
@@ -126,6 +176,7 @@ impl<T: OutputValueType + Send + Sync, E: ObjectType + Sync + Send> Type for Con
                     cache_control: Default::default(),
                     external: false,
                     requires: None,
+                    compute_complexity: None,
                     provides: None
                 });
 
@@ -139,8 +190,12 @@ impl<T: OutputValueType + Send + Sync, E: ObjectType + Sync + Send> Type for Con
 }
 
 #[async_trait::async_trait]
-impl<T: OutputValueType + Send + Sync, E: ObjectType + Sync + Send> ObjectType
-    for Connection<T, E>
+impl<
+        T: OutputValueType + Send + Sync,
+        E: ObjectType + Sync + Send,
+        C: ConnectionNameType,
+        D: EdgeNameType,
+    > ObjectType for Connection<T, E, C, D>
 {
     async fn resolve_field(&self, ctx: &Context<'_>, field: &Field) -> Result<serde_json::Value> {
         if field.name.as_str() == "pageInfo" {
@@ -156,6 +211,7 @@ impl<T: OutputValueType + Send + Sync, E: ObjectType + Sync + Send> ObjectType
                     cursor,
                     extra_type,
                     node,
+                    _mark: PhantomData::<D>,
                 })
                 .collect_vec();
             return OutputValueType::resolve(&edges, &ctx_obj, field.position).await;
@@ -175,15 +231,19 @@ impl<T: OutputValueType + Send + Sync, E: ObjectType + Sync + Send> ObjectType
             path: None,
             err: QueryError::FieldNotFound {
                 field_name: field.name.clone(),
-                object: Connection::<T, E>::type_name().to_string(),
+                object: Self::type_name().to_string(),
             },
         })
     }
 }
 
 #[async_trait::async_trait]
-impl<T: OutputValueType + Send + Sync, E: ObjectType + Sync + Send> OutputValueType
-    for Connection<T, E>
+impl<
+        T: OutputValueType + Send + Sync,
+        E: ObjectType + Sync + Send,
+        C: ConnectionNameType,
+        D: EdgeNameType,
+    > OutputValueType for Connection<T, E, C, D>
 {
     async fn resolve(
         value: &Self,
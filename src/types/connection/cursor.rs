@@ -0,0 +1,76 @@
+use crate::FieldResult;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Key used to sign the cursors of the connections
+///
+/// When this key is in the data of the schema or the query, the cursors returned by `DataSource::query` are
+/// signed with HMAC-SHA256, and the `after` and `before` cursors are verified before they are passed to
+/// `DataSource::query_operation`. Cursors that were not signed with this key are rejected with an error, so
+/// clients can't craft their own cursors.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct QueryRoot;
+///
+/// #[Object]
+/// impl QueryRoot {
+///     #[field]
+///     async fn numbers(&self, ctx: &Context<'_>,
+///         after: Option<String>,
+///         before: Option<String>,
+///         first: Option<i32>,
+///         last: Option<i32>
+///     ) -> FieldResult<Connection<&i32, EmptyEdgeFields>> {
+///         const NUMBERS: &[i32] = &[1, 2, 3, 4, 5];
+///         NUMBERS.query(ctx, after, before, first, last).await
+///     }
+/// }
+///
+/// let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+///     .data(CursorSigningKey::new(b"secret"))
+///     .finish();
+/// ```
+pub struct CursorSigningKey(Vec<u8>);
+
+impl CursorSigningKey {
+    /// Create a cursor signing key.
+    pub fn new(key: impl AsRef<[u8]>) -> Self {
+        CursorSigningKey(key.as_ref().to_vec())
+    }
+
+    fn mac(&self, cursor: &str) -> HmacSha256 {
+        let mut mac = HmacSha256::new_varkey(&self.0).expect("HMAC can take key of any size");
+        mac.input(cursor.as_bytes());
+        mac
+    }
+
+    /// Returns the signed cursor, `{cursor}.{signature}`.
+    pub fn sign(&self, cursor: &str) -> String {
+        let signature = self.mac(cursor).result().code();
+        format!(
+            "{}.{}",
+            cursor,
+            base64::encode_config(&signature, base64::URL_SAFE_NO_PAD)
+        )
+    }
+
+    /// Verifies the signature of a signed cursor and returns the original cursor.
+    pub fn verify(&self, signed_cursor: &str) -> FieldResult<String> {
+        let mut parts = signed_cursor.rsplitn(2, '.');
+        let signature = parts
+            .next()
+            .and_then(|signature| base64::decode_config(signature, base64::URL_SAFE_NO_PAD).ok());
+        match (parts.next(), signature) {
+            (Some(cursor), Some(signature)) if self.mac(cursor).verify(&signature).is_ok() => {
+                Ok(cursor.to_string())
+            }
+            _ => Err("Invalid cursor".into()),
+        }
+    }
+}
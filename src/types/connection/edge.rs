@@ -1,3 +1,4 @@
+use crate::types::connection::EdgeNameType;
 use crate::{
     do_resolve, registry, Context, ContextSelectionSet, ObjectType, OutputValueType, Result, Type,
 };
@@ -5,20 +6,23 @@ use graphql_parser::query::Field;
 use graphql_parser::Pos;
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::marker::PhantomData;
 
-pub struct Edge<'a, T, E> {
+pub struct Edge<'a, T, E, D> {
     pub cursor: &'a str,
     pub node: &'a T,
     pub extra_type: &'a E,
+    pub _mark: PhantomData<D>,
 }
 
-impl<'a, T, E> Type for Edge<'a, T, E>
+impl<'a, T, E, D> Type for Edge<'a, T, E, D>
 where
     T: OutputValueType + Send + Sync + 'a,
     E: ObjectType + Sync + Send + 'a,
+    D: EdgeNameType,
 {
     fn type_name() -> Cow<'static, str> {
-        Cow::Owned(format!("{}Edge", T::type_name()))
+        Cow::Owned(D::type_name::<T>())
     }
 
     fn create_type_info(registry: &mut registry::Registry) -> String {
@@ -50,6 +54,7 @@ where
                             cache_control: Default::default(),
                             external: false,
                             requires: None,
+                            compute_complexity: None,
                             provides: None,
                         },
                     );
@@ -65,6 +70,7 @@ where
                             cache_control: Default::default(),
                             external: false,
                             requires: None,
+                            compute_complexity: None,
                             provides: None,
                         },
                     );
@@ -81,10 +87,11 @@ where
 }
 
 #[async_trait::async_trait]
-impl<'a, T, E> ObjectType for Edge<'a, T, E>
+impl<'a, T, E, D> ObjectType for Edge<'a, T, E, D>
 where
     T: OutputValueType + Send + Sync + 'a,
     E: ObjectType + Sync + Send + 'a,
+    D: EdgeNameType,
 {
     async fn resolve_field(&self, ctx: &Context<'_>, field: &Field) -> Result<serde_json::Value> {
         if field.name.as_str() == "node" {
@@ -99,10 +106,11 @@ where
 }
 
 #[async_trait::async_trait]
-impl<'a, T, E> OutputValueType for Edge<'a, T, E>
+impl<'a, T, E, D> OutputValueType for Edge<'a, T, E, D>
 where
     T: OutputValueType + Send + Sync + 'a,
     E: ObjectType + Sync + Send + 'a,
+    D: EdgeNameType,
 {
     async fn resolve(
         value: &Self,
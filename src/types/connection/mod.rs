@@ -1,11 +1,13 @@
 mod connection_type;
+mod cursor;
 mod edge;
 mod page_info;
 mod slice;
 
-use crate::{Context, FieldResult, ObjectType};
+use crate::{Context, FieldResult, ObjectType, OutputValueType};
 
 pub use connection_type::Connection;
+pub use cursor::CursorSigningKey;
 
 /// Connection query operation
 pub enum QueryOperation<'a> {
@@ -31,6 +33,40 @@ pub enum QueryOperation<'a> {
 #[async_graphql_derive::SimpleObject(internal)]
 pub struct EmptyEdgeFields;
 
+/// Name of a connection type
+///
+/// Implement this trait and use it as the `C` parameter of `Connection` to customize the connection type name.
+pub trait ConnectionNameType: Send + Sync {
+    /// Returns the connection type name for the node type `T`.
+    fn type_name<T: OutputValueType>() -> String;
+}
+
+/// Name of an edge type
+///
+/// Implement this trait and use it as the `D` parameter of `Connection` to customize the edge type name.
+pub trait EdgeNameType: Send + Sync {
+    /// Returns the edge type name for the node type `T`.
+    fn type_name<T: OutputValueType>() -> String;
+}
+
+/// Default connection type name, `{T}Connection`
+pub struct DefaultConnectionName;
+
+impl ConnectionNameType for DefaultConnectionName {
+    fn type_name<T: OutputValueType>() -> String {
+        format!("{}Connection", T::type_name())
+    }
+}
+
+/// Default edge type name, `{T}Edge`
+pub struct DefaultEdgeName;
+
+impl EdgeNameType for DefaultEdgeName {
+    fn type_name<T: OutputValueType>() -> String {
+        format!("{}Edge", T::type_name())
+    }
+}
+
 /// Data source of GraphQL Cursor Connections type
 ///
 /// `Edge` is an extension object type that extends the edge fields, If you don't need it, you can use `EmptyEdgeFields`.
@@ -130,19 +166,50 @@ pub trait DataSource: Sync + Send {
     type EdgeFieldsObj: ObjectType + Send + Sync;
 
     /// Execute the query.
+    ///
+    /// Returns an error if `first` or `last` is negative, or if `first` and `last`, or `after` and `before`
+    /// are provided together.
+    ///
+    /// If there is a `CursorSigningKey` in the data, the cursors are verified and signed with it.
     async fn query(
         &self,
-        _ctx: &Context<'_>,
+        ctx: &Context<'_>,
         after: Option<String>,
         before: Option<String>,
         first: Option<i32>,
         last: Option<i32>,
     ) -> FieldResult<Connection<Self::Element, Self::EdgeFieldsObj>> {
+        if first.map(|first| first < 0).unwrap_or_default() {
+            return Err("The \"first\" parameter must be a non-negative number".into());
+        }
+        if last.map(|last| last < 0).unwrap_or_default() {
+            return Err("The \"last\" parameter must be a non-negative number".into());
+        }
+        if first.is_some() && last.is_some() {
+            return Err(
+                "The \"first\" and \"last\" parameters cannot exist at the same time".into(),
+            );
+        }
+        if after.is_some() && before.is_some() {
+            return Err(
+                "The \"after\" and \"before\" parameters cannot exist at the same time".into(),
+            );
+        }
+
+        let signing_key = ctx.data_opt::<CursorSigningKey>();
+        let (after, before) = match signing_key {
+            Some(key) => (
+                after.map(|after| key.verify(&after)).transpose()?,
+                before.map(|before| key.verify(&before)).transpose()?,
+            ),
+            None => (after, before),
+        };
+
         let operation = if let Some(after) = &after {
             QueryOperation::Forward {
                 after: Some(after),
                 limit: match first {
-                    Some(value) => value.max(0) as usize,
+                    Some(value) => value as usize,
                     None => 10,
                 },
             }
@@ -150,19 +217,19 @@ pub trait DataSource: Sync + Send {
             QueryOperation::Backward {
                 before: Some(before),
                 limit: match last {
-                    Some(value) => value.max(0) as usize,
+                    Some(value) => value as usize,
                     None => 10,
                 },
             }
         } else if let Some(first) = first {
             QueryOperation::Forward {
                 after: None,
-                limit: first.max(0) as usize,
+                limit: first as usize,
             }
         } else if let Some(last) = last {
             QueryOperation::Backward {
                 before: None,
-                limit: last.max(0) as usize,
+                limit: last as usize,
             }
         } else {
             QueryOperation::Forward {
@@ -171,7 +238,11 @@ pub trait DataSource: Sync + Send {
             }
         };
 
-        self.query_operation(&operation).await
+        let connection = self.query_operation(&operation).await?;
+        Ok(match signing_key {
+            Some(key) => connection.map_cursors(|cursor| key.sign(&cursor)),
+            None => connection,
+        })
     }
 
     /// Parses the parameters and executes the query，Usually you just need to implement this method.
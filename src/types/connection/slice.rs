@@ -17,7 +17,8 @@ impl<'a, T: Sync> DataSource for &'a [T] {
                     .and_then(|after| base64::decode(after).ok())
                     .and_then(|data| data.as_slice().read_u32::<BE>().ok())
                     .map(|idx| (idx + 1) as usize)
-                    .unwrap_or(0);
+                    .unwrap_or(0)
+                    .min(self.len());
                 let end = (start + *limit).min(self.len());
                 (start, end)
             }
@@ -26,7 +27,8 @@ impl<'a, T: Sync> DataSource for &'a [T] {
                     .and_then(|before| base64::decode(before).ok())
                     .and_then(|data| data.as_slice().read_u32::<BE>().ok())
                     .map(|idx| idx as usize)
-                    .unwrap_or_else(|| self.len());
+                    .unwrap_or_else(|| self.len())
+                    .min(self.len());
                 let start = if end < *limit { 0 } else { end - *limit };
                 (start, end)
             }
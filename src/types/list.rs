@@ -1,4 +1,6 @@
-use crate::{registry, ContextSelectionSet, InputValueType, OutputValueType, Result, Type, Value};
+use crate::{
+    registry, ContextSelectionSet, Error, InputValueType, OutputValueType, Result, Type, Value,
+};
 use graphql_parser::Pos;
 use std::borrow::Cow;
 
@@ -43,7 +45,7 @@ impl<T: OutputValueType + Send + Sync> OutputValueType for Vec<T> {
         let mut futures = Vec::with_capacity(value.len());
         for (idx, item) in value.iter().enumerate() {
             let ctx_idx = ctx.with_index(idx);
-            futures.push(async move { OutputValueType::resolve(item, &ctx_idx, pos).await });
+            futures.push(async move { resolve_item(item, &ctx_idx, pos).await });
         }
         Ok(futures::future::try_join_all(futures).await?.into())
     }
@@ -51,11 +53,16 @@ impl<T: OutputValueType + Send + Sync> OutputValueType for Vec<T> {
 
 impl<T: Type> Type for &[T] {
     fn type_name() -> Cow<'static, str> {
-        Cow::Owned(format!("[{}]", T::type_name()))
+        Cow::Owned(format!("[{}]", T::qualified_type_name()))
+    }
+
+    fn qualified_type_name() -> String {
+        format!("[{}]!", T::qualified_type_name())
     }
 
     fn create_type_info(registry: &mut registry::Registry) -> String {
-        T::create_type_info(registry)
+        T::create_type_info(registry);
+        Self::qualified_type_name()
     }
 }
 
@@ -69,15 +76,113 @@ impl<T: OutputValueType + Send + Sync> OutputValueType for &[T] {
         let mut futures = Vec::with_capacity(value.len());
         for (idx, item) in (*value).iter().enumerate() {
             let ctx_idx = ctx.with_index(idx);
-            futures.push(async move { OutputValueType::resolve(item, &ctx_idx, pos).await });
+            futures.push(async move { resolve_item(item, &ctx_idx, pos).await });
         }
         Ok(futures::future::try_join_all(futures).await?.into())
     }
 }
 
+impl<'a, T: Type + Clone> Type for Cow<'a, [T]> {
+    fn type_name() -> Cow<'static, str> {
+        <&[T] as Type>::type_name()
+    }
+
+    fn qualified_type_name() -> String {
+        <&[T] as Type>::qualified_type_name()
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        <&[T] as Type>::create_type_info(registry)
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a, T: OutputValueType + Clone + Send + Sync> OutputValueType for Cow<'a, [T]> {
+    async fn resolve(
+        value: &Self,
+        ctx: &ContextSelectionSet<'_>,
+        pos: Pos,
+    ) -> Result<serde_json::Value> {
+        let slice: &[T] = value;
+        OutputValueType::resolve(&slice, ctx, pos).await
+    }
+}
+
+/// A list of the items of an iterator
+///
+/// It allows a field to return an iterator adaptor, such as `ListIter(values.iter().map(...))`,
+/// the items are resolved as they are produced without collecting them into a `Vec` first.
+/// The iterator is cloned to be iterated, so `I` is usually a cheap to clone adaptor over borrowed data.
+pub struct ListIter<I>(pub I);
+
+impl<I> Type for ListIter<I>
+where
+    I: IntoIterator,
+    I::Item: Type,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(format!("[{}]", I::Item::qualified_type_name()))
+    }
+
+    fn qualified_type_name() -> String {
+        format!("[{}]!", I::Item::qualified_type_name())
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        I::Item::create_type_info(registry);
+        Self::qualified_type_name()
+    }
+}
+
+#[async_trait::async_trait]
+impl<I> OutputValueType for ListIter<I>
+where
+    I: IntoIterator + Clone + Send + Sync,
+    I::Item: OutputValueType + Send + Sync,
+{
+    async fn resolve(
+        value: &Self,
+        ctx: &ContextSelectionSet<'_>,
+        pos: Pos,
+    ) -> Result<serde_json::Value> {
+        let mut futures = Vec::new();
+        for (idx, item) in value.0.clone().into_iter().enumerate() {
+            let ctx_idx = ctx.with_index(idx);
+            futures.push(async move { resolve_item(&item, &ctx_idx, pos).await });
+        }
+        Ok(futures::future::try_join_all(futures).await?.into())
+    }
+}
+
+/// Resolves an element of a list.
+///
+/// An error without a path, such as a scalar that can't be serialized, gets the path of the element.
+async fn resolve_item<T: OutputValueType + Send + Sync>(
+    item: &T,
+    ctx: &ContextSelectionSet<'_>,
+    pos: Pos,
+) -> Result<serde_json::Value> {
+    OutputValueType::resolve(item, ctx, pos)
+        .await
+        .map_err(|err| match err {
+            Error::Query {
+                pos,
+                path: None,
+                err,
+            } => Error::Query {
+                pos,
+                path: ctx.path_node.as_ref().map(|path_node| path_node.to_json()),
+                err,
+            },
+            err => err,
+        })
+}
+
 #[cfg(test)]
 mod tests {
+    use super::ListIter;
     use crate::Type;
+    use std::borrow::Cow;
 
     #[test]
     fn test_list_type() {
@@ -88,5 +193,14 @@ mod tests {
         assert_eq!(Vec::<i32>::qualified_type_name(), "[Int!]!");
         assert_eq!(Vec::<Option<i32>>::qualified_type_name(), "[Int]!");
         assert_eq!(Option::<Vec::<Option<i32>>>::qualified_type_name(), "[Int]");
+
+        assert_eq!(<&[i32]>::type_name(), "[Int!]");
+        assert_eq!(<&[i32]>::qualified_type_name(), "[Int!]!");
+        assert_eq!(Cow::<[Option<i32>]>::type_name(), "[Int]");
+        assert_eq!(Cow::<[i32]>::qualified_type_name(), "[Int!]!");
+        assert_eq!(
+            ListIter::<std::vec::IntoIter<i32>>::qualified_type_name(),
+            "[Int!]!"
+        );
     }
 }
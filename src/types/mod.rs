@@ -5,11 +5,17 @@ mod r#enum;
 mod list;
 mod optional;
 mod query_root;
+mod result;
 mod upload;
 
-pub use connection::{Connection, DataSource, EmptyEdgeFields, QueryOperation};
+pub use connection::{
+    Connection, ConnectionNameType, CursorSigningKey, DataSource, DefaultConnectionName,
+    DefaultEdgeName, EdgeNameType, EmptyEdgeFields, QueryOperation,
+};
 pub use empty_mutation::EmptyMutation;
 pub use empty_subscription::EmptySubscription;
+pub use list::ListIter;
 pub use query_root::QueryRoot;
+pub(crate) use query_root::INTROSPECTION_CACHE_SIZE;
 pub use r#enum::{EnumItem, EnumType};
 pub use upload::Upload;
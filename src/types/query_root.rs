@@ -1,12 +1,15 @@
 use crate::model::{__Schema, __Type};
 use crate::scalars::Any;
 use crate::{
-    do_resolve, registry, Context, ContextSelectionSet, Error, ObjectType, OutputValueType,
-    QueryError, Result, Type, Value,
+    do_resolve, registry, Context, ContextSelectionSet, Error, FieldResult, ObjectType,
+    OutputValueType, QueryError, Result, Type, Value,
 };
 use async_graphql_derive::SimpleObject;
 use graphql_parser::query::Field;
 use graphql_parser::Pos;
+use itertools::Itertools;
+use lru::LruCache;
+use parking_lot::Mutex;
 use std::borrow::Cow;
 use std::collections::HashMap;
 
@@ -17,9 +20,45 @@ struct Service {
     sdl: Option<String>,
 }
 
+/// The number of `__schema` results kept by `QueryRoot::introspection_cache`.
+pub(crate) const INTROSPECTION_CACHE_SIZE: usize = 16;
+
+pub(crate) type IntrospectionGuard = Box<dyn Fn(&Context<'_>) -> FieldResult<()> + Send + Sync>;
+
 pub struct QueryRoot<T> {
     pub inner: T,
     pub disable_introspection: bool,
+    pub(crate) introspection_guard: Option<IntrospectionGuard>,
+
+    /// The results of `__schema`, keyed by the selection set and the fragments of the query.
+    ///
+    /// The schema can't change once it's built, so the result is only computed once for each query. The key comes
+    /// from the client, so only the `INTROSPECTION_CACHE_SIZE` most recently used results are kept.
+    pub(crate) introspection_cache: Mutex<LruCache<String, serde_json::Value>>,
+}
+
+impl<T> QueryRoot<T> {
+    fn check_introspection_guard(&self, ctx: &Context<'_>, field: &Field) -> Result<()> {
+        match &self.introspection_guard {
+            Some(guard) => guard(ctx).map_err(|err| {
+                err.into_error_with_path(field.position, ctx.path_node.as_ref().unwrap().to_json())
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns the cache key of a `__schema` field, or `None` if it can't be cached because it uses variables.
+fn introspection_cache_key(ctx: &Context<'_>, field: &Field) -> Option<String> {
+    let mut key = field.selection_set.to_string();
+    for (_, fragment) in ctx.fragments.iter().sorted_by(|a, b| a.0.cmp(b.0)) {
+        key.push_str(&fragment.to_string());
+    }
+    if key.contains('$') {
+        None
+    } else {
+        Some(key)
+    }
 }
 
 impl<T: Type> Type for QueryRoot<T> {
@@ -44,6 +83,7 @@ impl<T: Type> Type for QueryRoot<T> {
                     cache_control: Default::default(),
                     external: false,
                     requires: None,
+                    compute_complexity: None,
                     provides: None,
                 },
             );
@@ -63,6 +103,7 @@ impl<T: Type> Type for QueryRoot<T> {
                                 ty: "String!".to_string(),
                                 default_value: None,
                                 validator: None,
+                                transformer: None,
                             },
                         );
                         args
@@ -72,6 +113,7 @@ impl<T: Type> Type for QueryRoot<T> {
                     cache_control: Default::default(),
                     external: false,
                     requires: None,
+                    compute_complexity: None,
                     provides: None,
                 },
             );
@@ -94,17 +136,31 @@ impl<T: ObjectType + Send + Sync> ObjectType for QueryRoot<T> {
                     },
                 });
             }
+            self.check_introspection_guard(ctx, field)?;
+
+            let cache_key = introspection_cache_key(ctx, field);
+            if let Some(value) = cache_key
+                .as_ref()
+                .and_then(|key| self.introspection_cache.lock().get(key).cloned())
+            {
+                return Ok(value);
+            }
 
             let ctx_obj = ctx.with_selection_set(&field.selection_set);
-            return OutputValueType::resolve(
+            let value = OutputValueType::resolve(
                 &__Schema {
                     registry: &ctx.registry,
                 },
                 &ctx_obj,
                 field.position,
             )
-            .await;
+            .await?;
+            if let Some(key) = cache_key {
+                self.introspection_cache.lock().put(key, value.clone());
+            }
+            return Ok(value);
         } else if field.name.as_str() == "__type" {
+            self.check_introspection_guard(ctx, field)?;
             let type_name: String = ctx.param_value("name", field.position, || Value::Null)?;
             let ctx_obj = ctx.with_selection_set(&field.selection_set);
             return OutputValueType::resolve(
@@ -121,7 +177,11 @@ impl<T: ObjectType + Send + Sync> ObjectType for QueryRoot<T> {
                 ctx.param_value("representations", field.position, || Value::Null)?;
             let mut res = Vec::new();
             for item in representations {
-                res.push(self.inner.find_entity(ctx, field.position, &item.0).await?);
+                res.push(
+                    self.inner
+                        .find_entity(&ctx.with_representation(&item.0), field.position, &item.0)
+                        .await?,
+                );
             }
             return Ok(res.into());
         } else if field.name.as_str() == "_service" {
@@ -0,0 +1,53 @@
+use crate::{registry, ContextSelectionSet, FieldResult, OutputValueType, Pos, Result, Type};
+use std::borrow::Cow;
+
+/// A `FieldResult<T>` used as an output value is nullable, an error resolves to `null`
+/// and is added to the `errors` of the response, so that a `Vec<FieldResult<T>>` doesn't
+/// fail as a whole when some of its elements fail.
+impl<T: Type> Type for FieldResult<T> {
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn qualified_type_name() -> String {
+        T::type_name().to_string()
+    }
+
+    fn create_type_info(registry: &mut registry::Registry) -> String {
+        T::create_type_info(registry);
+        T::type_name().to_string()
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: OutputValueType + Sync> OutputValueType for FieldResult<T> {
+    async fn resolve(
+        value: &Self,
+        ctx: &ContextSelectionSet<'_>,
+        pos: Pos,
+    ) -> Result<serde_json::Value> {
+        match value {
+            Ok(inner) => OutputValueType::resolve(inner, ctx, pos).await,
+            Err(err) => {
+                let err = match &ctx.path_node {
+                    Some(path_node) => err.clone().into_error_with_path(pos, path_node.to_json()),
+                    None => err.clone().into_error(pos),
+                };
+                ctx.add_error(err)?;
+                Ok(serde_json::Value::Null)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FieldResult, Type};
+
+    #[test]
+    fn test_result_type() {
+        assert_eq!(FieldResult::<i32>::type_name(), "Int");
+        assert_eq!(FieldResult::<i32>::qualified_type_name(), "Int");
+        assert_eq!(Vec::<FieldResult<i32>>::type_name(), "[Int]");
+    }
+}
@@ -42,6 +42,11 @@ use std::path::PathBuf;
 /// --form 'map={ "0": ["variables.file"] }' \
 /// --form '0=@myFile.txt'
 /// ```
+///
+/// # Saving the file
+///
+/// With the `async-std` or `tokio` feature enabled, `Upload::save_to` copies the uploaded file to a path
+/// with the corresponding runtime.
 pub struct Upload {
     /// Filename
     pub filename: String,
@@ -53,6 +58,37 @@ pub struct Upload {
     pub path: PathBuf,
 }
 
+#[cfg(any(feature = "async-std", feature = "tokio"))]
+impl Upload {
+    /// Copy the uploaded file to `path` using `async_std::fs`, and returns the number of bytes written.
+    #[cfg(feature = "async-std")]
+    pub async fn save_to<P: AsRef<std::path::Path>>(&self, path: P) -> crate::FieldResult<u64> {
+        async_std::fs::copy(&self.path, path.as_ref())
+            .await
+            .map_err(|err| self.save_error(path.as_ref(), err))
+    }
+
+    /// Copy the uploaded file to `path` using `tokio::fs`, and returns the number of bytes written.
+    #[cfg(all(feature = "tokio", not(feature = "async-std")))]
+    pub async fn save_to<P: AsRef<std::path::Path>>(&self, path: P) -> crate::FieldResult<u64> {
+        tokio::fs::copy(&self.path, path.as_ref())
+            .await
+            .map_err(|err| self.save_error(path.as_ref(), err))
+    }
+
+    fn save_error(&self, path: &std::path::Path, err: std::io::Error) -> crate::FieldError {
+        crate::FieldError::new(
+            format!(
+                "Failed to save file \"{}\" to \"{}\": {}",
+                self.filename,
+                path.display(),
+                err
+            ),
+            None,
+        )
+    }
+}
+
 impl<'a> Type for Upload {
     fn type_name() -> Cow<'static, str> {
         Cow::Borrowed("Upload")
@@ -66,6 +102,7 @@ impl<'a> Type for Upload {
                 Value::String(s) => s.starts_with("file:"),
                 _ => false,
             },
+            accepted_forms: &[],
         })
     }
 }
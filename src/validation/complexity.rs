@@ -0,0 +1,111 @@
+use crate::registry::{self, TypeName};
+use crate::{ContextSelectionSet, Result};
+use graphql_parser::query::{Field, Selection, TypeCondition};
+use std::collections::HashMap;
+
+/// Calculates the complexity of `field`, whose selection set has a complexity of `child_complexity`.
+pub(crate) fn field_complexity(
+    ctx: &ContextSelectionSet<'_>,
+    field: &Field,
+    registry_field: Option<&registry::Field>,
+    child_complexity: usize,
+) -> Result<usize> {
+    if ctx.registry.ignore_deprecated_complexity
+        && registry_field.map_or(false, |field| field.deprecation.is_some())
+    {
+        // only the field itself is free, its selection set still counts
+        return Ok(child_complexity);
+    }
+    match registry_field.and_then(|field| field.compute_complexity) {
+        Some(compute_complexity) => compute_complexity(&ctx.with_field(field), child_complexity),
+        None => Ok(child_complexity.saturating_add(1)),
+    }
+}
+
+/// Calculates the complexity of the selection set of `ctx`, whose type is `type_name`.
+///
+/// The complexity of a field is computed by its `complexity` expression if it has one (see `Object`),
+/// otherwise it is `1` plus the complexity of its selection set. Fragment spreads are expanded, so a
+/// fragment costs as much as the fields it selects each time it is spread. The selections skipped by `@skip` or
+/// `@include` cost nothing. If `SchemaBuilder::ignore_deprecated_complexity` is enabled, a deprecated field costs
+/// only its selection set.
+pub(crate) fn calculate_complexity(
+    ctx: &ContextSelectionSet<'_>,
+    type_name: &str,
+) -> Result<usize> {
+    calculate_complexity_inner(ctx, type_name, &mut HashMap::new())
+}
+
+/// `fragments` holds the complexity of the fragments already computed, a fragment is computed once no matter how
+/// many times it is spread, so that chained fragments don't take an exponential time.
+fn calculate_complexity_inner(
+    ctx: &ContextSelectionSet<'_>,
+    type_name: &str,
+    fragments: &mut HashMap<String, usize>,
+) -> Result<usize> {
+    let ty = ctx.registry.types.get(type_name);
+    let mut complexity: usize = 0;
+
+    for selection in &ctx.item.items {
+        match selection {
+            Selection::Field(field) => {
+                if ctx.is_skip(&field.directives)? {
+                    continue;
+                }
+                let registry_field = ty.and_then(|ty| ty.field_by_name(&field.name));
+                let child_complexity = match registry_field {
+                    Some(registry_field) => calculate_complexity_inner(
+                        &ctx.with_selection_set(&field.selection_set),
+                        TypeName::concrete_typename(&registry_field.ty),
+                        fragments,
+                    )?,
+                    None => 0,
+                };
+                complexity = complexity.saturating_add(field_complexity(
+                    ctx,
+                    field,
+                    registry_field,
+                    child_complexity,
+                )?);
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                if ctx.is_skip(&fragment_spread.directives)? {
+                    continue;
+                }
+                if let Some(fragment) = ctx.fragments.get(&fragment_spread.fragment_name) {
+                    let fragment_complexity = match fragments.get(&fragment_spread.fragment_name) {
+                        Some(fragment_complexity) => *fragment_complexity,
+                        None => {
+                            let TypeCondition::On(name) = &fragment.type_condition;
+                            let fragment_complexity = calculate_complexity_inner(
+                                &ctx.with_selection_set(&fragment.selection_set),
+                                name,
+                                fragments,
+                            )?;
+                            fragments
+                                .insert(fragment_spread.fragment_name.clone(), fragment_complexity);
+                            fragment_complexity
+                        }
+                    };
+                    complexity = complexity.saturating_add(fragment_complexity);
+                }
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                if ctx.is_skip(&inline_fragment.directives)? {
+                    continue;
+                }
+                let name = match &inline_fragment.type_condition {
+                    Some(TypeCondition::On(name)) => name.as_str(),
+                    None => type_name,
+                };
+                complexity = complexity.saturating_add(calculate_complexity_inner(
+                    &ctx.with_selection_set(&inline_fragment.selection_set),
+                    name,
+                    fragments,
+                )?);
+            }
+        }
+    }
+
+    Ok(complexity)
+}
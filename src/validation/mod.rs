@@ -1,6 +1,7 @@
+mod complexity;
 mod rules;
 mod suggestion;
-mod utils;
+pub(crate) mod utils;
 mod visitor;
 mod visitors;
 
@@ -12,16 +13,21 @@ use crate::{CacheControl, Error, Result};
 use graphql_parser::query::Document;
 use visitor::{visit, VisitorContext, VisitorNil};
 
+pub(crate) use complexity::{calculate_complexity, field_complexity};
+
+#[derive(Clone, Copy)]
 pub struct CheckResult {
     pub cache_control: CacheControl,
-    pub complexity: usize,
     pub depth: usize,
 }
 
 /// Validation mode
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum ValidationMode {
     /// Execute all validation rules.
+    ///
+    /// This includes the rules that don't affect the execution, such as reporting the variables that are declared
+    /// but never used.
     Strict,
 
     /// The executor itself also has error handling, so it can improve performance, but it can lose some error messages.
@@ -32,10 +38,10 @@ pub fn check_rules(
     registry: &Registry,
     doc: &Document,
     mode: ValidationMode,
+    disable_introspection: bool,
 ) -> Result<CheckResult> {
     let mut ctx = VisitorContext::new(registry, doc);
     let mut cache_control = CacheControl::default();
-    let mut complexity = 0;
     let mut depth = 0;
 
     match mode {
@@ -63,14 +69,12 @@ pub fn check_rules(
                 .with(rules::PossibleFragmentSpreads::default())
                 .with(rules::ProvidedNonNullArguments)
                 .with(rules::KnownDirectives::default())
+                .with(rules::UniqueDirectivesPerLocation)
                 .with(rules::OverlappingFieldsCanBeMerged)
                 .with(rules::UploadFile)
                 .with(visitors::CacheControlCalculate {
                     cache_control: &mut cache_control,
                 })
-                .with(visitors::ComplexityCalculate {
-                    complexity: &mut complexity,
-                })
                 .with(visitors::DepthCalculate::new(&mut depth));
             visit(&mut visitor, &mut ctx, doc);
         }
@@ -81,20 +85,20 @@ pub fn check_rules(
                 .with(visitors::CacheControlCalculate {
                     cache_control: &mut cache_control,
                 })
-                .with(visitors::ComplexityCalculate {
-                    complexity: &mut complexity,
-                })
                 .with(visitors::DepthCalculate::new(&mut depth));
             visit(&mut visitor, &mut ctx, doc);
         }
     }
 
+    if disable_introspection {
+        visit(&mut rules::NoIntrospection, &mut ctx, doc);
+    }
+
     if !ctx.errors.is_empty() {
         return Err(Error::Rule { errors: ctx.errors });
     }
     Ok(CheckResult {
         cache_control,
-        complexity,
         depth: depth as usize,
     })
 }
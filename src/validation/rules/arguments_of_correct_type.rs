@@ -1,6 +1,6 @@
 use crate::context::QueryPathNode;
 use crate::registry::InputValue;
-use crate::validation::utils::is_valid_input_value;
+use crate::validation::utils::{is_valid_input_value, validate_input_value};
 use crate::validation::visitor::{Visitor, VisitorContext};
 use crate::QueryPathSegment;
 use graphql_parser::query::Field;
@@ -38,11 +38,15 @@ impl<'a> Visitor<'a> for ArgumentsOfCorrectType<'a> {
             .and_then(|args| args.get(name).map(|input| input))
         {
             if let Some(validator) = &arg.validator {
-                if let Some(reason) = validator.is_valid(value) {
-                    ctx.report_error(
-                        vec![pos],
-                        format!("Invalid value for argument \"{}\", {}", arg.name, reason),
-                    );
+                if let Some(reason) = validate_input_value(
+                    &**validator,
+                    value,
+                    QueryPathNode {
+                        parent: None,
+                        segment: QueryPathSegment::Name(arg.name),
+                    },
+                ) {
+                    ctx.report_error(vec![pos], format!("Invalid value for argument {}", reason));
                     return;
                 }
             }
@@ -8,6 +8,7 @@ mod known_fragment_names;
 mod known_type_names;
 mod lone_anonymous_operation;
 mod no_fragment_cycles;
+mod no_introspection;
 mod no_undefined_variables;
 mod no_unused_fragments;
 mod no_unused_variables;
@@ -16,6 +17,7 @@ mod possible_fragment_spreads;
 mod provided_non_null_arguments;
 mod scalar_leafs;
 mod unique_argument_names;
+mod unique_directives_per_location;
 mod unique_fragment_names;
 mod unique_operation_names;
 mod unique_variable_names;
@@ -33,6 +35,7 @@ pub use known_fragment_names::KnownFragmentNames;
 pub use known_type_names::KnownTypeNames;
 pub use lone_anonymous_operation::LoneAnonymousOperation;
 pub use no_fragment_cycles::NoFragmentCycles;
+pub use no_introspection::NoIntrospection;
 pub use no_undefined_variables::NoUndefinedVariables;
 pub use no_unused_fragments::NoUnusedFragments;
 pub use no_unused_variables::NoUnusedVariables;
@@ -41,6 +44,7 @@ pub use possible_fragment_spreads::PossibleFragmentSpreads;
 pub use provided_non_null_arguments::ProvidedNonNullArguments;
 pub use scalar_leafs::ScalarLeafs;
 pub use unique_argument_names::UniqueArgumentNames;
+pub use unique_directives_per_location::UniqueDirectivesPerLocation;
 pub use unique_fragment_names::UniqueFragmentNames;
 pub use unique_operation_names::UniqueOperationNames;
 pub use unique_variable_names::UniqueVariableNames;
@@ -0,0 +1,23 @@
+use crate::validation::visitor::{Visitor, VisitorContext};
+use graphql_parser::query::Field;
+
+#[derive(Default)]
+pub struct NoIntrospection;
+
+impl<'a> Visitor<'a> for NoIntrospection {
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Field) {
+        if let Some(parent_type) = ctx.parent_type() {
+            if parent_type.name() == ctx.registry.query_type
+                && (field.name == "__schema" || field.name == "__type")
+            {
+                ctx.report_error(
+                    vec![field.position],
+                    format!(
+                        "Introspection is disabled, the field \"{}\" is not allowed.",
+                        field.name
+                    ),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::validation::visitor::{Visitor, VisitorContext};
+use graphql_parser::query::{
+    Field, FragmentDefinition, FragmentSpread, InlineFragment, OperationDefinition,
+};
+use graphql_parser::schema::Directive;
+use std::collections::HashSet;
+
+#[derive(Default)]
+pub struct UniqueDirectivesPerLocation;
+
+fn check_directives(ctx: &mut VisitorContext<'_>, directives: &[Directive]) {
+    let mut names = HashSet::new();
+    for directive in directives {
+        let is_repeatable = ctx
+            .registry
+            .directives
+            .get(directive.name.as_str())
+            .map(|directive| directive.is_repeatable)
+            .unwrap_or(true);
+        if !is_repeatable && !names.insert(directive.name.as_str()) {
+            ctx.report_error(
+                vec![directive.position],
+                format!(
+                    "The directive \"{}\" can only be used once at this location.",
+                    directive.name
+                ),
+            );
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for UniqueDirectivesPerLocation {
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        operation_definition: &'a OperationDefinition,
+    ) {
+        match operation_definition {
+            OperationDefinition::Query(query) => check_directives(ctx, &query.directives),
+            OperationDefinition::Mutation(mutation) => check_directives(ctx, &mutation.directives),
+            OperationDefinition::Subscription(subscription) => {
+                check_directives(ctx, &subscription.directives)
+            }
+            OperationDefinition::SelectionSet(_) => {}
+        }
+    }
+
+    fn enter_fragment_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        fragment_definition: &'a FragmentDefinition,
+    ) {
+        check_directives(ctx, &fragment_definition.directives);
+    }
+
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Field) {
+        check_directives(ctx, &field.directives);
+    }
+
+    fn enter_fragment_spread(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        fragment_spread: &'a FragmentSpread,
+    ) {
+        check_directives(ctx, &fragment_spread.directives);
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        inline_fragment: &'a InlineFragment,
+    ) {
+        check_directives(ctx, &inline_fragment.directives);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::test_harness::{expect_fails_rule, expect_passes_rule};
+
+    pub fn factory() -> UniqueDirectivesPerLocation {
+        UniqueDirectivesPerLocation
+    }
+
+    #[test]
+    fn distinct_directives() {
+        expect_passes_rule(
+            factory,
+            r#"
+          {
+            dog @include(if: true) @skip(if: false) {
+              name
+            }
+          }
+        "#,
+        );
+    }
+
+    #[test]
+    fn duplicate_directives() {
+        expect_fails_rule(
+            factory,
+            r#"
+          {
+            dog @include(if: true) @include(if: false) {
+              name
+            }
+          }
+        "#,
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::context::QueryPathNode;
+use crate::validators::InputValueValidator;
 use crate::{registry, Pos, QueryPathSegment, Value};
 use graphql_parser::query::OperationDefinition;
 use std::collections::HashSet;
@@ -45,6 +46,35 @@ pub fn operation_name(operation_definition: &OperationDefinition) -> (Option<&st
     }
 }
 
+/// Checks a value with a validator, the elements of a list are checked one by one if it's a list validator,
+/// so the error has the path of the invalid element.
+pub fn validate_input_value(
+    validator: &dyn InputValueValidator,
+    value: &Value,
+    path_node: QueryPathNode,
+) -> Option<String> {
+    match (validator.elements(), value) {
+        (Some(elements), Value::List(values)) => {
+            for (idx, elem) in values.iter().enumerate() {
+                if let Some(reason) = validate_input_value(
+                    elements,
+                    elem,
+                    QueryPathNode {
+                        parent: Some(&path_node),
+                        segment: QueryPathSegment::Index(idx),
+                    },
+                ) {
+                    return Some(reason);
+                }
+            }
+            None
+        }
+        _ => validator
+            .is_valid(value)
+            .map(|reason| valid_error(&path_node, reason)),
+    }
+}
+
 pub fn is_valid_input_value(
     registry: &registry::Registry,
     type_name: &str,
@@ -89,11 +119,23 @@ pub fn is_valid_input_value(
 
             if let Some(ty) = registry.types.get(type_name) {
                 match ty {
-                    registry::Type::Scalar { is_valid, .. } => {
+                    registry::Type::Scalar {
+                        is_valid,
+                        accepted_forms,
+                        ..
+                    } => {
                         if !is_valid(value) {
                             Some(valid_error(
                                 &path_node,
-                                format!("expected type \"{}\"", type_name),
+                                if accepted_forms.is_empty() {
+                                    format!("expected type \"{}\"", type_name)
+                                } else {
+                                    format!(
+                                        "expected type \"{}\", accepted forms: {}",
+                                        type_name,
+                                        accepted_forms.join(", ")
+                                    )
+                                },
                             ))
                         } else {
                             None
@@ -119,8 +161,28 @@ pub fn is_valid_input_value(
                             format!("expected type \"{}\"", type_name),
                         )),
                     },
-                    registry::Type::InputObject { input_fields, .. } => match value {
+                    registry::Type::InputObject {
+                        input_fields,
+                        oneof,
+                        ..
+                    } => match value {
                         Value::Object(values) => {
+                            if *oneof
+                                && values
+                                    .values()
+                                    .filter(|value| **value != Value::Null)
+                                    .count()
+                                    != 1
+                            {
+                                return Some(valid_error(
+                                    &path_node,
+                                    format!(
+                                        "exactly one field of oneOf input object \"{}\" must be provided",
+                                        ty.name()
+                                    ),
+                                ));
+                            }
+
                             let mut input_names = values
                                 .keys()
                                 .map(|name| name.as_str())
@@ -128,16 +190,22 @@ pub fn is_valid_input_value(
 
                             for field in input_fields.values() {
                                 input_names.remove(field.name);
+                                if field.transformer.is_some() {
+                                    // checked with the transformed value, see `transform_input_value`
+                                    continue;
+                                }
+
                                 if let Some(value) = values.get(field.name) {
                                     if let Some(validator) = &field.validator {
-                                        if let Some(reason) = validator.is_valid(value) {
-                                            return Some(valid_error(
-                                                &QueryPathNode {
-                                                    parent: Some(&path_node),
-                                                    segment: QueryPathSegment::Name(field.name),
-                                                },
-                                                reason,
-                                            ));
+                                        if let Some(reason) = validate_input_value(
+                                            &**validator,
+                                            value,
+                                            QueryPathNode {
+                                                parent: Some(&path_node),
+                                                segment: QueryPathSegment::Name(field.name),
+                                            },
+                                        ) {
+                                            return Some(reason);
                                         }
                                     }
 
@@ -185,3 +253,75 @@ pub fn is_valid_input_value(
         }
     }
 }
+
+/// Applies the transformers of the input object fields to a value, then checks each transformed value with the
+/// validator of its field.
+///
+/// It runs once, when an argument is parsed. The validation of the query skips the fields that have a transformer,
+/// because their transformed value isn't known before.
+pub fn transform_input_value(
+    registry: &registry::Registry,
+    type_name: &str,
+    value: Value,
+    path_node: QueryPathNode,
+) -> Result<Value, String> {
+    match registry::TypeName::create(type_name) {
+        registry::TypeName::NonNull(type_name) => {
+            transform_input_value(registry, type_name, value, path_node)
+        }
+        registry::TypeName::List(type_name) => match value {
+            Value::List(elems) => elems
+                .into_iter()
+                .enumerate()
+                .map(|(idx, elem)| {
+                    transform_input_value(
+                        registry,
+                        type_name,
+                        elem,
+                        QueryPathNode {
+                            parent: Some(&path_node),
+                            segment: QueryPathSegment::Index(idx),
+                        },
+                    )
+                })
+                .collect::<Result<_, _>>()
+                .map(Value::List),
+            value => transform_input_value(registry, type_name, value, path_node),
+        },
+        registry::TypeName::Named(type_name) => match (registry.types.get(type_name), value) {
+            (Some(registry::Type::InputObject { input_fields, .. }), Value::Object(mut values)) => {
+                for field in input_fields.values() {
+                    if let Some(mut value) = values.remove(field.name) {
+                        if let Some(transformer) = &field.transformer {
+                            value = transformer.transform(value);
+                            if let Some(validator) = &field.validator {
+                                if let Some(reason) = validate_input_value(
+                                    &**validator,
+                                    &value,
+                                    QueryPathNode {
+                                        parent: Some(&path_node),
+                                        segment: QueryPathSegment::Name(field.name),
+                                    },
+                                ) {
+                                    return Err(reason);
+                                }
+                            }
+                        }
+                        let value = transform_input_value(
+                            registry,
+                            &field.ty,
+                            value,
+                            QueryPathNode {
+                                parent: Some(&path_node),
+                                segment: QueryPathSegment::Name(field.name),
+                            },
+                        )?;
+                        values.insert(field.name.to_string(), value);
+                    }
+                }
+                Ok(Value::Object(values))
+            }
+            (_, value) => Ok(value),
+        },
+    }
+}
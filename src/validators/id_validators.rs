@@ -0,0 +1,56 @@
+use crate::validators::InputValueValidator;
+use graphql_parser::query::Value;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// The compiled regular expressions of the `IDPattern` validators, each pattern is compiled once.
+static ID_PATTERNS: Lazy<Mutex<HashMap<&'static str, Result<Regex, String>>>> =
+    Lazy::new(Default::default);
+
+/// ID format validator
+///
+/// `pattern` is a regular expression that the whole ID must match, such as `user_[0-9]+`.
+/// An ID sent as an integer is matched as its decimal string.
+///
+/// An invalid pattern is reported when the schema is built, see `SchemaBuilder::try_finish`.
+pub struct IDPattern {
+    /// The regular expression the ID must match.
+    pub pattern: &'static str,
+}
+
+impl IDPattern {
+    fn regex(&self) -> Result<Regex, String> {
+        ID_PATTERNS
+            .lock()
+            .entry(self.pattern)
+            .or_insert_with(|| {
+                Regex::new(&format!("^(?:{})$", self.pattern))
+                    .map_err(|err| format!("invalid id pattern \"{}\": {}", self.pattern, err))
+            })
+            .clone()
+    }
+}
+
+impl InputValueValidator for IDPattern {
+    fn is_valid(&self, value: &Value) -> Option<String> {
+        let id = match value {
+            Value::String(s) => s.clone(),
+            Value::Int(n) => n.as_i64()?.to_string(),
+            _ => return None,
+        };
+        match self.regex() {
+            Ok(re) if re.is_match(&id) => None,
+            Ok(_) => Some(format!(
+                "the id \"{}\" doesn't match the pattern \"{}\"",
+                id, self.pattern
+            )),
+            Err(err) => Some(err),
+        }
+    }
+
+    fn check(&self) -> Result<(), String> {
+        self.regex().map(|_| ())
+    }
+}
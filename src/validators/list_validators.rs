@@ -48,3 +48,30 @@ impl InputValueValidator for ListMaxLength {
         }
     }
 }
+
+/// List elements validator
+///
+/// Applies a validator to each element of a list, such as `validator(list(Email))`.
+pub struct List<V>(pub V);
+
+impl<V: InputValueValidator> InputValueValidator for List<V> {
+    fn is_valid(&self, value: &Value) -> Option<String> {
+        if let Value::List(values) = value {
+            values.iter().enumerate().find_map(|(idx, value)| {
+                self.0
+                    .is_valid(value)
+                    .map(|reason| format!("the element {} is invalid, {}", idx, reason))
+            })
+        } else {
+            None
+        }
+    }
+
+    fn elements(&self) -> Option<&dyn InputValueValidator> {
+        Some(&self.0)
+    }
+
+    fn check(&self) -> Result<(), String> {
+        self.0.check()
+    }
+}
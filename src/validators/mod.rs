@@ -1,14 +1,16 @@
 //! Input value validators
 
+mod id_validators;
 mod int_validators;
 mod list_validators;
 mod string_validators;
 
 use graphql_parser::schema::Value;
 
+pub use id_validators::IDPattern;
 pub use int_validators::{IntEqual, IntGreaterThan, IntLessThan, IntNonZero, IntRange};
-pub use list_validators::{ListMaxLength, ListMinLength};
-pub use string_validators::{Email, StringMaxLength, StringMinLength, MAC};
+pub use list_validators::{List, ListMaxLength, ListMinLength};
+pub use string_validators::{Chars, Email, StringMaxLength, StringMinLength, MAC};
 
 /// Input value validator
 ///
@@ -39,6 +41,12 @@ pub use string_validators::{Email, StringMaxLength, StringMinLength, MAC};
 ///     async fn value3(&self, #[arg(validator(IntRange(min = 100, max = 200)))] value: i32) -> i32 {
 ///         unimplemented!()
 ///     }
+///
+///     // Input is a list of email addresses
+///     #[field]
+///     async fn value4(&self, #[arg(validator(list(Email)))] emails: Vec<String>) -> i32 {
+///         unimplemented!()
+///     }
 /// }
 /// ```
 pub trait InputValueValidator
@@ -49,20 +57,61 @@ where
     ///
     /// If the input type is different from the required type, return None directly, and other validators will find this error.
     fn is_valid(&self, value: &Value) -> Option<String>;
+
+    /// Returns the validator of each element if this validator checks the elements of a list, see `List`.
+    ///
+    /// The elements are validated one by one, so the error contains the index of the invalid element.
+    fn elements(&self) -> Option<&dyn InputValueValidator> {
+        None
+    }
+
+    /// Checks the validator itself, such as a regular expression that doesn't compile, returns the reason if it is
+    /// invalid.
+    ///
+    /// It is called when the schema is built, the error is returned by `SchemaBuilder::try_finish`.
+    fn check(&self) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 /// An extension trait for `InputValueValidator`
+///
+/// The combinators used by `#[arg(validator(and(...), or(...)))]`, they can also build validators in Rust code.
+///
+/// ```rust
+/// use async_graphql::validators::*;
+/// use async_graphql::Value;
+///
+/// let validator = StringMinLength { length: 3 }
+///     .and(StringMaxLength { length: 10 })
+///     .and(Email {}.not("the value must not be an email address"));
+/// assert!(validator.is_valid(&Value::String("hello".to_string())).is_none());
+/// assert!(validator.is_valid(&Value::String("hi".to_string())).is_some());
+/// assert!(validator.is_valid(&Value::String("a@b.com".to_string())).is_some());
+/// ```
 pub trait InputValueValidatorExt: InputValueValidator + Sized {
     /// Merge the two validators and return None only if both validators are successful.
+    ///
+    /// The error is the error of the first validator that fails.
     fn and<R: InputValueValidator>(self, other: R) -> And<Self, R> {
         And(self, other)
     }
 
     /// Merge two validators, and return None when either validator verifies successfully.
+    ///
+    /// The error is the error of the first validator if both fail.
     fn or<R: InputValueValidator>(self, other: R) -> Or<Self, R> {
         Or(self, other)
     }
 
+    /// Negate the validator, return `message` when the validator verifies successfully, and None when it fails.
+    ///
+    /// Validators return None when the value has a different type than the one they check, so a negated
+    /// validator fails for these values.
+    fn not<M: Into<String>>(self, message: M) -> Not<Self> {
+        Not(self, message.into())
+    }
+
     /// Changes the error message
     fn map_err<F: Fn(String) -> String>(self, f: F) -> MapErr<Self, F> {
         MapErr(self, f)
@@ -80,7 +129,11 @@ where
     B: InputValueValidator,
 {
     fn is_valid(&self, value: &Value) -> Option<String> {
-        self.0.is_valid(value).and(self.1.is_valid(value))
+        self.0.is_valid(value).or_else(|| self.1.is_valid(value))
+    }
+
+    fn check(&self) -> Result<(), String> {
+        self.0.check().and_then(|_| self.1.check())
     }
 }
 
@@ -93,7 +146,32 @@ where
     B: InputValueValidator,
 {
     fn is_valid(&self, value: &Value) -> Option<String> {
-        self.0.is_valid(value).or_else(|| self.1.is_valid(value))
+        self.0
+            .is_valid(value)
+            .and_then(|err| self.1.is_valid(value).map(|_| err))
+    }
+
+    fn check(&self) -> Result<(), String> {
+        self.0.check().and_then(|_| self.1.check())
+    }
+}
+
+/// Invalidator for `InputValueValidator::not`
+pub struct Not<I>(I, String);
+
+impl<I> InputValueValidator for Not<I>
+where
+    I: InputValueValidator,
+{
+    fn is_valid(&self, value: &Value) -> Option<String> {
+        match self.0.is_valid(value) {
+            Some(_) => None,
+            None => Some(self.1.clone()),
+        }
+    }
+
+    fn check(&self) -> Result<(), String> {
+        self.0.check()
     }
 }
 
@@ -108,4 +186,8 @@ where
     fn is_valid(&self, value: &Value) -> Option<String> {
         self.0.is_valid(value).map(&self.1)
     }
+
+    fn check(&self) -> Result<(), String> {
+        self.0.check()
+    }
 }
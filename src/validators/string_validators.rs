@@ -51,6 +51,51 @@ impl InputValueValidator for StringMaxLength {
     }
 }
 
+/// Allowed characters validator
+///
+/// `allowed` is a set of characters, such as `a-z0-9_-`. A `-` between two characters is a range,
+/// a `-` at the beginning or the end is the character itself.
+pub struct Chars {
+    /// The allowed characters.
+    pub allowed: &'static str,
+}
+
+impl Chars {
+    fn is_allowed(&self, c: char) -> bool {
+        let allowed = self.allowed.chars().collect::<Vec<_>>();
+        let mut idx = 0;
+        while idx < allowed.len() {
+            if idx + 2 < allowed.len() && allowed[idx + 1] == '-' {
+                if (allowed[idx]..=allowed[idx + 2]).contains(&c) {
+                    return true;
+                }
+                idx += 3;
+            } else {
+                if allowed[idx] == c {
+                    return true;
+                }
+                idx += 1;
+            }
+        }
+        false
+    }
+}
+
+impl InputValueValidator for Chars {
+    fn is_valid(&self, value: &Value) -> Option<String> {
+        if let Value::String(s) = value {
+            s.chars()
+                .enumerate()
+                .find(|(_, c)| !self.is_allowed(*c))
+                .map(|(pos, c)| {
+                    format!("contains disallowed character '{}' at position {}", c, pos)
+                })
+        } else {
+            None
+        }
+    }
+}
+
 static EMAIL_RE: Lazy<Regex> = Lazy::new(|| {
     Regex::new("^(([0-9A-Za-z!#$%&'*+-/=?^_`{|}~&&[^@]]+)|(\"([0-9A-Za-z!#$%&'*+-/=?^_`{|}~ \"(),:;<>@\\[\\\\\\]]+)\"))@").unwrap()
 });
@@ -0,0 +1,525 @@
+use crate::{Error, Pos, Result, Value};
+use graphql_parser::parse_query;
+use graphql_parser::query::{
+    Definition, Directive, Document, OperationDefinition, Selection, SelectionSet,
+};
+use std::borrow::Cow;
+use std::fmt::Write;
+
+/// Parses a GraphQL value literal, such as `{a: 1, b: [RED, GREEN], c: "abc"}`.
+///
+/// It's useful to build variables or default values from strings. The position of a parse error
+/// is relative to `source`.
+pub fn parse_value(source: &str) -> Result<Value> {
+    const PREFIX: &str = "query ($value: Int = ";
+
+    let (source, positions) = normalize_block_strings(source);
+    let mut document =
+        parse_query(&format!("{}{}\n) {{ value }}", PREFIX, source)).map_err(|err| {
+            match Error::from(err) {
+                Error::Parse {
+                    line,
+                    column,
+                    message,
+                } => {
+                    let column = if line == 1 {
+                        column.saturating_sub(PREFIX.len()).max(1)
+                    } else {
+                        column
+                    };
+                    let pos = positions.original(Pos { line, column });
+                    Error::Parse {
+                        line: pos.line,
+                        column: pos.column,
+                        message,
+                    }
+                }
+                err => err,
+            }
+        })?;
+
+    match document.definitions.pop() {
+        Some(Definition::Operation(OperationDefinition::Query(mut query)))
+            if document.definitions.is_empty() && query.variable_definitions.len() == 1 =>
+        {
+            query
+                .variable_definitions
+                .remove(0)
+                .default_value
+                .ok_or_else(|| Error::Parse {
+                    line: 1,
+                    column: 1,
+                    message: "Expected a value".to_string(),
+                })
+        }
+        _ => Err(Error::Parse {
+            line: 1,
+            column: 1,
+            message: "Invalid value".to_string(),
+        }),
+    }
+}
+
+/// Parses a query document, its block strings are normalized by `normalize_block_strings` and the positions of
+/// the document and of a parse error are those of `source`.
+pub(crate) fn parse_query_source(source: &str) -> Result<Document> {
+    let (normalized, positions) = normalize_block_strings(source);
+    let mut document = parse_query(&normalized).map_err(|err| match Error::from(err) {
+        Error::Parse {
+            line,
+            column,
+            message,
+        } => {
+            let pos = positions.original(Pos { line, column });
+            Error::Parse {
+                line: pos.line,
+                column: pos.column,
+                message,
+            }
+        }
+        err => err,
+    })?;
+    if !positions.0.is_empty() {
+        positions.restore_document(&mut document);
+    }
+    Ok(document)
+}
+
+/// The columns moved by `normalize_block_strings`.
+///
+/// A block string written on a single line can be longer once it is written as an escaped string, the tokens that
+/// follow it on the line are moved to the right. Each item is the line, the first moved column and the number of
+/// columns they are moved by.
+#[derive(Default)]
+struct PositionMap(Vec<(usize, usize, usize)>);
+
+impl PositionMap {
+    /// Returns the position in the original source of `pos`, a position in the normalized source.
+    fn original(&self, pos: Pos) -> Pos {
+        let shift = self
+            .0
+            .iter()
+            .filter(|(line, column, _)| *line == pos.line && *column <= pos.column)
+            .map(|(_, _, shift)| *shift)
+            .sum::<usize>();
+        Pos {
+            line: pos.line,
+            column: pos.column - shift,
+        }
+    }
+
+    fn restore(&self, pos: &mut Pos) {
+        *pos = self.original(*pos);
+    }
+
+    fn restore_directives(&self, directives: &mut [Directive]) {
+        for directive in directives {
+            self.restore(&mut directive.position);
+        }
+    }
+
+    fn restore_selection_set(&self, selection_set: &mut SelectionSet) {
+        self.restore(&mut selection_set.span.0);
+        self.restore(&mut selection_set.span.1);
+        for selection in &mut selection_set.items {
+            match selection {
+                Selection::Field(field) => {
+                    self.restore(&mut field.position);
+                    self.restore_directives(&mut field.directives);
+                    self.restore_selection_set(&mut field.selection_set);
+                }
+                Selection::FragmentSpread(fragment_spread) => {
+                    self.restore(&mut fragment_spread.position);
+                    self.restore_directives(&mut fragment_spread.directives);
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    self.restore(&mut inline_fragment.position);
+                    self.restore_directives(&mut inline_fragment.directives);
+                    self.restore_selection_set(&mut inline_fragment.selection_set);
+                }
+            }
+        }
+    }
+
+    fn restore_document(&self, document: &mut Document) {
+        for definition in &mut document.definitions {
+            let (position, variable_definitions, directives, selection_set) = match definition {
+                Definition::Operation(OperationDefinition::SelectionSet(selection_set)) => {
+                    self.restore_selection_set(selection_set);
+                    continue;
+                }
+                Definition::Operation(OperationDefinition::Query(query)) => (
+                    &mut query.position,
+                    &mut query.variable_definitions,
+                    &mut query.directives,
+                    &mut query.selection_set,
+                ),
+                Definition::Operation(OperationDefinition::Mutation(mutation)) => (
+                    &mut mutation.position,
+                    &mut mutation.variable_definitions,
+                    &mut mutation.directives,
+                    &mut mutation.selection_set,
+                ),
+                Definition::Operation(OperationDefinition::Subscription(subscription)) => (
+                    &mut subscription.position,
+                    &mut subscription.variable_definitions,
+                    &mut subscription.directives,
+                    &mut subscription.selection_set,
+                ),
+                Definition::Fragment(fragment) => {
+                    self.restore(&mut fragment.position);
+                    self.restore_directives(&mut fragment.directives);
+                    self.restore_selection_set(&mut fragment.selection_set);
+                    continue;
+                }
+            };
+            self.restore(position);
+            for variable_definition in variable_definitions {
+                self.restore(&mut variable_definition.position);
+            }
+            self.restore_directives(directives);
+            self.restore_selection_set(selection_set);
+        }
+    }
+}
+
+/// Rewrites the block strings of a GraphQL document as regular strings.
+///
+/// `graphql_parser` doesn't remove the indentation and the blank lines of block strings as required by the
+/// specification, so the value of each block string is computed here and written as an escaped string.
+/// The line breaks of a block string are kept after it and the escaped string is padded with spaces, so the
+/// positions of the following tokens don't change, except after a single line block string whose escaped string
+/// is longer, these are recorded in the returned `PositionMap`.
+fn normalize_block_strings(source: &str) -> (Cow<'_, str>, PositionMap) {
+    let mut positions = PositionMap::default();
+    if !source.contains(r#"""""#) {
+        return (Cow::Borrowed(source), positions);
+    }
+
+    let mut output = String::with_capacity(source.len());
+    let mut rest = source;
+    // the position at the end of `output`, only used to record the moved columns
+    let mut pos = Pos { line: 1, column: 1 };
+    while let Some((start, end)) = find_block_string(rest) {
+        let token = &rest[start..end];
+        let token_start = output.len();
+        output.push_str(&rest[..start]);
+        let literal_start = output.len();
+        push_string_literal(&mut output, &block_string_value(&token[3..token.len() - 3]));
+        let literal_len = output[literal_start..].chars().count();
+        let (lines, last_line) = match token.rfind('\n') {
+            Some(idx) => (token.matches('\n').count(), &token[idx + 1..]),
+            None => (0, token),
+        };
+        let mut padding = last_line.chars().count();
+        if lines > 0 {
+            output.extend(std::iter::repeat('\n').take(lines));
+        } else if literal_len > padding {
+            let mut literal_end = pos;
+            advance_pos(&mut literal_end, &output[token_start..]);
+            positions
+                .0
+                .push((literal_end.line, literal_end.column, literal_len - padding));
+            padding = 0;
+        } else {
+            padding -= literal_len;
+        }
+        output.extend(std::iter::repeat(' ').take(padding));
+        advance_pos(&mut pos, &output[token_start..]);
+        rest = &rest[end..];
+    }
+    output.push_str(rest);
+    (Cow::Owned(output), positions)
+}
+
+/// Moves `pos` to the end of `s`, which follows `pos`.
+fn advance_pos(pos: &mut Pos, s: &str) {
+    match s.rfind('\n') {
+        Some(idx) => {
+            pos.line += s.matches('\n').count();
+            pos.column = s[idx + 1..].chars().count() + 1;
+        }
+        None => pos.column += s.chars().count(),
+    }
+}
+
+/// Returns the byte range of the first block string in `source`, skipping comments and regular strings.
+fn find_block_string(source: &str) -> Option<(usize, usize)> {
+    let bytes = source.as_bytes();
+    let mut idx = 0;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'#' => {
+                while idx < bytes.len() && bytes[idx] != b'\n' && bytes[idx] != b'\r' {
+                    idx += 1;
+                }
+            }
+            b'"' if bytes[idx..].starts_with(b"\"\"\"") => {
+                let mut end = idx + 3;
+                while end < bytes.len() {
+                    if bytes[end..].starts_with(b"\\\"\"\"") {
+                        end += 4;
+                    } else if bytes[end..].starts_with(b"\"\"\"") {
+                        return Some((idx, end + 3));
+                    } else {
+                        end += 1;
+                    }
+                }
+                return None;
+            }
+            b'"' => {
+                idx += 1;
+                while idx < bytes.len() && bytes[idx] != b'"' && bytes[idx] != b'\n' {
+                    if bytes[idx] == b'\\' {
+                        idx += 1;
+                    }
+                    idx += 1;
+                }
+                idx += 1;
+            }
+            _ => idx += 1,
+        }
+    }
+    None
+}
+
+/// Computes the value of a block string from its raw content, as defined by `BlockStringValue` in the specification.
+fn block_string_value(raw: &str) -> String {
+    let raw = raw.replace(r#"\""""#, r#"""""#);
+    let is_whitespace = |c: char| c == ' ' || c == '\t';
+    let is_blank = |line: &&str| line.chars().all(is_whitespace);
+
+    let lines = raw.lines().collect::<Vec<_>>();
+    let common_indent = lines
+        .iter()
+        .skip(1)
+        .filter(|line| !is_blank(line))
+        .map(|line| line.len() - line.trim_start_matches(is_whitespace).len())
+        .min()
+        .unwrap_or(0);
+    let mut lines = lines
+        .into_iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            if idx == 0 {
+                line
+            } else {
+                &line[common_indent.min(line.len())..]
+            }
+        })
+        .skip_while(is_blank)
+        .collect::<Vec<_>>();
+    while lines.last().map_or(false, is_blank) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+fn push_string_literal(output: &mut String, value: &str) {
+    output.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str(r#"\""#),
+            '\\' => output.push_str(r#"\\"#),
+            '\n' => output.push_str(r#"\n"#),
+            '\r' => output.push_str(r#"\r"#),
+            '\t' => output.push_str(r#"\t"#),
+            c if c.is_control() => {
+                write!(output, "\\u{:04x}", c as u32).ok();
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
+/// Deep merge two values, returns the merged value.
+///
+/// If both values are objects, they are merged recursively. Otherwise `other` wins, so a list in `other`
+/// replaces the list in `value` instead of being concatenated.
+///
+/// It's useful to apply default values to a partially provided input object.
+pub fn merge_value(value: Value, other: Value) -> Value {
+    match (value, other) {
+        (Value::Object(mut obj), Value::Object(other)) => {
+            for (name, value) in other {
+                let value = match obj.remove(&name) {
+                    Some(prev) => merge_value(prev, value),
+                    None => value,
+                };
+                obj.insert(name, value);
+            }
+            Value::Object(obj)
+        }
+        (_, other) => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn object(fields: Vec<(&str, Value)>) -> Value {
+        Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect::<BTreeMap<_, _>>(),
+        )
+    }
+
+    #[test]
+    fn test_parse_object_value() {
+        assert_eq!(
+            parse_value(r#"{a: 1, b: [RED, 2.5], c: {d: "abc", e: null, f: true}}"#).unwrap(),
+            object(vec![
+                ("a", Value::Int(1.into())),
+                (
+                    "b",
+                    Value::List(vec![Value::Enum("RED".to_string()), Value::Float(2.5)]),
+                ),
+                (
+                    "c",
+                    object(vec![
+                        ("d", Value::String("abc".to_string())),
+                        ("e", Value::Null),
+                        ("f", Value::Boolean(true)),
+                    ]),
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_value_error() {
+        match parse_value("{a: 1, b: }") {
+            Err(Error::Parse { line, column, .. }) => {
+                assert_eq!(line, 1);
+                assert_eq!(column, 11);
+            }
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_parse_block_string() {
+        assert_eq!(
+            parse_value("\"\"\"\n    Hello,\n      World!\n\n    Yours,\n      GraphQL.\n  \"\"\"")
+                .unwrap(),
+            Value::String("Hello,\n  World!\n\nYours,\n  GraphQL.".to_string())
+        );
+        assert_eq!(
+            parse_value("\"\"\"  first line\n    \\\"\"\"quoted\\\"\"\"\n    \"\"\"").unwrap(),
+            Value::String("  first line\n\"\"\"quoted\"\"\"".to_string())
+        );
+        assert_eq!(
+            parse_value(r#""""single""""#).unwrap(),
+            Value::String("single".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_block_strings_keeps_positions() {
+        assert_eq!(
+            normalize_block_strings("{ a(s: \"\"\"\n  x\n  \"\"\") b }").0,
+            "{ a(s: \"x\"\n\n     ) b }"
+        );
+        assert_eq!(
+            normalize_block_strings(r#"{ a(s: """x""") b }"#).0,
+            r#"{ a(s: "x"    ) b }"#
+        );
+        assert_eq!(
+            normalize_block_strings("# \"\"\"\n{ a(s: \"\\\"\"\") }").0,
+            "# \"\"\"\n{ a(s: \"\\\"\"\") }"
+        );
+    }
+
+    #[test]
+    fn test_parse_query_source_restores_positions() {
+        // the escaped string is 2 columns longer than the block string
+        let source = r#"{ a(s: """\""" \""" \"""""") b }"#;
+        let (normalized, _) = normalize_block_strings(source);
+        assert_eq!(
+            normalized.find(" b }").unwrap(),
+            source.find(" b }").unwrap() + 2
+        );
+
+        let document = parse_query_source(source).unwrap();
+        let field = match &document.definitions[0] {
+            Definition::Operation(OperationDefinition::SelectionSet(selection_set)) => {
+                match &selection_set.items[1] {
+                    Selection::Field(field) => field,
+                    _ => panic!("expected a field"),
+                }
+            }
+            _ => panic!("expected a selection set"),
+        };
+        assert_eq!(field.name, "b");
+        assert_eq!(
+            field.position,
+            Pos {
+                line: 1,
+                column: 30
+            }
+        );
+
+        match parse_query_source(&source.replace(" b }", " b ) }")) {
+            Err(Error::Parse { line, column, .. }) => {
+                assert_eq!(line, 1);
+                assert_eq!(column, 31);
+            }
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_merge_nested_object() {
+        let value = object(vec![
+            ("a", Value::Int(1.into())),
+            (
+                "b",
+                object(vec![
+                    ("c", Value::Int(2.into())),
+                    ("d", Value::String("abc".to_string())),
+                ]),
+            ),
+        ]);
+        let other = object(vec![
+            ("b", object(vec![("d", Value::Boolean(true))])),
+            ("e", Value::Null),
+        ]);
+        assert_eq!(
+            merge_value(value, other),
+            object(vec![
+                ("a", Value::Int(1.into())),
+                (
+                    "b",
+                    object(vec![
+                        ("c", Value::Int(2.into())),
+                        ("d", Value::Boolean(true))
+                    ]),
+                ),
+                ("e", Value::Null),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_merge_replace_list() {
+        let value = object(vec![(
+            "a",
+            Value::List(vec![Value::Int(1.into()), Value::Int(2.into())]),
+        )]);
+        let other = object(vec![("a", Value::List(vec![Value::Int(3.into())]))]);
+        assert_eq!(
+            merge_value(value, other),
+            object(vec![("a", Value::List(vec![Value::Int(3.into())]))])
+        );
+
+        assert_eq!(
+            merge_value(Value::Int(1.into()), object(vec![])),
+            object(vec![])
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use async_graphql::http::{GQLBatchResponse, GQLResponse};
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_batch_errors_are_isolated() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+
+        #[field]
+        async fn error(&self) -> FieldResult<i32> {
+            Err("failed".into())
+        }
+
+        #[field]
+        async fn partial(&self) -> Vec<FieldResult<i32>> {
+            vec![Ok(1), Err("failed to load 2".into())]
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let body: &[u8] =
+        br#"[{"query": "{ error }"}, {"query": "{ value }"}, {"query": "{ partial }"}, {"query": "{ value }"}]"#;
+    let builders = (Some("application/json"), body)
+        .into_batch_query_builder()
+        .await
+        .unwrap();
+    let resp = GQLBatchResponse(
+        builders
+            .execute(&schema)
+            .await
+            .into_iter()
+            .map(GQLResponse)
+            .collect(),
+    );
+    assert_eq!(
+        serde_json::to_value(&resp).unwrap(),
+        serde_json::json!([
+            {
+                "errors": [{
+                    "message": "failed",
+                    "locations": [{ "line": 1, "column": 3 }],
+                    "path": ["error"],
+                }]
+            },
+            { "data": { "value": 10 } },
+            {
+                "data": { "partial": [1, null] },
+                "errors": [{
+                    "message": "failed to load 2",
+                    "locations": [{ "line": 1, "column": 3 }],
+                    "path": ["partial", 1],
+                }]
+            },
+            { "data": { "value": 10 } },
+        ])
+    );
+}
@@ -0,0 +1,205 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_complexity() {
+    #[SimpleObject]
+    struct Item {
+        #[field]
+        a: i32,
+
+        #[field]
+        b: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+
+        #[field(complexity = "child_complexity * first as usize")]
+        async fn items(&self, #[arg(default = "1")] first: i32) -> Vec<Item> {
+            (0..first).map(|n| Item { a: n, b: n }).collect()
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_complexity(10)
+        .finish();
+
+    // 1 + 2 * 4
+    assert!(schema
+        .execute("{ value items(first: 4) { a b } }")
+        .await
+        .is_ok());
+
+    // 1 + 2 * 5
+    match schema.execute("{ value items(first: 5) { a b } }").await {
+        Err(Error::Query {
+            err: QueryError::TooComplex,
+            ..
+        }) => {}
+        _ => panic!("expected TooComplex"),
+    }
+
+    // the default value of the argument
+    assert!(schema.execute("{ items { a b } }").await.is_ok());
+
+    // fragments and variables
+    let query = r#"
+        query($first: Int!) { items(first: $first) { ...ItemFields } }
+        fragment ItemFields on Item { a b }
+    "#;
+    let execute = |first: i32| {
+        QueryBuilder::new(query)
+            .variables(Variables::parse_from_json(serde_json::json!({ "first": first })).unwrap())
+            .execute(&schema)
+    };
+    assert!(execute(5).await.is_ok());
+    match execute(6).await {
+        Err(Error::Query {
+            err: QueryError::TooComplex,
+            ..
+        }) => {}
+        _ => panic!("expected TooComplex"),
+    }
+
+    // the skipped selections cost nothing, 1 + 1 * 9
+    assert!(schema
+        .execute("{ value items(first: 9) { a b @skip(if: true) } }")
+        .await
+        .is_ok());
+    assert!(schema
+        .execute("{ value items(first: 9) { a ... on Item @include(if: false) { b } } }")
+        .await
+        .is_ok());
+    match schema
+        .execute("{ value items(first: 9) { a b @include(if: true) } }")
+        .await
+    {
+        Err(Error::Query {
+            err: QueryError::TooComplex,
+            ..
+        }) => {}
+        _ => panic!("expected TooComplex"),
+    }
+}
+
+#[async_std::test]
+pub async fn test_complexity_ignore_deprecated() {
+    #[SimpleObject]
+    struct Item {
+        #[field]
+        a: i32,
+
+        #[field]
+        b: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+
+        #[field(deprecation = "Use `value`")]
+        async fn old_value(&self) -> i32 {
+            10
+        }
+
+        #[field(deprecation = "Use `value`")]
+        async fn old_items(&self) -> Vec<Item> {
+            vec![Item { a: 1, b: 2 }]
+        }
+    }
+
+    let query = "{ value oldValue oldItems { a b } }";
+
+    // 1 + 1 + (1 + 2)
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_complexity(3)
+        .finish();
+    match schema.execute(query).await {
+        Err(Error::Query {
+            err: QueryError::TooComplex,
+            ..
+        }) => {}
+        _ => panic!("expected TooComplex"),
+    }
+
+    // 1 + 0 + (0 + 2)
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_complexity(3)
+        .ignore_deprecated_complexity(true)
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "value": 10,
+            "oldValue": 10,
+            "oldItems": [{ "a": 1, "b": 2 }],
+        })
+    );
+
+    // the selection set of a deprecated field is still counted
+    match schema
+        .execute("{ oldItems { a b } other: oldItems { a b } }")
+        .await
+    {
+        Err(Error::Query {
+            err: QueryError::TooComplex,
+            ..
+        }) => {}
+        _ => panic!("expected TooComplex"),
+    }
+    match schema.execute("{ value a: value b: value c: value }").await {
+        Err(Error::Query {
+            err: QueryError::TooComplex,
+            ..
+        }) => {}
+        _ => panic!("expected TooComplex"),
+    }
+}
+
+#[async_std::test]
+pub async fn test_complexity_chained_fragments() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_complexity(1000)
+        .finish();
+
+    // each fragment spreads the next one twice, so the query selects `value` 2^40 times
+    let mut query = String::from("{ ...F0 }");
+    for i in 0..40 {
+        query.push_str(&format!(
+            " fragment F{} on QueryRoot {{ ...F{} ...F{} }}",
+            i,
+            i + 1,
+            i + 1
+        ));
+    }
+    query.push_str(" fragment F40 on QueryRoot { value }");
+
+    match schema.execute(&query).await {
+        Err(Error::Query {
+            err: QueryError::TooComplex,
+            ..
+        }) => {}
+        _ => panic!("expected TooComplex"),
+    }
+}
@@ -0,0 +1,269 @@
+use async_graphql::*;
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    #[field]
+    async fn numbers(
+        &self,
+        ctx: &Context<'_>,
+        after: Option<String>,
+        before: Option<String>,
+        first: Option<i32>,
+        last: Option<i32>,
+    ) -> FieldResult<Connection<&i32, EmptyEdgeFields>> {
+        const NUMBERS: &[i32] = &[1, 2, 3, 4, 5];
+        NUMBERS.query(ctx, after, before, first, last).await
+    }
+}
+
+#[async_std::test]
+pub async fn test_connection_first_zero() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                "{ numbers(first: 0) { edges { node } pageInfo { hasPreviousPage hasNextPage } } }"
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "numbers": {
+                "edges": [],
+                "pageInfo": { "hasPreviousPage": false, "hasNextPage": true },
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_connection_first_oversized() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ numbers(first: 100) { edges { node } pageInfo { hasPreviousPage hasNextPage } } }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "numbers": {
+                "edges": [
+                    { "node": 1 },
+                    { "node": 2 },
+                    { "node": 3 },
+                    { "node": 4 },
+                    { "node": 5 },
+                ],
+                "pageInfo": { "hasPreviousPage": false, "hasNextPage": false },
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_connection_negative_first() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    match schema
+        .execute("{ numbers(first: -1) { edges { node } } }")
+        .await
+    {
+        Err(Error::Query {
+            err: QueryError::FieldError { err, .. },
+            ..
+        }) => assert_eq!(err, "The \"first\" parameter must be a non-negative number"),
+        _ => panic!("expected a field error"),
+    }
+}
+
+#[async_std::test]
+pub async fn test_connection_custom_names() {
+    #[SimpleObject]
+    struct Weight {
+        #[field]
+        weight: i32,
+    }
+
+    struct NumberList;
+
+    impl ConnectionNameType for NumberList {
+        fn type_name<T: OutputValueType>() -> String {
+            "NumberList".to_string()
+        }
+    }
+
+    struct NumberItem;
+
+    impl EdgeNameType for NumberItem {
+        fn type_name<T: OutputValueType>() -> String {
+            "NumberItem".to_string()
+        }
+    }
+
+    struct Root;
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn numbers(&self) -> Connection<i32, Weight, NumberList, NumberItem> {
+            Connection::new(
+                Some(2),
+                false,
+                false,
+                vec![
+                    ("a".to_string(), Weight { weight: 10 }, 1),
+                    ("b".to_string(), Weight { weight: 20 }, 2),
+                ],
+            )
+        }
+    }
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ numbers { totalCount edges { cursor node weight } } }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "numbers": {
+                "totalCount": 2,
+                "edges": [
+                    { "cursor": "a", "node": 1, "weight": 10 },
+                    { "cursor": "b", "node": 2, "weight": 20 },
+                ],
+            }
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    connection: __type(name: "NumberList") { name }
+                    edge: __type(name: "NumberItem") { fields { name } }
+                    defaultName: __type(name: "IntConnection") { name }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "connection": { "name": "NumberList" },
+            "edge": {
+                "fields": [
+                    { "name": "cursor" },
+                    { "name": "node" },
+                    { "name": "weight" },
+                ]
+            },
+            "defaultName": null,
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_connection_conflicting_arguments() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    for (query, message) in &[
+        (
+            "{ numbers(first: 1, last: 1) { edges { node } } }",
+            "The \"first\" and \"last\" parameters cannot exist at the same time",
+        ),
+        (
+            r#"{ numbers(after: "a", before: "b") { edges { node } } }"#,
+            "The \"after\" and \"before\" parameters cannot exist at the same time",
+        ),
+    ] {
+        match schema.execute(query).await {
+            Err(Error::Query {
+                err: QueryError::FieldError { err, .. },
+                ..
+            }) => assert_eq!(&err, message),
+            _ => panic!("expected a field error"),
+        }
+    }
+
+    // A single direction is still accepted.
+    assert!(schema
+        .execute("{ numbers(first: 1) { edges { node } } }")
+        .await
+        .is_ok());
+    assert!(schema
+        .execute("{ numbers(last: 1) { edges { node } } }")
+        .await
+        .is_ok());
+}
+
+#[async_std::test]
+pub async fn test_connection_signed_cursor() {
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(CursorSigningKey::new(b"secret"))
+        .finish();
+
+    let res = schema
+        .execute("{ numbers(first: 2) { edges { node } pageInfo { endCursor } } }")
+        .await
+        .unwrap()
+        .data;
+    let end_cursor = res["numbers"]["pageInfo"]["endCursor"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(
+        CursorSigningKey::new(b"secret")
+            .verify(&end_cursor)
+            .unwrap(),
+        base64::encode(1u32.to_be_bytes())
+    );
+
+    assert_eq!(
+        schema
+            .execute(&format!(
+                r#"{{ numbers(after: "{}", first: 2) {{ edges {{ node }} }} }}"#,
+                end_cursor
+            ))
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "numbers": {
+                "edges": [{ "node": 3 }, { "node": 4 }],
+            }
+        })
+    );
+
+    let tampered = format!(
+        "{}{}",
+        base64::encode(3u32.to_be_bytes()),
+        &end_cursor[end_cursor.find('.').unwrap()..]
+    );
+    match schema
+        .execute(&format!(
+            r#"{{ numbers(after: "{}", first: 2) {{ edges {{ node }} }} }}"#,
+            tampered
+        ))
+        .await
+    {
+        Err(Error::Query {
+            err: QueryError::FieldError { err, .. },
+            ..
+        }) => assert_eq!(err, "Invalid cursor"),
+        _ => panic!("expected the tampered cursor to be rejected"),
+    }
+
+    match schema
+        .execute(&format!(
+            r#"{{ numbers(after: "{}", first: 2) {{ edges {{ node }} }} }}"#,
+            base64::encode(1u32.to_be_bytes())
+        ))
+        .await
+    {
+        Err(Error::Query {
+            err: QueryError::FieldError { err, .. },
+            ..
+        }) => assert_eq!(err, "Invalid cursor"),
+        _ => panic!("expected the unsigned cursor to be rejected"),
+    }
+}
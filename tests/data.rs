@@ -0,0 +1,81 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_data_or_default() {
+    #[derive(Default, Clone)]
+    struct Settings {
+        prefix: String,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self, ctx: &Context<'_>) -> String {
+            format!("{}value", ctx.data_or_default::<Settings>().prefix)
+        }
+
+        #[field]
+        async fn count(&self, ctx: &Context<'_>) -> i32 {
+            *ctx.data_or_default::<i32>()
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(Settings {
+            prefix: "my_".to_string(),
+        })
+        .finish();
+    assert_eq!(
+        schema.execute("{ value count }").await.unwrap().data,
+        serde_json::json!({
+            "value": "my_value",
+            "count": 0,
+        })
+    );
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute("{ value count }").await.unwrap().data,
+        serde_json::json!({
+            "value": "value",
+            "count": 0,
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_request_extensions() {
+    use async_graphql::http::GQLRequest;
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn trace_id(&self, ctx: &Context<'_>) -> Option<String> {
+            ctx.request_extensions()
+                .and_then(|extensions| extensions.get("traceId"))
+                .and_then(|trace_id| trace_id.as_str())
+                .map(ToString::to_string)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let request: GQLRequest = serde_json::from_value(serde_json::json!({
+        "query": "{ traceId }",
+        "extensions": { "traceId": "abc" },
+    }))
+    .unwrap();
+    let builder = request.into_query_builder().await.unwrap();
+    assert_eq!(
+        builder.execute(&schema).await.unwrap().data,
+        serde_json::json!({ "traceId": "abc" })
+    );
+
+    assert_eq!(
+        schema.execute("{ traceId }").await.unwrap().data,
+        serde_json::json!({ "traceId": null })
+    );
+}
@@ -63,3 +63,45 @@ pub async fn test_directive_include() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_register_directive() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        pub async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .register_directive(Directive {
+            name: "audit",
+            description: Some("Records the access to the field."),
+            locations: vec![DirectiveLocation::FIELD],
+            args: Default::default(),
+            is_repeatable: false,
+        })
+        .finish();
+
+    let resp = schema
+        .execute(r#"{ __schema { directives { name description locations isRepeatable } } }"#)
+        .await
+        .unwrap();
+    let directives = resp.data["__schema"]["directives"].as_array().unwrap();
+    assert!(directives.contains(&serde_json::json!({
+        "name": "audit",
+        "description": "Records the access to the field.",
+        "locations": ["FIELD"],
+        "isRepeatable": false,
+    })));
+
+    assert_eq!(
+        schema.execute("{ value @audit }").await.unwrap().data,
+        serde_json::json!({ "value": 10 })
+    );
+    assert!(schema.execute("query @audit { value }").await.is_err());
+    assert!(schema.execute("{ value @audit @audit }").await.is_err());
+}
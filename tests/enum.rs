@@ -75,3 +75,93 @@ pub async fn test_enum_derive_and_item_attributes() {
         TestStruct { value: Test::Real }
     );
 }
+
+#[async_std::test]
+pub async fn test_enum_int_variable() {
+    #[Enum]
+    enum Code {
+        A = 1,
+        B = 2,
+    }
+
+    struct Root;
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn code(&self, input: Code) -> Code {
+            input
+        }
+    }
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    let query = r#"query($code: Code!) { code(input: $code) }"#;
+
+    let resp = QueryBuilder::new(query)
+        .variables(Variables::parse_from_json(serde_json::json!({ "code": 2 })).unwrap())
+        .execute(&schema)
+        .await
+        .unwrap();
+    assert_eq!(resp.data, serde_json::json!({ "code": "B" }));
+
+    match QueryBuilder::new(query)
+        .variables(Variables::parse_from_json(serde_json::json!({ "code": 3 })).unwrap())
+        .execute(&schema)
+        .await
+    {
+        Err(Error::Query { err, .. }) => {
+            assert_eq!(err.to_string(), "Invalid value 3 for enum \"Code\".")
+        }
+        _ => panic!("expected a query error"),
+    }
+
+    // the literals of the query must be enum values
+    assert!(schema.execute("{ code(input: 2) }").await.is_err());
+}
+
+#[async_std::test]
+pub async fn test_enum_rename_items() {
+    #[Enum(rename_items = "camelCase")]
+    enum MyEnum {
+        FirstValue,
+        #[item(name = "SECOND")]
+        SecondValue,
+    }
+
+    struct Root;
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn value(&self, input: MyEnum) -> MyEnum {
+            input
+        }
+    }
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ a: value(input: firstValue) b: value(input: SECOND) }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "a": "firstValue",
+            "b": "SECOND",
+        })
+    );
+    assert!(schema
+        .execute("{ value(input: FIRST_VALUE) }")
+        .await
+        .is_err());
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "MyEnum") { enumValues { name } } }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": { "enumValues": [{ "name": "SECOND" }, { "name": "firstValue" }] }
+        })
+    );
+}
@@ -0,0 +1,88 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_error_display() {
+    struct Item;
+
+    #[Object]
+    impl Item {
+        #[field]
+        async fn value(&self) -> FieldResult<i32> {
+            Err("bad value".into())
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn items(&self) -> Vec<Item> {
+            vec![Item]
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let err = schema
+        .execute("{\n  items { value }\n}")
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Query error at 2:11 (path: items.0.value): Failed to resolve field: bad value"
+    );
+
+    let err = schema.execute("{ items {").await.err().unwrap();
+    let message = err.to_string();
+    assert!(message.starts_with("Parse error at 1:"));
+    assert!(!message.contains('\n'));
+
+    let err = schema.execute("{ unknown }").await.err().unwrap();
+    let message = err.to_string();
+    assert!(message.starts_with(r#"Rule error: Unknown field "unknown" on type "QueryRoot"."#));
+    assert!(message.ends_with(" at 1:3"));
+}
+
+#[async_std::test]
+pub async fn test_field_error_source() {
+    #[derive(Debug)]
+    struct DbError;
+
+    impl std::fmt::Display for DbError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "connection refused")
+        }
+    }
+
+    impl std::error::Error for DbError {}
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> FieldResult<i32> {
+            Err(FieldError::from("failed to load value").with_source(DbError))
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let err = schema.execute("{ value }").await.err().unwrap();
+
+    let source = std::error::Error::source(&err).unwrap();
+    assert_eq!(source.to_string(), "connection refused");
+    assert!(source.downcast_ref::<DbError>().is_some());
+
+    let resp = serde_json::to_value(async_graphql::http::GQLResponse(Err(err))).unwrap();
+    assert_eq!(
+        resp,
+        serde_json::json!({
+            "errors": [{
+                "message": "failed to load value",
+                "locations": [{"line": 1, "column": 3}],
+                "path": ["value"],
+            }]
+        })
+    );
+}
@@ -0,0 +1,182 @@
+use async_graphql::extensions::{ApolloTracing, Extension, RequestTiming, ResponseSize};
+use async_graphql::*;
+use std::sync::{Arc, Mutex};
+
+#[async_std::test]
+pub async fn test_extension_query_and_variables() {
+    #[derive(Default)]
+    struct Received {
+        query: Option<String>,
+        variables: Option<Variables>,
+    }
+
+    struct Recorder(Arc<Mutex<Received>>);
+
+    impl Extension for Recorder {
+        fn name(&self) -> &'static str {
+            "recorder"
+        }
+
+        fn parse_start(&self, query_source: &str) {
+            self.0.lock().unwrap().query = Some(query_source.to_string());
+        }
+
+        fn parse_variables(&self, variables: &Variables) {
+            self.0.lock().unwrap().variables = Some(variables.clone());
+        }
+
+        fn result(&self) -> Option<serde_json::Value> {
+            None
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self, n: i32) -> i32 {
+            n
+        }
+    }
+
+    let received = Arc::new(Mutex::new(Received::default()));
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .extension({
+            let received = received.clone();
+            move || Recorder(received.clone())
+        })
+        .finish();
+
+    let query = "query($n: Int!) { value(n: $n) }";
+    let variables = Variables::parse_from_json(serde_json::json!({ "n": 10 })).unwrap();
+    QueryBuilder::new(query)
+        .variables(variables)
+        .execute(&schema)
+        .await
+        .unwrap();
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.query.as_deref(), Some(query));
+    let variables = received.variables.as_ref().unwrap();
+    assert_eq!(variables.len(), 1);
+    assert_eq!(variables.get("n"), Some(&Value::Int(10.into())));
+}
+
+#[async_std::test]
+pub async fn test_response_size_extension() {
+    #[SimpleObject]
+    struct MyObj {
+        #[field]
+        a: i32,
+
+        #[field]
+        b: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn obj(&self) -> MyObj {
+            MyObj { a: 1, b: 2 }
+        }
+
+        #[field]
+        async fn list(&self) -> Vec<MyObj> {
+            vec![MyObj { a: 3, b: 4 }, MyObj { a: 5, b: 6 }]
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .extension(ResponseSize::default)
+        .finish();
+    let resp = schema
+        .execute("{ obj { a b } list { a __typename } }")
+        .await
+        .unwrap();
+    let bytes = serde_json::to_vec(&resp.data).unwrap().len();
+    assert_eq!(
+        resp.extensions.unwrap()["responseSize"],
+        serde_json::json!({ "nodes": 6, "bytes": bytes })
+    );
+}
+
+#[async_std::test]
+pub async fn test_request_timing_extension() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .extension(RequestTiming::default)
+        .extension(ApolloTracing::default)
+        .try_finish()
+        .unwrap();
+    let resp = schema.execute("{ value }").await.unwrap();
+    let extensions = resp.extensions.unwrap();
+    assert!(extensions.contains_key("tracing"));
+    let tracing = &extensions["requestTiming"];
+
+    assert_eq!(tracing["version"], serde_json::json!(1));
+    assert!(tracing["startTime"].is_string());
+    assert!(tracing["endTime"].is_string());
+    assert!(tracing["duration"].as_i64().unwrap() > 0);
+    assert_eq!(tracing["execution"], serde_json::json!({ "resolvers": [] }));
+}
+
+#[async_std::test]
+pub async fn test_extension_results() {
+    struct Static(&'static str, serde_json::Value);
+
+    impl Extension for Static {
+        fn name(&self) -> &'static str {
+            self.0
+        }
+
+        fn result(&self) -> Option<serde_json::Value> {
+            Some(self.1.clone())
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .extension(|| Static("cost", serde_json::json!({ "requested": 1 })))
+        .extension(|| Static("logging", serde_json::json!({ "id": "abc" })))
+        .try_finish()
+        .unwrap();
+    let resp = schema.execute("{ value }").await.unwrap();
+    assert_eq!(
+        serde_json::Value::Object(resp.extensions.unwrap()),
+        serde_json::json!({
+            "cost": { "requested": 1 },
+            "logging": { "id": "abc" },
+        })
+    );
+
+    match Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .extension(|| Static("cost", serde_json::json!({ "requested": 1 })))
+        .extension(|| Static("logging", serde_json::json!({ "id": "abc" })))
+        .extension(|| Static("cost", serde_json::json!({ "actual": 2 })))
+        .try_finish()
+    {
+        Err(SchemaError::DuplicateExtensionName { name }) => assert_eq!(name, "cost"),
+        _ => panic!("expected a duplicate extension name error"),
+    }
+}
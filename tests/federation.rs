@@ -91,3 +91,177 @@ pub async fn test_federation() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_federation_requires() {
+    struct Item {
+        id: ID,
+    }
+
+    #[Object(extends)]
+    impl Item {
+        #[field(external)]
+        async fn id(&self) -> &ID {
+            &self.id
+        }
+
+        #[field(external)]
+        async fn weight(&self, ctx: &Context<'_>) -> Option<i32> {
+            ctx.entity_field("weight")
+        }
+
+        #[field(requires = "weight")]
+        async fn shipping_estimate(&self, ctx: &Context<'_>) -> Option<i32> {
+            ctx.entity_field::<i32>("weight").map(|weight| weight * 2)
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[entity]
+        async fn find_item_by_id(&self, id: ID) -> Item {
+            Item { id }
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        _entities(representations: [{__typename: "Item", id: "1", weight: 10}]) {
+            ... on Item {
+                shippingEstimate
+            }
+        }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "_entities": [
+                {"shippingEstimate": 20},
+            ]
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_federation_disabled() {
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .enable_federation(false)
+        .finish();
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    service: __type(name: "_Service") { name }
+                    entity: __type(name: "_Entity") { name }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "service": null,
+            "entity": null,
+        })
+    );
+    assert!(schema.execute("{ _service { sdl } }").await.is_err());
+}
+
+#[async_std::test]
+pub async fn test_federation_enabled_without_entities() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .enable_federation(true)
+        .finish();
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    service: __type(name: "_Service") { name }
+                    entity: __type(name: "_Entity") { name }
+                    any: __type(name: "_Any") { name }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "service": { "name": "_Service" },
+            "entity": null,
+            "any": null,
+        })
+    );
+    assert!(schema
+        .execute("{ _entities(representations: []) { __typename } }")
+        .await
+        .is_err());
+}
+
+#[async_std::test]
+pub async fn test_federation_disabled_keeps_any() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn echo(&self, value: Any) -> Any {
+            value
+        }
+
+        #[entity]
+        async fn find_user_by_id(&self, id: ID) -> User {
+            User { id }
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .enable_federation(false)
+        .finish();
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    entity: __type(name: "_Entity") { name }
+                    any: __type(name: "_Any") { name }
+                    echo(value: 1)
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "entity": null,
+            "any": { "name": "_Any" },
+            "echo": 1,
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_federation_link() {
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .federation_link("https://specs.apollo.dev/federation/v2.0")
+        .finish();
+    let resp = schema.execute("{ _service { sdl } }").await.unwrap();
+    let sdl = resp.data["_service"]["sdl"].as_str().unwrap();
+    assert!(sdl.starts_with(
+        "extend schema @link(url: \"https://specs.apollo.dev/federation/v2.0\", import: [\"@key\", \"@external\", \"@provides\"])\n\n"
+    ));
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let resp = schema.execute("{ _service { sdl } }").await.unwrap();
+    assert!(!resp.data["_service"]["sdl"]
+        .as_str()
+        .unwrap()
+        .contains("@link"));
+}
@@ -0,0 +1,141 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_merge_fields_from_fragment() {
+    #[SimpleObject]
+    struct Detail {
+        #[field]
+        x: i32,
+
+        #[field]
+        y: i32,
+    }
+
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        #[field]
+        async fn a(&self) -> i32 {
+            1
+        }
+
+        #[field]
+        async fn b(&self) -> i32 {
+            2
+        }
+
+        #[field]
+        async fn details(&self) -> Vec<Detail> {
+            vec![Detail { x: 10, y: 20 }, Detail { x: 30, y: 40 }]
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[field]
+        async fn obj(&self) -> MyObj {
+            MyObj
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let query = r#"{
+        obj { a details { x } }
+        ... on Query {
+            obj { b details { y } }
+        }
+        ...QueryFragment
+    }
+
+    fragment QueryFragment on Query {
+        obj { a details { x } }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "obj": {
+                "a": 1,
+                "b": 2,
+                "details": [
+                    { "x": 10, "y": 20 },
+                    { "x": 30, "y": 40 },
+                ]
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_merge_fields_resolved_once() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[SimpleObject]
+    struct Counter {
+        #[field]
+        value: i32,
+
+        #[field]
+        items: Vec<i32>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[field]
+        async fn counter(&self, ctx: &Context<'_>) -> Counter {
+            let value = ctx.data::<AtomicUsize>().fetch_add(1, Ordering::SeqCst) as i32 + 1;
+            Counter {
+                value,
+                items: vec![value, value],
+            }
+        }
+    }
+
+    struct Mutation;
+
+    #[Object]
+    impl Mutation {
+        #[field]
+        async fn increment(&self, ctx: &Context<'_>) -> Counter {
+            let value = ctx.data::<AtomicUsize>().fetch_add(1, Ordering::SeqCst) as i32 + 1;
+            Counter {
+                value,
+                items: vec![value],
+            }
+        }
+    }
+
+    let schema = Schema::build(Query, Mutation, EmptySubscription)
+        .data(AtomicUsize::new(0))
+        .finish();
+    let query = r#"{
+        counter { value }
+        counter { items }
+        ...QueryFragment
+    }
+
+    fragment QueryFragment on Query {
+        counter { value }
+    }"#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "counter": { "value": 1, "items": [1, 1] }
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute("mutation { increment { value } increment { items } }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "increment": { "value": 2, "items": [2] }
+        })
+    );
+}
@@ -111,3 +111,305 @@ pub async fn test_inputobject_derive_and_item_attributes() {
         MyInputObject { real: 100 }
     );
 }
+
+#[async_std::test]
+pub async fn test_input_object_field_transformer() {
+    use async_graphql::transformers::InputValueTransformer;
+
+    struct Trim {}
+
+    impl InputValueTransformer for Trim {
+        fn transform(&self, value: Value) -> Value {
+            match value {
+                Value::String(s) => Value::String(s.trim().to_string()),
+                value => value,
+            }
+        }
+    }
+
+    #[InputObject]
+    struct MyInput {
+        #[field(transformer(Trim), validator(validators::StringMaxLength(length = 5)))]
+        name: String,
+        #[field(transformer(Trim), default = r#""  tag  ""#)]
+        tag: String,
+    }
+
+    struct Root;
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn name(&self, input: MyInput) -> String {
+            input.name
+        }
+
+        #[field]
+        async fn tag(&self, input: MyInput) -> String {
+            input.tag
+        }
+    }
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ name(input: {name: "  abc  "}) }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "name": "abc" })
+    );
+
+    let resp = QueryBuilder::new(r#"query($input: MyInput!) { name(input: $input) }"#)
+        .variables(
+            Variables::parse_from_json(serde_json::json!({ "input": { "name": "  abc " } }))
+                .unwrap(),
+        )
+        .execute(&schema)
+        .await
+        .unwrap();
+    assert_eq!(resp.data, serde_json::json!({ "name": "abc" }));
+
+    assert_eq!(
+        schema
+            .execute(r#"{ tag(input: {name: "abc"}) }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "tag": "tag" })
+    );
+
+    match schema
+        .execute(r#"{ name(input: {name: "  abcdef  "}) }"#)
+        .await
+    {
+        Err(Error::Query {
+            err: QueryError::InvalidArgumentValue { reason },
+            ..
+        }) => assert_eq!(
+            reason,
+            "\"input.name\", the value length is 6, but the length must be less than or equal to 5"
+        ),
+        _ => panic!("expected an invalid argument error"),
+    }
+}
+
+#[async_std::test]
+pub async fn test_input_object_oneof() {
+    #[InputObject(oneof)]
+    struct UserBy {
+        id: Option<i32>,
+        name: Option<String>,
+    }
+
+    #[InputObject]
+    struct Plain {
+        value: i32,
+    }
+
+    struct Root;
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn user(&self, by: UserBy) -> String {
+            match (by.id, by.name) {
+                (Some(id), _) => format!("id:{}", id),
+                (_, Some(name)) => format!("name:{}", name),
+                _ => unreachable!(),
+            }
+        }
+
+        #[field]
+        async fn plain(&self, input: Plain) -> i32 {
+            input.value
+        }
+    }
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    oneof: __type(name: "UserBy") { isOneOf }
+                    plain: __type(name: "Plain") { isOneOf }
+                    scalar: __type(name: "Int") { isOneOf }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "oneof": { "isOneOf": true },
+            "plain": { "isOneOf": false },
+            "scalar": { "isOneOf": null },
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ user(by: { name: "abc" }) }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "user": "name:abc" })
+    );
+    assert!(schema
+        .execute(r#"{ user(by: { id: 1, name: "abc" }) }"#)
+        .await
+        .is_err());
+    assert!(schema.execute(r#"{ user(by: {}) }"#).await.is_err());
+
+    let variables =
+        Variables::parse_from_json(serde_json::json!({ "by": { "id": 1, "name": "abc" } }))
+            .unwrap();
+    match QueryBuilder::new(r#"query($by: UserBy!) { user(by: $by) }"#)
+        .variables(variables)
+        .execute(&schema)
+        .await
+    {
+        Err(Error::Query {
+            err: QueryError::InvalidArgumentValue { reason },
+            ..
+        }) => assert_eq!(
+            reason,
+            "\"by\", exactly one field of oneOf input object \"UserBy\" must be provided"
+        ),
+        _ => panic!("expected an invalid argument error"),
+    }
+
+    let variables = Variables::parse_from_json(serde_json::json!({ "by": { "id": 1 } })).unwrap();
+    assert_eq!(
+        QueryBuilder::new(r#"query($by: UserBy!) { user(by: $by) }"#)
+            .variables(variables)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "user": "id:1" })
+    );
+}
+
+#[async_std::test]
+pub async fn test_input_object_complex_default() {
+    #[InputObject]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[InputObject]
+    struct Shape {
+        name: String,
+
+        #[field(default = r#"{x: 1, y: 2}"#)]
+        origin: Point,
+
+        #[field(default = r#"[{x: 3, y: 4}, {x: 5, y: 6}]"#)]
+        points: Vec<Point>,
+    }
+
+    struct Root;
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn shape(&self, input: Shape) -> String {
+            format!(
+                "{} ({}, {}) {:?}",
+                input.name,
+                input.origin.x,
+                input.origin.y,
+                input.points.iter().map(|p| (p.x, p.y)).collect::<Vec<_>>()
+            )
+        }
+    }
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ shape(input: { name: "a" }) }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "shape": "a (1, 2) [(3, 4), (5, 6)]" })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ shape(input: { name: "b", origin: { x: 7, y: 8 } }) }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "shape": "b (7, 8) [(3, 4), (5, 6)]" })
+    );
+}
+
+#[async_std::test]
+pub async fn test_input_object_flatten() {
+    #[InputObject]
+    struct Pagination {
+        #[field(default = "0")]
+        offset: i32,
+        limit: i32,
+    }
+
+    #[InputObject]
+    struct Search {
+        keyword: String,
+
+        #[field(flatten)]
+        pagination: Pagination,
+    }
+
+    struct Root;
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn search(&self, input: Search) -> String {
+            format!(
+                "{} {} {}",
+                input.keyword, input.pagination.offset, input.pagination.limit
+            )
+        }
+    }
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ search(input: { keyword: "abc", offset: 10, limit: 20 }) }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "search": "abc 10 20" })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ search(input: { keyword: "abc", limit: 5 }) }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "search": "abc 0 5" })
+    );
+    assert!(schema
+        .execute(r#"{ search(input: { keyword: "abc", pagination: { limit: 5 } }) }"#)
+        .await
+        .is_err());
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Search") { inputFields { name } } }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": {
+                "inputFields": [
+                    { "name": "keyword" },
+                    { "name": "limit" },
+                    { "name": "offset" },
+                ]
+            }
+        })
+    );
+}
@@ -286,3 +286,64 @@ pub async fn test_interface_field_result() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_interface_unregistered_implementer() {
+    use async_graphql::graphql_parser::query::Field;
+    use std::borrow::Cow;
+
+    struct Hidden;
+
+    impl Type for Hidden {
+        fn type_name() -> Cow<'static, str> {
+            Cow::Borrowed("Hidden")
+        }
+
+        fn create_type_info(_registry: &mut registry::Registry) -> String {
+            Self::qualified_type_name()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ObjectType for Hidden {
+        async fn resolve_field(
+            &self,
+            _ctx: &Context<'_>,
+            _field: &Field,
+        ) -> Result<serde_json::Value> {
+            unreachable!()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl OutputValueType for Hidden {
+        async fn resolve(
+            _value: &Self,
+            _ctx: &ContextSelectionSet<'_>,
+            _pos: Pos,
+        ) -> Result<serde_json::Value> {
+            unreachable!()
+        }
+    }
+
+    #[Interface]
+    struct Node(Hidden);
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[field]
+        async fn node(&self) -> Node {
+            Hidden.into()
+        }
+    }
+
+    match Schema::build(Query, EmptyMutation, EmptySubscription).try_finish() {
+        Err(err) => assert_eq!(
+            err.to_string(),
+            "Type \"Hidden\" is a possible type of \"Node\", but it is not registered."
+        ),
+        Ok(_) => panic!("expected an error"),
+    }
+}
@@ -0,0 +1,287 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_export_introspection_json() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let res = schema.export_introspection_json().await.unwrap();
+    assert_eq!(res["__schema"]["queryType"]["name"], "QueryRoot");
+    let types = res["__schema"]["types"].as_array().unwrap();
+    assert!(types.iter().any(|ty| ty["name"] == "QueryRoot"));
+    assert!(types.iter().any(|ty| ty["name"] == "__Schema"));
+}
+
+#[async_std::test]
+pub async fn test_context_registry() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        #[field]
+        async fn a(&self) -> i32 {
+            1
+        }
+
+        #[field]
+        async fn b(&self) -> String {
+            "abc".to_string()
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn obj(&self) -> MyObj {
+            MyObj
+        }
+
+        #[field]
+        async fn field_names(&self, ctx: &Context<'_>, type_name: String) -> Option<Vec<String>> {
+            let mut names = ctx
+                .registry()
+                .types
+                .get(&type_name)?
+                .fields()?
+                .values()
+                .map(|field| format!("{}: {}", field.name, field.ty))
+                .collect::<Vec<_>>();
+            names.sort();
+            Some(names)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{ fieldNames(typeName: "MyObj") unknown: fieldNames(typeName: "Unknown") }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "fieldNames": ["a: Int!", "b: String!"],
+            "unknown": null,
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_introspection_include_deprecated() {
+    #[Enum]
+    enum Color {
+        Red,
+        #[item(deprecation = "Use RED")]
+        Blue,
+        Green,
+    }
+
+    #[InputObject]
+    struct MyInput {
+        b: i32,
+        a: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn color(&self, input: MyInput) -> Color {
+            let _ = input;
+            Color::Red
+        }
+
+        #[field(deprecation = "Use color")]
+        async fn old_color(&self) -> Color {
+            Color::Blue
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    color: __type(name: "Color") {
+                        enumValues { name }
+                        all: enumValues(includeDeprecated: true) { name isDeprecated }
+                    }
+                    query: __type(name: "QueryRoot") {
+                        fields { name }
+                        all: fields(includeDeprecated: true) { name isDeprecated }
+                    }
+                    input: __type(name: "MyInput") {
+                        inputFields { name }
+                    }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "color": {
+                "enumValues": [{ "name": "GREEN" }, { "name": "RED" }],
+                "all": [
+                    { "name": "BLUE", "isDeprecated": true },
+                    { "name": "GREEN", "isDeprecated": false },
+                    { "name": "RED", "isDeprecated": false },
+                ],
+            },
+            "query": {
+                "fields": [{ "name": "color" }],
+                "all": [
+                    { "name": "color", "isDeprecated": false },
+                    { "name": "oldColor", "isDeprecated": true },
+                ],
+            },
+            "input": {
+                "inputFields": [{ "name": "a" }, { "name": "b" }],
+            },
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_introspection_cache() {
+    use async_graphql::extensions::{Extension, ResolveInfo};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct FieldCounter(Arc<AtomicUsize>);
+
+    impl Extension for FieldCounter {
+        fn name(&self) -> &'static str {
+            "fieldCounter"
+        }
+
+        fn resolve_field_start(&self, _info: &ResolveInfo<'_>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn result(&self) -> Option<serde_json::Value> {
+            None
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .extension({
+            let count = count.clone();
+            move || FieldCounter(count.clone())
+        })
+        .finish();
+
+    let first = schema.export_introspection_json().await.unwrap();
+    assert!(count.swap(0, Ordering::SeqCst) > 1);
+
+    let second = schema.export_introspection_json().await.unwrap();
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert_eq!(first, second);
+}
+
+#[async_std::test]
+pub async fn test_disable_introspection() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .disable_introspection()
+        .finish();
+
+    for query in &[
+        "{ __schema { queryType { name } } }",
+        r#"{ __type(name: "QueryRoot") { name } }"#,
+    ] {
+        match schema.execute(query).await {
+            Err(Error::Rule { errors }) => {
+                assert_eq!(errors.len(), 1);
+                assert!(errors[0].message.starts_with("Introspection is disabled"));
+            }
+            _ => panic!("expected a validation error"),
+        }
+    }
+
+    assert_eq!(
+        schema.execute("{ value __typename }").await.unwrap().data,
+        serde_json::json!({ "value": 10, "__typename": "QueryRoot" })
+    );
+}
+
+#[async_std::test]
+pub async fn test_introspection_guard() {
+    struct Developer;
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .introspection_guard(|ctx| match ctx.data_opt::<Developer>() {
+            Some(_) => Ok(()),
+            None => Err("Forbidden".into()),
+        })
+        .finish();
+
+    assert_eq!(
+        schema.execute("{ value }").await.unwrap().data,
+        serde_json::json!({ "value": 10 })
+    );
+
+    for query in &[
+        "{ __schema { queryType { name } } }",
+        r#"{ __type(name: "QueryRoot") { name } }"#,
+    ] {
+        match schema.execute(query).await {
+            Err(Error::Query {
+                err: QueryError::FieldError { err, .. },
+                ..
+            }) => assert_eq!(err, "Forbidden"),
+            _ => panic!("expected the introspection to be denied"),
+        }
+    }
+
+    assert_eq!(
+        QueryBuilder::new("{ __schema { queryType { name } } }")
+            .data(Developer)
+            .execute(&schema)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "__schema": { "queryType": { "name": "QueryRoot" } } })
+    );
+}
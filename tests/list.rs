@@ -71,3 +71,117 @@ pub async fn test_list_type() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_list_cow_and_iter() {
+    use std::borrow::Cow;
+
+    struct Root {
+        value: Vec<i32>,
+    }
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn value_cow(&self) -> Cow<'_, [i32]> {
+            Cow::Borrowed(&self.value)
+        }
+
+        #[field]
+        async fn value_iter(
+            &self,
+        ) -> ListIter<std::iter::Map<std::ops::Range<i32>, fn(i32) -> i32>> {
+            ListIter((1..4).map((|n| n * 10) as fn(i32) -> i32))
+        }
+    }
+
+    let schema = Schema::new(
+        Root {
+            value: vec![1, 2, 3],
+        },
+        EmptyMutation,
+        EmptySubscription,
+    );
+    assert_eq!(
+        schema.execute("{ valueCow valueIter }").await.unwrap().data,
+        serde_json::json!({
+            "valueCow": [1, 2, 3],
+            "valueIter": [10, 20, 30],
+        })
+    );
+
+    assert_eq!(
+        schema
+            .execute(
+                r#"{ __type(name: "Root") { fields { name type { kind ofType { kind ofType { kind ofType { name } } } } } } }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "__type": {
+                "fields": [
+                    {
+                        "name": "valueCow",
+                        "type": { "kind": "NON_NULL", "ofType": { "kind": "LIST", "ofType": { "kind": "NON_NULL", "ofType": { "name": "Int" } } } },
+                    },
+                    {
+                        "name": "valueIter",
+                        "type": { "kind": "NON_NULL", "ofType": { "kind": "LIST", "ofType": { "kind": "NON_NULL", "ofType": { "name": "Int" } } } },
+                    },
+                ]
+            }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_list_null_element_path() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn sum(&self, ids: Vec<i32>) -> i32 {
+            ids.into_iter().sum()
+        }
+
+        #[field]
+        async fn times(&self) -> Vec<UnixTimestamp> {
+            vec![
+                UnixTimestamp(std::time::UNIX_EPOCH),
+                UnixTimestamp(std::time::UNIX_EPOCH - std::time::Duration::from_secs(1)),
+            ]
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    match schema.execute("{ sum(ids: [1, 2, null]) }").await {
+        Err(Error::Rule { errors }) => assert_eq!(
+            errors[0].message,
+            "Invalid value for argument \"ids.2\", expected type \"Int\""
+        ),
+        _ => panic!("expected a rule error"),
+    }
+
+    let variables = Variables::parse_from_json(serde_json::json!({ "ids": [1, 2, null] })).unwrap();
+    match QueryBuilder::new("query($ids: [Int!]!) { sum(ids: $ids) }")
+        .variables(variables)
+        .execute(&schema)
+        .await
+    {
+        Err(Error::Query {
+            err: QueryError::InvalidArgumentValue { reason },
+            ..
+        }) => assert_eq!(reason, "\"ids.2\", expected type \"Int\""),
+        _ => panic!("expected an invalid argument error"),
+    }
+
+    match schema.execute("{ times }").await {
+        Err(Error::Query { path, .. }) => {
+            assert_eq!(path, Some(serde_json::json!(["times", 1])))
+        }
+        _ => panic!("expected a query error"),
+    }
+}
@@ -0,0 +1,63 @@
+use async_graphql::middleware::{Middleware, Next};
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_middleware_fallback() {
+    struct Fallback;
+
+    #[async_graphql::async_trait::async_trait]
+    impl Middleware for Fallback {
+        async fn resolve(&self, ctx: &Context<'_>, next: Next<'_>) -> Result<serde_json::Value> {
+            match next.run(ctx).await {
+                Err(_) if ctx.name == "value" => Ok(0.into()),
+                res => res,
+            }
+        }
+    }
+
+    struct Double;
+
+    #[async_graphql::async_trait::async_trait]
+    impl Middleware for Double {
+        async fn resolve(&self, ctx: &Context<'_>, next: Next<'_>) -> Result<serde_json::Value> {
+            let value = next.run(ctx).await?;
+            Ok(match value.as_i64() {
+                Some(n) => (n * 2).into(),
+                None => value,
+            })
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self, fail: bool) -> FieldResult<i32> {
+            if fail {
+                Err("failed".into())
+            } else {
+                Ok(10)
+            }
+        }
+
+        #[field]
+        async fn other(&self) -> FieldResult<i32> {
+            Err("failed".into())
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .middleware(Fallback)
+        .middleware(Double)
+        .finish();
+    assert_eq!(
+        schema
+            .execute("{ a: value(fail: false) b: value(fail: true) }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "a": 20, "b": 0 })
+    );
+    assert!(schema.execute("{ other }").await.is_err());
+}
@@ -79,3 +79,54 @@ pub async fn test_mutation_fragment() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_mutation_shared_data() {
+    use parking_lot::RwLock;
+
+    #[derive(Default)]
+    struct Store {
+        value: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self, ctx: &Context<'_>) -> i32 {
+            ctx.data_read::<Store>().value
+        }
+    }
+
+    struct MutationRoot;
+
+    #[Object]
+    impl MutationRoot {
+        #[field]
+        async fn add(&self, ctx: &Context<'_>, n: i32) -> i32 {
+            let mut store = ctx.data_write::<Store>();
+            store.value += n;
+            store.value
+        }
+    }
+
+    let store = Arc::new(RwLock::new(Store::default()));
+    let schema = Schema::build(QueryRoot, MutationRoot, EmptySubscription)
+        .data(store.clone())
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("mutation { a: add(n: 10) b: add(n: 5) }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "a": 10, "b": 15 })
+    );
+    assert_eq!(
+        schema.execute("{ value }").await.unwrap().data,
+        serde_json::json!({ "value": 15 })
+    );
+    assert_eq!(store.read().value, 15);
+}
@@ -0,0 +1,139 @@
+use async_graphql::*;
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    #[field]
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+const DOCUMENT: &str = "query A { value } query B { value }";
+
+#[async_std::test]
+pub async fn test_operation_name() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let resp = QueryBuilder::new(DOCUMENT)
+        .operator_name("B")
+        .execute(&schema)
+        .await
+        .unwrap();
+    assert_eq!(resp.data, serde_json::json!({ "value": 10 }));
+
+    // The operation name is ignored for a single anonymous operation.
+    let resp = QueryBuilder::new("{ value }")
+        .operator_name("C")
+        .execute(&schema)
+        .await
+        .unwrap();
+    assert_eq!(resp.data, serde_json::json!({ "value": 10 }));
+}
+
+#[async_std::test]
+pub async fn test_unknown_operation_name() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    match QueryBuilder::new(DOCUMENT)
+        .operator_name("C")
+        .execute(&schema)
+        .await
+    {
+        Err(Error::Query { err, .. }) => {
+            match &err {
+                QueryError::UnknownOperationNamed { name, available } => {
+                    assert_eq!(name, "C");
+                    assert_eq!(available, &["A", "B"]);
+                }
+                _ => panic!("expected UnknownOperationNamed"),
+            }
+            assert_eq!(
+                err.to_string(),
+                r#"Unknown operation named "C", available operations: [A, B]"#
+            );
+        }
+        _ => panic!("expected UnknownOperationNamed"),
+    }
+}
+
+#[async_std::test]
+pub async fn test_ambiguous_operation() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    match schema.execute(DOCUMENT).await {
+        Err(Error::Query { err, .. }) => {
+            match &err {
+                QueryError::AmbiguousOperation { available } => {
+                    assert_eq!(available, &["A", "B"]);
+                }
+                _ => panic!("expected AmbiguousOperation"),
+            }
+            assert_eq!(
+                err.to_string(),
+                "The document contains multiple operations, an operation name must be provided, available operations: [A, B]"
+            );
+        }
+        _ => panic!("expected AmbiguousOperation"),
+    }
+}
+
+#[async_std::test]
+pub async fn test_response_operation() {
+    struct MutationRoot;
+
+    #[Object]
+    impl MutationRoot {
+        #[field]
+        async fn action(&self) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, MutationRoot, EmptySubscription);
+
+    let resp = QueryBuilder::new(DOCUMENT)
+        .operator_name("B")
+        .execute(&schema)
+        .await
+        .unwrap();
+    assert_eq!(resp.operation_name.as_deref(), Some("B"));
+    assert_eq!(resp.operation_type, OperationType::Query);
+
+    let resp = schema.execute("{ value }").await.unwrap();
+    assert_eq!(resp.operation_name, None);
+    assert_eq!(resp.operation_type, OperationType::Query);
+
+    let resp = schema.execute("mutation M { action }").await.unwrap();
+    assert_eq!(resp.operation_name.as_deref(), Some("M"));
+    assert_eq!(resp.operation_type, OperationType::Mutation);
+}
+
+#[async_std::test]
+pub async fn test_context_operation() {
+    struct Root;
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn operation(&self, ctx: &Context<'_>) -> String {
+            format!(
+                "{:?} {}",
+                ctx.operation_type(),
+                ctx.operation_name().unwrap_or("<anonymous>")
+            )
+        }
+    }
+
+    let schema = Schema::new(Root, Root, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("query GetOperation { operation }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "operation": "Query GetOperation" })
+    );
+    assert_eq!(
+        schema.execute("mutation { operation }").await.unwrap().data,
+        serde_json::json!({ "operation": "Mutation <anonymous>" })
+    );
+}
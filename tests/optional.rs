@@ -79,3 +79,61 @@ pub async fn test_optional_type() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_null_field_key_present() {
+    use async_graphql::http::GQLResponse;
+
+    #[SimpleObject]
+    struct MyObj {
+        #[field]
+        value: i32,
+    }
+
+    struct Root;
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn none_value(&self) -> Option<i32> {
+            None
+        }
+
+        #[field]
+        async fn none_obj(&self) -> Option<MyObj> {
+            None
+        }
+
+        #[field]
+        async fn failed(&self) -> Vec<FieldResult<i32>> {
+            vec![Err("failed".into())]
+        }
+    }
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    let resp = schema
+        .execute("{ noneValue alias: noneValue noneObj { value } failed ... on Root { noneObj { value } } }")
+        .await
+        .unwrap();
+    let data = resp.data.as_object().unwrap();
+    for key in &["noneValue", "alias", "noneObj", "failed"] {
+        assert!(data.contains_key(*key), "missing key {}", key);
+    }
+
+    assert_eq!(
+        serde_json::to_value(GQLResponse(Ok(resp))).unwrap(),
+        serde_json::json!({
+            "data": {
+                "noneValue": null,
+                "alias": null,
+                "noneObj": null,
+                "failed": [null],
+            },
+            "errors": [{
+                "message": "failed",
+                "locations": [{"line": 1, "column": 48}],
+                "path": ["failed", 0],
+            }]
+        })
+    );
+}
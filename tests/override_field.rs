@@ -0,0 +1,72 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_override_field() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(100i32)
+        .override_field("QueryRoot", "value", "Int!", |ctx| {
+            Box::pin(async move { Ok((*ctx.data::<i32>()).into()) })
+        })
+        .override_field("QueryRoot", "name", "String!", |_| {
+            Box::pin(async move { Ok("abc".into()) })
+        })
+        .finish();
+    assert_eq!(
+        schema.execute("{ value name }").await.unwrap().data,
+        serde_json::json!({
+            "value": 100,
+            "name": "abc",
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_override_field_invalid() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let res = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .override_field("Unknown", "value", "Int!", |_| {
+            Box::pin(async move { Ok(serde_json::Value::Null) })
+        })
+        .try_finish();
+    match res {
+        Err(SchemaError::UnregisteredObjectType { type_name }) => assert_eq!(type_name, "Unknown"),
+        _ => panic!("expected an unregistered object type error"),
+    }
+
+    let res = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .override_field("QueryRoot", "value", "[Unknown]", |_| {
+            Box::pin(async move { Ok(serde_json::Value::Null) })
+        })
+        .try_finish();
+    match res {
+        Err(SchemaError::InvalidFieldType {
+            type_name,
+            field_name,
+            ty,
+        }) => {
+            assert_eq!(type_name, "QueryRoot");
+            assert_eq!(field_name, "value");
+            assert_eq!(ty, "[Unknown]");
+        }
+        _ => panic!("expected an invalid field type error"),
+    }
+}
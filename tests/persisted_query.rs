@@ -0,0 +1,68 @@
+use async_graphql::persisted_query::LruPersistedQueryCache;
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_persisted_query() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            100
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .persisted_query_cache(LruPersistedQueryCache::default())
+        .finish();
+    let hash = query_hash("{ value }");
+    let extensions = serde_json::json!({
+        "persistedQuery": {
+            "version": 1,
+            "sha256Hash": hash,
+        }
+    });
+
+    // unknown hash
+    match QueryBuilder::new("")
+        .extensions(extensions.clone())
+        .execute(&schema)
+        .await
+    {
+        Err(Error::Query {
+            err: QueryError::PersistedQueryNotFound,
+            ..
+        }) => {}
+        _ => panic!("expected PersistedQueryNotFound"),
+    }
+
+    // hash doesn't match the query
+    match QueryBuilder::new("{ value value2: value }")
+        .extensions(extensions.clone())
+        .execute(&schema)
+        .await
+    {
+        Err(Error::Query {
+            err: QueryError::PersistedQueryHashMismatch,
+            ..
+        }) => {}
+        _ => panic!("expected PersistedQueryHashMismatch"),
+    }
+
+    // register the query
+    let resp = QueryBuilder::new("{ value }")
+        .extensions(extensions.clone())
+        .execute(&schema)
+        .await
+        .unwrap();
+    assert_eq!(resp.data, serde_json::json!({ "value": 100 }));
+
+    // only the hash
+    let resp = QueryBuilder::new("")
+        .extensions(extensions)
+        .execute(&schema)
+        .await
+        .unwrap();
+    assert_eq!(resp.data, serde_json::json!({ "value": 100 }));
+}
@@ -0,0 +1,98 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_plan() {
+    #[SimpleObject]
+    struct Item {
+        #[field]
+        a: i32,
+
+        #[field]
+        b: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            panic!("the plan must not resolve the fields")
+        }
+
+        #[field(complexity = "child_complexity * first as usize")]
+        async fn items(&self, first: i32) -> Vec<Item> {
+            panic!("the plan must not resolve the fields {}", first)
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .introspection_guard(|_| Ok(()))
+        .finish();
+    let plan = QueryBuilder::new(
+        r#"
+        query Plan($first: Int!) {
+            v: value
+            items(first: $first) { ...ItemFields b @skip(if: true) }
+            __type(name: "Item") { name }
+        }
+        fragment ItemFields on Item { a b }
+        "#,
+    )
+    .variables(Variables::parse_from_json(serde_json::json!({ "first": 3 })).unwrap())
+    .plan(&schema)
+    .unwrap();
+
+    let field = |name: &str,
+                 field_name: &str,
+                 parent_type: &str,
+                 ty: &str,
+                 complexity: usize,
+                 guards: Vec<&'static str>,
+                 fields: Vec<PlanField>| PlanField {
+        name: name.to_string(),
+        field_name: field_name.to_string(),
+        parent_type: parent_type.to_string(),
+        ty: ty.to_string(),
+        complexity,
+        guards,
+        fields,
+    };
+    assert_eq!(
+        plan,
+        ExecutionPlan {
+            operation_name: Some("Plan".to_string()),
+            operation_type: OperationType::Query,
+            complexity: 9,
+            fields: vec![
+                field("v", "value", "QueryRoot", "Int!", 1, vec![], vec![]),
+                field(
+                    "items",
+                    "items",
+                    "QueryRoot",
+                    "[Item!]!",
+                    6,
+                    vec![],
+                    vec![
+                        field("a", "a", "Item", "Int!", 1, vec![], vec![]),
+                        field("b", "b", "Item", "Int!", 1, vec![], vec![]),
+                    ]
+                ),
+                field(
+                    "__type",
+                    "__type",
+                    "QueryRoot",
+                    "__Type",
+                    2,
+                    vec!["introspection"],
+                    vec![field("name", "name", "__Type", "String", 1, vec![], vec![])]
+                ),
+            ],
+        }
+    );
+
+    match QueryBuilder::new("{ unknown }").plan(&schema) {
+        Err(Error::Rule { .. }) => {}
+        _ => panic!("expected a validation error"),
+    }
+}
@@ -0,0 +1,64 @@
+use async_graphql::query_cache::{CachedQuery, LruQueryCache, QueryCache};
+use async_graphql::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[async_std::test]
+pub async fn test_query_cache() {
+    #[derive(Default)]
+    struct Counter {
+        hits: AtomicUsize,
+        sets: AtomicUsize,
+    }
+
+    struct CountingCache(LruQueryCache, Arc<Counter>);
+
+    #[async_graphql::async_trait::async_trait]
+    impl QueryCache for CountingCache {
+        async fn get(&self, query_source: &str) -> Option<Arc<CachedQuery>> {
+            let query = self.0.get(query_source).await;
+            if query.is_some() {
+                self.1.hits.fetch_add(1, Ordering::SeqCst);
+            }
+            query
+        }
+
+        async fn set(&self, query_source: &str, query: Arc<CachedQuery>) {
+            self.1.sets.fetch_add(1, Ordering::SeqCst);
+            self.0.set(query_source, query).await;
+        }
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self, n: i32) -> i32 {
+            n
+        }
+    }
+
+    let counter = Arc::new(Counter::default());
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .query_cache(CountingCache(LruQueryCache::default(), counter.clone()))
+        .finish();
+
+    let query = "query($n: Int!) { value(n: $n) }";
+    for n in 0..3 {
+        let resp = QueryBuilder::new(query)
+            .variables(Variables::parse_from_json(serde_json::json!({ "n": n })).unwrap())
+            .execute(&schema)
+            .await
+            .unwrap();
+        assert_eq!(resp.data, serde_json::json!({ "value": n }));
+    }
+    assert_eq!(counter.sets.load(Ordering::SeqCst), 1);
+    assert_eq!(counter.hits.load(Ordering::SeqCst), 2);
+
+    // invalid queries are not cached
+    assert!(schema.execute("{ value }").await.is_err());
+    assert!(schema.execute("{ value }").await.is_err());
+    assert_eq!(counter.sets.load(Ordering::SeqCst), 1);
+    assert_eq!(counter.hits.load(Ordering::SeqCst), 2);
+}
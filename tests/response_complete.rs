@@ -0,0 +1,43 @@
+use async_graphql::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[async_std::test]
+pub async fn test_on_response_complete() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self, ctx: &Context<'_>) -> i32 {
+            let counter = ctx.data::<Arc<AtomicUsize>>().clone();
+            ctx.on_response_complete(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+            10
+        }
+
+        #[field]
+        async fn error(&self, ctx: &Context<'_>) -> FieldResult<i32> {
+            let counter = ctx.data::<Arc<AtomicUsize>>().clone();
+            ctx.on_response_complete(move || {
+                counter.fetch_add(10, Ordering::SeqCst);
+            });
+            Err("error".into())
+        }
+    }
+
+    let counter = Arc::new(AtomicUsize::default());
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(counter.clone())
+        .finish();
+
+    assert_eq!(
+        schema.execute("{ a: value b: value }").await.unwrap().data,
+        serde_json::json!({ "a": 10, "b": 10 })
+    );
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+
+    assert!(schema.execute("{ error }").await.is_err());
+    assert_eq!(counter.load(Ordering::SeqCst), 12);
+}
@@ -0,0 +1,98 @@
+use async_graphql::http::GQLResponse;
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_list_of_results() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn values(&self) -> Vec<FieldResult<i32>> {
+            vec![
+                Ok(1),
+                Err("failed to load 2".into()),
+                Ok(3),
+                Err("failed to load 4".into()),
+            ]
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let resp = schema.execute("{ values }").await.unwrap();
+    assert_eq!(
+        resp.data,
+        serde_json::json!({ "values": [1, null, 3, null] })
+    );
+    assert_eq!(
+        serde_json::to_value(GQLResponse(Ok(resp))).unwrap(),
+        serde_json::json!({
+            "data": { "values": [1, null, 3, null] },
+            "errors": [
+                {
+                    "message": "failed to load 2",
+                    "locations": [{"line": 1, "column": 3}],
+                    "path": ["values", 1],
+                },
+                {
+                    "message": "failed to load 4",
+                    "locations": [{"line": 1, "column": 3}],
+                    "path": ["values", 3],
+                },
+            ]
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_simple_object_result_field() {
+    #[SimpleObject]
+    struct MyObj {
+        #[field]
+        value: FieldResult<i32>,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn ok(&self) -> MyObj {
+            MyObj { value: Ok(10) }
+        }
+
+        #[field]
+        async fn err(&self) -> MyObj {
+            MyObj {
+                value: Err("failed to compute".into()),
+            }
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute("{ ok { value } }").await.unwrap().data,
+        serde_json::json!({ "ok": { "value": 10 } })
+    );
+
+    let resp = schema
+        .execute(r#"{ __type(name: "MyObj") { fields { type { kind } } } }"#)
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.data,
+        serde_json::json!({ "__type": { "fields": [{ "type": { "kind": "NON_NULL" } }] } })
+    );
+
+    match schema.execute("{ err { value } }").await {
+        Err(Error::Query {
+            path: Some(path),
+            err: QueryError::FieldError { err, .. },
+            ..
+        }) => {
+            assert_eq!(err, "failed to compute");
+            assert_eq!(path, serde_json::json!(["err", "value"]));
+        }
+        _ => panic!("expected a field error"),
+    }
+}
@@ -0,0 +1,36 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_safelist() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let allowed = "{ value }";
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .safelist(vec![query_hash(allowed)])
+        .finish();
+
+    assert_eq!(
+        schema.execute(allowed).await.unwrap().data,
+        serde_json::json!({ "value": 10 })
+    );
+
+    match schema.execute("{ value __typename }").await {
+        Err(Error::Query {
+            err: QueryError::QueryNotAllowed,
+            ..
+        }) => {}
+        _ => panic!("expected QueryNotAllowed"),
+    }
+
+    // the introspection query of the schema itself isn't checked
+    let res = schema.export_introspection_json().await.unwrap();
+    assert_eq!(res["__schema"]["queryType"]["name"], "QueryRoot");
+}
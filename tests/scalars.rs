@@ -54,3 +54,368 @@ test_scalars!(test_f64_scalar, f32, 10.5, 10.5);
 
 test_scalars!(test_i64_scalar, i64, 10, "10");
 test_scalars!(test_u64_scalar, u64, 10, "10");
+
+#[async_std::test]
+pub async fn test_newtype_scalar() {
+    #[NewtypeScalar]
+    struct UserId(i32);
+
+    #[NewtypeScalar(transparent)]
+    struct Age(i32);
+
+    struct Root;
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn user_id(&self, id: UserId) -> UserId {
+            UserId(id.0 + 1)
+        }
+
+        #[field]
+        async fn age(&self, age: Age) -> Age {
+            age
+        }
+    }
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ userId(id: 10) age(age: 20) }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "userId": 11, "age": 20 })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "UserId") { kind name } }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "__type": { "kind": "SCALAR", "name": "UserId" } })
+    );
+    assert!(schema.execute(r#"{ userId(id: "abc") }"#).await.is_err());
+}
+
+#[async_std::test]
+pub async fn test_unix_timestamp() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    struct Root;
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn seconds(&self, input: UnixTimestamp) -> UnixTimestamp {
+            input
+        }
+
+        #[field]
+        async fn millis(&self, input: UnixTimestampMillis) -> UnixTimestampMillis {
+            input
+        }
+
+        #[field]
+        async fn after_epoch(&self, input: UnixTimestamp) -> bool {
+            input.0 == UNIX_EPOCH + Duration::from_secs(1_500_000_000)
+        }
+
+        #[field]
+        async fn before_epoch(&self) -> UnixTimestamp {
+            UnixTimestamp(UNIX_EPOCH - Duration::from_secs(1))
+        }
+    }
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                "{ seconds(input: 1500000000) millis(input: 1500000000123) afterEpoch(input: 1500000000) }"
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "seconds": 1500000000,
+            "millis": 1500000000123i64,
+            "afterEpoch": true,
+        })
+    );
+
+    assert!(schema.execute("{ seconds(input: -1) }").await.is_err());
+    assert!(schema.execute(r#"{ seconds(input: "1") }"#).await.is_err());
+    assert!(schema.execute("{ beforeEpoch }").await.is_err());
+}
+
+#[async_std::test]
+pub async fn test_scalar_accepted_forms() {
+    struct Color(u32);
+
+    impl Scalar for Color {
+        fn type_name() -> &'static str {
+            "Color"
+        }
+
+        fn parse(value: &Value) -> Option<Self> {
+            match value {
+                Value::String(s) if s.starts_with('#') && s.len() == 7 => {
+                    u32::from_str_radix(&s[1..], 16).ok().map(Color)
+                }
+                Value::Int(n) => n.as_i64().map(|n| Color(n as u32)),
+                _ => None,
+            }
+        }
+
+        fn accepted_forms() -> &'static [&'static str] {
+            &["\"#rrggbb\" string", "RGB integer"]
+        }
+
+        fn to_json(&self) -> Result<serde_json::Value> {
+            Ok(format!("#{:06x}", self.0).into())
+        }
+    }
+
+    impl_scalar!(Color);
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn color(&self, color: Color) -> Color {
+            color
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r##"{ a: color(color: "#ff8000") b: color(color: 255) }"##)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "a": "#ff8000", "b": "#0000ff" })
+    );
+
+    match schema.execute("{ color(color: true) }").await {
+        Err(Error::Rule { errors }) => assert_eq!(
+            errors[0].message,
+            "Invalid value for argument \"color\", expected type \"Color\", accepted forms: \"#rrggbb\" string, RGB integer"
+        ),
+        _ => panic!("expected a rule error"),
+    }
+}
+
+#[async_std::test]
+pub async fn test_id() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self, id: ID) -> ID {
+            id
+        }
+
+        #[field]
+        async fn next(&self, id: ID) -> FieldResult<i32> {
+            Ok(id.parse::<i32>()? + 1)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ a: value(id: 100) b: value(id: "abc") next(id: 100) }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "a": "100", "b": "abc", "next": 101 })
+    );
+    assert!(schema.execute(r#"{ next(id: "abc") }"#).await.is_err());
+}
+
+#[cfg(feature = "chrono")]
+#[async_std::test]
+pub async fn test_chrono() {
+    use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn next_day(&self, date: NaiveDate) -> NaiveDate {
+            date.succ()
+        }
+
+        #[field]
+        async fn timestamp(&self, datetime: DateTime<Utc>) -> i32 {
+            datetime.timestamp() as i32
+        }
+
+        #[field]
+        async fn epoch(&self) -> DateTime<Utc> {
+            Utc.timestamp(0, 0)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    nextDay(date: "2020-02-28")
+                    timestamp(datetime: "1970-01-01T01:00:00+01:00")
+                    epoch
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "nextDay": "2020-02-29",
+            "timestamp": 0,
+            "epoch": "1970-01-01T00:00:00+00:00",
+        })
+    );
+
+    for query in &[
+        r#"{ nextDay(date: "2020-02-30") }"#,
+        r#"{ nextDay(date: "2020/02/28") }"#,
+        r#"{ timestamp(datetime: "1970-01-01 00:00:00") }"#,
+        r#"{ timestamp(datetime: 0) }"#,
+    ] {
+        match schema.execute(query).await {
+            Err(Error::Rule { errors }) => assert_eq!(errors.len(), 1),
+            _ => panic!("expected a validation error"),
+        }
+    }
+
+    let resp = schema
+        .execute(r#"{ __type(name: "NaiveDate") { name kind } }"#)
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.data,
+        serde_json::json!({ "__type": { "name": "NaiveDate", "kind": "SCALAR" } })
+    );
+}
+
+#[cfg(feature = "uuid")]
+#[async_std::test]
+pub async fn test_uuid() {
+    use uuid::Uuid;
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn id(&self, id: Uuid) -> Uuid {
+            id
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ id(id: "936DA01F-9ABD-4D9D-80C7-02AF85C822A8") }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "id": "936da01f-9abd-4d9d-80c7-02af85c822a8" })
+    );
+
+    for query in &[r#"{ id(id: "not-a-uuid") }"#, r#"{ id(id: 10) }"#] {
+        match schema.execute(query).await {
+            Err(Error::Rule { errors }) => assert_eq!(errors.len(), 1),
+            _ => panic!("expected a validation error"),
+        }
+    }
+
+    let resp = schema
+        .execute(r#"{ __type(name: "UUID") { name kind } }"#)
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.data,
+        serde_json::json!({ "__type": { "name": "UUID", "kind": "SCALAR" } })
+    );
+}
+
+#[async_std::test]
+pub async fn test_base64() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn reverse(&self, data: Base64) -> Base64 {
+            data.iter().rev().cloned().collect::<Vec<u8>>().into()
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ reverse(data: "AQID") }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "reverse": "AwIB" })
+    );
+
+    for query in &[r#"{ reverse(data: "AQI*") }"#, r#"{ reverse(data: 10) }"#] {
+        match schema.execute(query).await {
+            Err(Error::Rule { errors }) => assert_eq!(errors.len(), 1),
+            _ => panic!("expected a validation error"),
+        }
+    }
+}
+
+#[cfg(feature = "coordinates")]
+#[async_std::test]
+pub async fn test_coordinates() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn echo(&self, position: Coordinates) -> Coordinates {
+            position
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    for query in &[
+        r#"{ echo(position: { lat: 48.5, lng: -2.25 }) }"#,
+        r#"{ echo(position: { type: "Point", coordinates: [-2.25, 48.5] }) }"#,
+    ] {
+        assert_eq!(
+            schema.execute(query).await.unwrap().data,
+            serde_json::json!({ "echo": { "lat": 48.5, "lng": -2.25 } })
+        );
+    }
+    assert_eq!(
+        schema
+            .execute("{ echo(position: { lat: -90, lng: 180 }) }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "echo": { "lat": -90.0, "lng": 180.0 } })
+    );
+
+    for query in &[
+        r#"{ echo(position: { lat: 90.5, lng: 0 }) }"#,
+        r#"{ echo(position: { lat: 0, lng: -180.5 }) }"#,
+        r#"{ echo(position: { type: "Point", coordinates: [0, 91] }) }"#,
+        r#"{ echo(position: { lat: 0 }) }"#,
+    ] {
+        match schema.execute(query).await {
+            Err(Error::Rule { errors }) => assert_eq!(errors.len(), 1),
+            _ => panic!("expected a validation error"),
+        }
+    }
+}
@@ -0,0 +1,509 @@
+use async_graphql::*;
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    #[field]
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+#[async_std::test]
+pub async fn test_schema_limits() {
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(schema.complexity_limit(), None);
+    assert_eq!(schema.depth_limit(), None);
+    assert_eq!(schema.validation_mode(), ValidationMode::Strict);
+    assert!(!schema.introspection_disabled());
+    assert!(!schema.has_safelist());
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_complexity(100)
+        .limit_depth(5)
+        .validation_mode(ValidationMode::Fast)
+        .disable_introspection()
+        .safelist(vec![query_hash("{ value }")])
+        .finish();
+    assert_eq!(schema.complexity_limit(), Some(100));
+    assert_eq!(schema.depth_limit(), Some(5));
+    assert_eq!(schema.validation_mode(), ValidationMode::Fast);
+    assert!(schema.introspection_disabled());
+    assert!(schema.has_safelist());
+}
+
+#[async_std::test]
+pub async fn test_auto_typename() {
+    #[SimpleObject]
+    struct Inner {
+        #[field]
+        value: i32,
+    }
+
+    struct Root;
+
+    #[Object]
+    impl Root {
+        #[field]
+        async fn inner(&self) -> Inner {
+            Inner { value: 10 }
+        }
+
+        #[field]
+        async fn list(&self) -> Vec<Inner> {
+            vec![Inner { value: 1 }]
+        }
+    }
+
+    let query = "{ inner { value } list { value } }";
+
+    let schema = Schema::new(Root, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "inner": { "value": 10 },
+            "list": [{ "value": 1 }],
+        })
+    );
+
+    let schema = Schema::build(Root, EmptyMutation, EmptySubscription)
+        .auto_typename(true)
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "inner": { "__typename": "Inner", "value": 10 },
+            "list": [{ "__typename": "Inner", "value": 1 }],
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_duplicate_field_name() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            1
+        }
+
+        #[field(name = "value")]
+        async fn value2(&self) -> i32 {
+            2
+        }
+    }
+
+    let err = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .try_finish()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Field \"value\" of type \"QueryRoot\" is defined more than once."
+    );
+}
+
+#[async_std::test]
+pub async fn test_simple_object_flatten() {
+    #[SimpleObject]
+    struct Timestamps {
+        #[field]
+        created_at: i32,
+        #[field]
+        updated_at: i32,
+    }
+
+    #[SimpleObject]
+    struct Post {
+        #[field]
+        title: String,
+        #[field(flatten)]
+        timestamps: Timestamps,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn post(&self) -> Post {
+            Post {
+                title: "abc".to_string(),
+                timestamps: Timestamps {
+                    created_at: 1,
+                    updated_at: 2,
+                },
+            }
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ post { title createdAt updatedAt } }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "post": { "title": "abc", "createdAt": 1, "updatedAt": 2 },
+        })
+    );
+    assert!(schema
+        .execute("{ post { timestamps { createdAt } } }")
+        .await
+        .is_err());
+
+    #[SimpleObject]
+    struct Conflict {
+        #[field(name = "createdAt")]
+        created: i32,
+        #[field(flatten)]
+        timestamps: Timestamps,
+    }
+
+    struct ConflictRoot;
+
+    #[Object]
+    impl ConflictRoot {
+        #[field]
+        async fn conflict(&self) -> Conflict {
+            Conflict {
+                created: 0,
+                timestamps: Timestamps {
+                    created_at: 1,
+                    updated_at: 2,
+                },
+            }
+        }
+    }
+
+    let err = Schema::build(ConflictRoot, EmptyMutation, EmptySubscription)
+        .try_finish()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Field \"createdAt\" of type \"Conflict\" is defined more than once."
+    );
+}
+
+#[async_std::test]
+pub async fn test_canonical_query() {
+    #[SimpleObject]
+    struct Inner {
+        #[field]
+        a: i32,
+        #[field]
+        b: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self, x: i32, y: i32) -> i32 {
+            x + y
+        }
+
+        #[field]
+        async fn inner(&self) -> Inner {
+            Inner { a: 1, b: 2 }
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let canonical_query = |query: &'static str| {
+        let schema = &schema;
+        async move {
+            QueryBuilder::new(query)
+                .canonical_query(true)
+                .execute(schema)
+                .await
+                .unwrap()
+                .canonical_query
+                .unwrap()
+        }
+    };
+
+    let query1 =
+        canonical_query("{ value(x: 1, y: 2) inner { a b ...F } } fragment F on Inner { a }").await;
+    let query2 = canonical_query(
+        r#"
+        fragment F on Inner {
+            a
+        }
+
+        {
+            inner { ...F b   a }
+            value(y: 2,
+                  x: 1)
+        }
+    "#,
+    )
+    .await;
+    assert_eq!(query1, query2);
+    assert_ne!(
+        query1,
+        canonical_query("{ value(x: 2, y: 1) inner { a b ...F } } fragment F on Inner { a }").await
+    );
+
+    assert!(schema
+        .execute("{ value(x: 1, y: 2) }")
+        .await
+        .unwrap()
+        .canonical_query
+        .is_none());
+}
+
+#[async_std::test]
+pub async fn test_rename_all() {
+    #[InputObject(rename_all = "snake_case")]
+    struct MyInput {
+        first_value: i32,
+        #[field(name = "secondValue")]
+        second_value: i32,
+    }
+
+    #[SimpleObject(rename_all = "SCREAMING_SNAKE_CASE")]
+    struct MyObj {
+        #[field]
+        first_value: i32,
+        #[field(name = "second")]
+        second_value: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object(rename_all = "PascalCase")]
+    impl QueryRoot {
+        #[field]
+        async fn sum_input(&self, my_input: MyInput) -> i32 {
+            my_input.first_value + my_input.second_value
+        }
+
+        #[field(name = "obj")]
+        async fn my_obj(&self, #[arg(name = "value")] first_value: i32) -> MyObj {
+            MyObj {
+                first_value,
+                second_value: 2,
+            }
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    SumInput(MyInput: { first_value: 1, secondValue: 2 })
+                    obj(value: 1) { FIRST_VALUE second }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "SumInput": 3,
+            "obj": { "FIRST_VALUE": 1, "second": 2 },
+        })
+    );
+    assert!(schema
+        .execute("{ sumInput(myInput: { firstValue: 1, secondValue: 2 }) }")
+        .await
+        .is_err());
+}
+
+#[async_std::test]
+pub async fn test_sdl() {
+    #[Enum(desc = "A color")]
+    enum Color {
+        Red,
+        #[item(deprecation = "Use RED")]
+        Crimson,
+    }
+
+    #[InputObject]
+    struct Filter {
+        #[field(default = "10")]
+        limit: i32,
+        color: Option<Color>,
+    }
+
+    #[SimpleObject(desc = "An item")]
+    struct Item {
+        #[field(desc = "The name")]
+        name: String,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field(desc = "Search the items")]
+        async fn items(&self, filter: Filter, #[arg(default = "0")] offset: i32) -> Vec<Item> {
+            let _ = (filter, offset);
+            Vec::new()
+        }
+
+        #[field(deprecation = "Use `items`")]
+        async fn item(&self) -> Option<Item> {
+            None
+        }
+    }
+
+    struct MutationRoot;
+
+    #[Object]
+    impl MutationRoot {
+        #[field]
+        async fn clear(&self) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, MutationRoot, EmptySubscription);
+    assert_eq!(
+        schema.sdl(),
+        "schema {
+\tquery: QueryRoot
+\tmutation: MutationRoot
+}
+
+\"\"\"A color\"\"\"
+enum Color {
+\tCRIMSON @deprecated(reason: \"Use RED\")
+\tRED
+}
+
+input Filter {
+\tcolor: Color
+\tlimit: Int! = 10
+}
+
+\"\"\"An item\"\"\"
+type Item {
+\t\"\"\"The name\"\"\"
+\tname: String!
+}
+
+type MutationRoot {
+\tclear: Boolean!
+}
+
+type QueryRoot {
+\titem: Item @deprecated(reason: \"Use `items`\")
+\t\"\"\"Search the items\"\"\"
+\titems(filter: Filter!, offset: Int! = 0): [Item!]!
+}
+"
+    );
+}
+
+#[async_std::test]
+pub async fn test_reserved_field_name() {
+    #[SimpleObject]
+    struct MyObj {
+        #[field(name = "__value")]
+        value: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn obj(&self) -> MyObj {
+            MyObj { value: 1 }
+        }
+    }
+
+    let err = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .try_finish()
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Field \"__value\" of type \"MyObj\" can't start with \"__\", it is reserved for introspection."
+    );
+}
+
+#[async_std::test]
+pub async fn test_sort_response_keys() {
+    #[SimpleObject]
+    struct MyObj {
+        #[field]
+        b: i32,
+
+        #[field]
+        a: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            1
+        }
+
+        #[field]
+        async fn objs(&self) -> Vec<MyObj> {
+            vec![MyObj { b: 2, a: 1 }]
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .sort_response_keys(true)
+        .finish();
+    let res = schema
+        .execute("{ value objs { b a } alias: value }")
+        .await
+        .unwrap();
+    assert_eq!(
+        serde_json::to_string(&res.data).unwrap(),
+        r#"{"alias":1,"objs":[{"a":1,"b":2}],"value":1}"#
+    );
+}
+
+#[async_std::test]
+pub async fn test_wide_selection() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self, n: i32) -> i32 {
+            n
+        }
+
+        #[field]
+        async fn objs(&self) -> Vec<QueryRoot> {
+            vec![QueryRoot, QueryRoot]
+        }
+    }
+
+    let fields = (0..500)
+        .map(|n| format!("f{}: value(n: {})", n, n))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let query = format!("{{ {} objs {{ {} }} }}", fields, fields);
+    let mut obj = serde_json::Map::new();
+    for n in 0..500 {
+        obj.insert(format!("f{}", n), n.into());
+    }
+    obj.insert("__typename".to_string(), "QueryRoot".into());
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .auto_typename(true)
+        .finish();
+    let data = schema.execute(&query).await.unwrap().data;
+    let mut root = obj.clone();
+    root.remove("__typename");
+    root.insert("objs".to_string(), vec![obj.clone(), obj].into());
+    assert_eq!(data, serde_json::Value::Object(root));
+}
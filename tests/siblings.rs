@@ -0,0 +1,94 @@
+use async_graphql::*;
+use futures_timer::Delay;
+use std::time::Duration;
+
+struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    #[field]
+    async fn price(&self) -> i32 {
+        Delay::new(Duration::from_millis(50)).await;
+        100
+    }
+
+    #[field]
+    async fn price_with_tax(&self, ctx: &Context<'_>) -> FieldResult<Option<i32>> {
+        Ok(ctx
+            .sibling_value("price")
+            .await?
+            .and_then(|price| price.as_i64())
+            .map(|price| price as i32 * 2))
+    }
+
+    #[field]
+    async fn a(&self, ctx: &Context<'_>) -> FieldResult<Option<i32>> {
+        Delay::new(Duration::from_millis(10)).await;
+        Ok(ctx
+            .sibling_value("b")
+            .await?
+            .and_then(|b| b.as_i64())
+            .map(|b| b as i32))
+    }
+
+    #[field]
+    async fn b(&self, ctx: &Context<'_>) -> FieldResult<Option<i32>> {
+        Ok(ctx
+            .sibling_value("a")
+            .await?
+            .and_then(|a| a.as_i64())
+            .map(|a| a as i32))
+    }
+}
+
+#[async_std::test]
+pub async fn test_sibling_value() {
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .sibling_values(true)
+        .finish();
+
+    assert_eq!(
+        schema.execute("{ priceWithTax price }").await.unwrap().data,
+        serde_json::json!({ "priceWithTax": 200, "price": 100 })
+    );
+
+    // the sibling isn't selected
+    assert_eq!(
+        schema.execute("{ priceWithTax }").await.unwrap().data,
+        serde_json::json!({ "priceWithTax": null })
+    );
+
+    // the siblings are found by response key
+    assert_eq!(
+        schema
+            .execute("{ tax: priceWithTax price }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "tax": 200, "price": 100 })
+    );
+    assert_eq!(
+        schema
+            .execute("{ priceWithTax p: price }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "priceWithTax": null, "p": 100 })
+    );
+
+    // the fields are waiting for each other
+    match schema.execute("{ a b }").await {
+        Err(Error::Query {
+            err: QueryError::FieldError { err, .. },
+            ..
+        }) => assert_eq!(
+            err,
+            "The field \"a\" can't wait for \"b\", it is waiting for \"a\""
+        ),
+        _ => panic!("expected an error"),
+    }
+
+    // sibling values are disabled
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert!(schema.execute("{ priceWithTax price }").await.is_err());
+}
@@ -420,3 +420,531 @@ pub async fn test_subscription_ws_transport_with_token() {
         );
     }
 }
+
+#[async_std::test]
+pub async fn test_subscription_filter_with_data() {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Role {
+        Admin,
+        Guest,
+    }
+
+    struct MaxValue(i32);
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    #[SimpleObject]
+    struct Event {
+        #[field]
+        value: i32,
+
+        #[field]
+        admin_only: bool,
+    }
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        #[field]
+        async fn events(&self, ctx: &Context<'_>) -> impl Stream<Item = Event> {
+            let role = *ctx.data::<Role>();
+            let max_value = ctx.data::<MaxValue>().0;
+            futures::stream::iter((0..6).map(|value| Event {
+                value,
+                admin_only: value % 2 == 1,
+            }))
+            .filter(move |event| {
+                let allowed = event.value < max_value && (!event.admin_only || role == Role::Admin);
+                async move { allowed }
+            })
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+        .data(MaxValue(5))
+        .finish();
+
+    for (role, expected) in vec![("admin", vec![0, 1, 2, 3, 4]), ("guest", vec![0, 2, 4])] {
+        let (mut sink, mut stream) =
+            schema.subscription_connection(WebSocketTransport::new(|value| {
+                #[derive(serde_derive::Deserialize)]
+                struct Payload {
+                    role: String,
+                }
+
+                let payload: Payload = serde_json::from_value(value).unwrap();
+                let mut data = Data::default();
+                data.insert(if payload.role == "admin" {
+                    Role::Admin
+                } else {
+                    Role::Guest
+                });
+                Ok(data)
+            }));
+
+        sink.send(
+            serde_json::to_vec(&serde_json::json!({
+                "type": "connection_init",
+                "payload": { "role": role }
+            }))
+            .unwrap()
+            .into(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Some(serde_json::json!({
+            "type": "connection_ack",
+            })),
+            serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+        );
+
+        sink.send(
+            serde_json::to_vec(&serde_json::json!({
+                "type": "start",
+                "id": "1",
+                "payload": {
+                    "query": "subscription { events { value } }"
+                },
+            }))
+            .unwrap()
+            .into(),
+        )
+        .await
+        .unwrap();
+
+        for value in expected {
+            assert_eq!(
+                Some(serde_json::json!({
+                "type": "data",
+                "id": "1",
+                "payload": { "data": { "events": { "value": value } } },
+                })),
+                serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+            );
+        }
+    }
+}
+
+#[async_std::test]
+pub async fn test_subscription_over_http() {
+    use async_graphql::http::GQLRequest;
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        #[field]
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..10)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let request: GQLRequest = serde_json::from_value(serde_json::json!({
+        "query": "subscription { values }",
+    }))
+    .unwrap();
+    let builder = request.into_query_builder().await.unwrap();
+    match builder.execute(&schema).await {
+        Err(Error::Query {
+            err: QueryError::SubscriptionNotSupportedOverHttp,
+            ..
+        }) => {}
+        _ => panic!("expected SubscriptionNotSupportedOverHttp"),
+    }
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_terminate() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Guard(Arc<AtomicUsize>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[derive(Clone)]
+    struct TerminateEvent(i32);
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        #[field]
+        async fn values(&self, ctx: &Context<'_>) -> impl Stream<Item = i32> {
+            let guard = Guard(ctx.data::<Arc<AtomicUsize>>().clone());
+            SimpleBroker::<TerminateEvent>::subscribe().map(move |event| {
+                let _ = &guard;
+                event.0
+            })
+        }
+    }
+
+    let released = Arc::new(AtomicUsize::default());
+    let schema = Schema::build(QueryRoot, EmptyMutation, SubscriptionRoot)
+        .data(released.clone())
+        .finish();
+    let (mut sink, mut stream) = schema.subscription_connection(WebSocketTransport::default());
+
+    for msg in vec![
+        serde_json::json!({ "type": "connection_init" }),
+        serde_json::json!({
+            "type": "start",
+            "id": "1",
+            "payload": { "query": "subscription { values }" },
+        }),
+        serde_json::json!({
+            "type": "start",
+            "id": "2",
+            "payload": { "query": "subscription { values }" },
+        }),
+        serde_json::json!({ "type": "connection_terminate" }),
+    ] {
+        sink.send(serde_json::to_vec(&msg).unwrap().into())
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_ack",
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+    assert!(stream.next().await.is_none());
+    assert_eq!(released.load(Ordering::SeqCst), 2);
+}
+
+#[async_std::test]
+pub async fn test_subscription_with_upload() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        #[field]
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..10)
+        }
+
+        #[field]
+        async fn file_size(&self, file: Upload) -> impl Stream<Item = i32> {
+            futures::stream::once(async move { file.filename.len() as i32 })
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+
+    match schema
+        .create_subscription_stream(
+            r#"subscription { values fileSize(file: "file:a.txt|/tmp/a.txt") }"#,
+            None,
+            Default::default(),
+            None,
+        )
+        .await
+    {
+        Err(Error::Query {
+            pos,
+            err:
+                QueryError::UploadNotSupportedOverSubscription {
+                    field_name,
+                    arg_name,
+                },
+            ..
+        }) => {
+            assert_eq!(
+                pos,
+                Pos {
+                    line: 1,
+                    column: 23
+                }
+            );
+            assert_eq!(field_name, "fileSize");
+            assert_eq!(arg_name, "file");
+        }
+        _ => panic!("expected UploadNotSupportedOverSubscription"),
+    }
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_keep_alive() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        #[field]
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..10)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+
+    {
+        let (mut sink, mut stream) = schema.subscription_connection(
+            WebSocketTransport::default()
+                .keep_alive_interval(Some(std::time::Duration::from_millis(50))),
+        );
+
+        sink.send(
+            serde_json::to_vec(&serde_json::json!({
+                "type": "connection_init",
+            }))
+            .unwrap()
+            .into(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Some(serde_json::json!({
+            "type": "connection_ack",
+            })),
+            serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+        );
+
+        for _ in 0..2 {
+            assert_eq!(
+                Some(serde_json::json!({
+                "type": "ka",
+                })),
+                serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+            );
+        }
+    }
+
+    {
+        let (mut sink, mut stream) =
+            schema.subscription_connection(WebSocketTransport::default().keep_alive_interval(None));
+
+        sink.send(
+            serde_json::to_vec(&serde_json::json!({
+                "type": "connection_init",
+            }))
+            .unwrap()
+            .into(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Some(serde_json::json!({
+            "type": "connection_ack",
+            })),
+            serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+        );
+
+        assert!(
+            async_std::future::timeout(std::time::Duration::from_millis(200), stream.next())
+                .await
+                .is_err()
+        );
+    }
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_init_error() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        #[field]
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..10)
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let (mut sink, mut stream) =
+        schema.subscription_connection(WebSocketTransport::new(|_| Err("Invalid token".into())));
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "connection_init",
+            "payload": { "token": "123456" }
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_error",
+        "payload": { "message": "Invalid token" },
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+    assert!(stream.next().await.is_none());
+}
+
+#[async_std::test]
+pub async fn test_subscription_ws_transport_stop() {
+    #[SimpleObject]
+    #[derive(Clone)]
+    struct Event {
+        #[field]
+        value: i32,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {}
+
+    struct SubscriptionRoot;
+
+    #[Subscription]
+    impl SubscriptionRoot {
+        #[field]
+        async fn values(&self) -> impl Stream<Item = i32> {
+            futures::stream::iter(0..1)
+        }
+
+        #[field]
+        async fn events(&self) -> impl Stream<Item = Event> {
+            SimpleBroker::<Event>::subscribe()
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, SubscriptionRoot);
+    let (mut sink, mut stream) = schema.subscription_connection(WebSocketTransport::default());
+
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({ "type": "connection_init" }))
+            .unwrap()
+            .into(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "connection_ack",
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+
+    // Stopping a subscription that has already ended does nothing.
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({
+            "type": "start",
+            "id": "0",
+            "payload": { "query": "subscription { values }" },
+        }))
+        .unwrap()
+        .into(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        Some(serde_json::json!({
+        "type": "data",
+        "id": "0",
+        "payload": { "data": { "values": 0 } },
+        })),
+        serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+    );
+
+    for id in &["1", "2"] {
+        sink.send(
+            serde_json::to_vec(&serde_json::json!({
+                "type": "start",
+                "id": id,
+                "payload": { "query": "subscription { events { value } }" },
+            }))
+            .unwrap()
+            .into(),
+        )
+        .await
+        .unwrap();
+    }
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({ "type": "stop", "id": "0" }))
+            .unwrap()
+            .into(),
+    )
+    .await
+    .unwrap();
+    sink.send(
+        serde_json::to_vec(&serde_json::json!({ "type": "stop", "id": "1" }))
+            .unwrap()
+            .into(),
+    )
+    .await
+    .unwrap();
+    assert!(futures::poll!(stream.next()).is_pending());
+
+    for value in 0..2 {
+        SimpleBroker::publish(Event { value });
+        assert_eq!(
+            Some(serde_json::json!({
+            "type": "data",
+            "id": "2",
+            "payload": { "data": { "events": { "value": value } } },
+            })),
+            serde_json::from_slice(&stream.next().await.unwrap()).unwrap()
+        );
+        assert!(futures::poll!(stream.next()).is_pending());
+    }
+}
+
+#[async_std::test]
+pub async fn test_simple_broker_topics() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Message(i32);
+
+    let mut room1 = SimpleBroker::<Message>::subscribe_to("room1");
+    let mut room2 = SimpleBroker::<Message>::subscribe_to("room2");
+    let mut all = SimpleBroker::<Message>::subscribe();
+
+    SimpleBroker::publish_to("room1", Message(1));
+    SimpleBroker::publish_to("room2", Message(2));
+    SimpleBroker::publish_to("room3", Message(3));
+    SimpleBroker::publish(Message(4));
+    SimpleBroker::publish_to("room1", Message(5));
+
+    assert_eq!(room1.next().await, Some(Message(1)));
+    assert_eq!(room1.next().await, Some(Message(5)));
+    assert!(futures::poll!(room1.next()).is_pending());
+
+    assert_eq!(room2.next().await, Some(Message(2)));
+    assert!(futures::poll!(room2.next()).is_pending());
+
+    assert_eq!(all.next().await, Some(Message(4)));
+    assert!(futures::poll!(all.next()).is_pending());
+}
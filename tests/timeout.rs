@@ -0,0 +1,42 @@
+use async_graphql::*;
+use std::time::Duration;
+
+#[async_std::test]
+pub async fn test_query_timeout() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn fast(&self) -> i32 {
+            1
+        }
+
+        #[field]
+        async fn slow(&self) -> i32 {
+            async_std::task::sleep(Duration::from_secs(10)).await;
+            2
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+
+    let res = QueryBuilder::new("{ fast }")
+        .timeout(Duration::from_secs(10))
+        .execute(&schema)
+        .await
+        .unwrap();
+    assert_eq!(res.data, serde_json::json!({ "fast": 1 }));
+
+    match QueryBuilder::new("{ fast slow }")
+        .timeout(Duration::from_millis(50))
+        .execute(&schema)
+        .await
+    {
+        Err(Error::Query {
+            err: QueryError::Timeout,
+            ..
+        }) => {}
+        _ => panic!("expected a timeout error"),
+    }
+}
@@ -0,0 +1,138 @@
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_union_look_ahead() {
+    #[SimpleObject]
+    struct Cat {
+        #[field]
+        meow: String,
+    }
+
+    #[SimpleObject]
+    struct Dog {
+        #[field]
+        bark: String,
+    }
+
+    #[Union]
+    struct Pet(Cat, Dog);
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn pet(&self, ctx: &Context<'_>) -> Option<Pet> {
+            let types = ctx.look_ahead_types();
+            if types.contains("Dog") {
+                Some(
+                    Dog {
+                        bark: "woof".to_string(),
+                    }
+                    .into(),
+                )
+            } else if types.contains("Cat") {
+                Some(
+                    Cat {
+                        meow: "meow".to_string(),
+                    }
+                    .into(),
+                )
+            } else {
+                None
+            }
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ pet { ... on Dog { bark } } }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "pet": { "bark": "woof" }
+        })
+    );
+    assert_eq!(
+        schema
+            .execute("{ pet { ...CatFields } } fragment CatFields on Cat { meow }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "pet": { "meow": "meow" }
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_union_typename() {
+    #[SimpleObject]
+    struct Cat {
+        #[field]
+        meow: String,
+    }
+
+    #[SimpleObject]
+    struct Dog {
+        #[field]
+        bark: String,
+    }
+
+    #[Union]
+    struct Pet(Cat, Dog);
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn pets(&self) -> Vec<Pet> {
+            vec![
+                Cat {
+                    meow: "meow".to_string(),
+                }
+                .into(),
+                Dog {
+                    bark: "woof".to_string(),
+                }
+                .into(),
+            ]
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ pets { __typename } }")
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "pets": [{ "__typename": "Cat" }, { "__typename": "Dog" }]
+        })
+    );
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    pets {
+                        ... on Pet { kind: __typename }
+                        ... on Cat { __typename meow }
+                        ... on Dog { __typename bark }
+                    }
+                }"#
+            )
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({
+            "pets": [
+                { "kind": "Cat", "__typename": "Cat", "meow": "meow" },
+                { "kind": "Dog", "__typename": "Dog", "bark": "woof" },
+            ]
+        })
+    );
+}
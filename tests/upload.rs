@@ -0,0 +1,25 @@
+#![cfg(feature = "async-std")]
+
+use async_graphql::*;
+
+#[async_std::test]
+pub async fn test_upload_save_to() {
+    let dir = tempdir::TempDir::new("async-graphql").unwrap();
+    let src = dir.path().join("src.txt");
+    let dst = dir.path().join("dst.txt");
+    std::fs::write(&src, b"hello upload").unwrap();
+
+    let upload = Upload {
+        filename: "hello.txt".to_string(),
+        content_type: Some("text/plain".to_string()),
+        path: src,
+    };
+    assert_eq!(upload.save_to(&dst).await.unwrap(), 12);
+    assert_eq!(std::fs::read(&dst).unwrap(), b"hello upload");
+
+    let err = upload
+        .save_to(dir.path().join("missing").join("dst.txt"))
+        .await
+        .unwrap_err();
+    assert!(err.0.starts_with("Failed to save file \"hello.txt\""));
+}
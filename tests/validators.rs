@@ -0,0 +1,218 @@
+use async_graphql::validators::{
+    Chars, Email, IDPattern, InputValueValidatorExt, StringMaxLength, StringMinLength,
+};
+use async_graphql::*;
+use std::sync::Arc;
+
+#[async_std::test]
+pub async fn test_chars_validator() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn slug(
+            &self,
+            #[arg(validator(and(Chars(allowed = "a-z0-9-"), StringMaxLength(length = 10))))]
+            value: String,
+        ) -> String {
+            value
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ slug(value: "hello-123") }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "slug": "hello-123" })
+    );
+
+    match schema.execute(r#"{ slug(value: "Hello") }"#).await {
+        Err(Error::Rule { errors }) => {
+            assert_eq!(errors.len(), 1);
+            assert!(errors[0]
+                .message
+                .ends_with("contains disallowed character 'H' at position 0"));
+        }
+        _ => panic!("expected a validation error"),
+    }
+
+    assert!(schema
+        .execute(r#"{ slug(value: "hello-world-123") }"#)
+        .await
+        .is_err());
+}
+
+#[async_std::test]
+pub async fn test_list_validator() {
+    #[InputObject]
+    struct MyInput {
+        #[field(validator(list(StringMinLength(length = 3))))]
+        tags: Vec<String>,
+    }
+
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn count(&self, input: MyInput) -> i32 {
+            input.tags.len() as i32
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ count(input: { tags: ["abc", "defg"] }) }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "count": 2 })
+    );
+
+    match schema
+        .execute(r#"{ count(input: { tags: ["abc", "de", "fgh"] }) }"#)
+        .await
+    {
+        Err(Error::Rule { errors }) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(
+                errors[0].message,
+                "Invalid value for argument \"input.tags.1\", the value length is 2, but the length must be greater than or equal to 3"
+            );
+        }
+        _ => panic!("expected a validation error"),
+    }
+}
+
+#[async_std::test]
+pub async fn test_id_pattern_validator() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn user(&self, #[arg(validator(IDPattern(pattern = "user_[0-9]+")))] id: ID) -> ID {
+            id
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ user(id: "user_123") }"#)
+            .await
+            .unwrap()
+            .data,
+        serde_json::json!({ "user": "user_123" })
+    );
+
+    match schema.execute(r#"{ user(id: "admin_123") }"#).await {
+        Err(Error::Rule { errors }) => {
+            assert_eq!(errors.len(), 1);
+            assert!(errors[0]
+                .message
+                .ends_with("the id \"admin_123\" doesn't match the pattern \"user_[0-9]+\""));
+        }
+        _ => panic!("expected a validation error"),
+    }
+
+    assert!(schema.execute("{ user(id: 123) }").await.is_err());
+}
+
+#[async_std::test]
+pub async fn test_validator_combinators() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let validator = StringMinLength { length: 3 }
+        .and(StringMaxLength { length: 8 })
+        .or(Email {})
+        .and(Chars { allowed: "0-9" }.not("the value must not be a number"));
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .register_directive(Directive {
+            name: "tag",
+            description: None,
+            locations: vec![DirectiveLocation::FIELD],
+            args: {
+                let mut args = std::collections::HashMap::new();
+                args.insert(
+                    "name",
+                    registry::InputValue {
+                        name: "name",
+                        description: None,
+                        ty: "String!".to_string(),
+                        default_value: None,
+                        validator: Some(Arc::new(validator)),
+                        transformer: None,
+                    },
+                );
+                args
+            },
+            is_repeatable: false,
+        })
+        .finish();
+
+    for name in &["hello", "someone@example.com"] {
+        assert_eq!(
+            schema
+                .execute(&format!(r#"{{ value @tag(name: "{}") }}"#, name))
+                .await
+                .unwrap()
+                .data,
+            serde_json::json!({ "value": 10 })
+        );
+    }
+
+    for (name, message) in &[
+        (
+            "hi",
+            "the value length is 2, but the length must be greater than or equal to 3",
+        ),
+        ("12345", "the value must not be a number"),
+    ] {
+        match schema
+            .execute(&format!(r#"{{ value @tag(name: "{}") }}"#, name))
+            .await
+        {
+            Err(Error::Rule { errors }) => {
+                assert_eq!(errors.len(), 1);
+                assert!(errors[0].message.ends_with(message));
+            }
+            _ => panic!("expected a validation error"),
+        }
+    }
+}
+
+#[async_std::test]
+pub async fn test_id_pattern_validator_invalid_pattern() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn user(&self, #[arg(validator(IDPattern(pattern = "user_[0-9")))] id: ID) -> ID {
+            id
+        }
+    }
+
+    match Schema::build(QueryRoot, EmptyMutation, EmptySubscription).try_finish() {
+        Err(SchemaError::InvalidValidator { name, reason }) => {
+            assert_eq!(name, "QueryRoot.user(id)");
+            assert!(reason.starts_with("invalid id pattern \"user_[0-9\""));
+        }
+        _ => panic!("expected an invalid validator error"),
+    }
+}
@@ -73,3 +73,121 @@ pub async fn test_variable_default_value() {
         })
     );
 }
+
+#[async_std::test]
+pub async fn test_unused_variable() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        pub async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    let query = "query Q($unused: Int) { value }";
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    match schema.execute(query).await {
+        Err(Error::Rule { errors }) => {
+            assert_eq!(errors.len(), 1);
+            assert_eq!(
+                errors[0].message,
+                r#"Variable "$unused" is not used by operation "Q""#
+            );
+            assert_eq!(errors[0].locations, vec![Pos { line: 1, column: 9 }]);
+        }
+        _ => panic!("expected an unused variable error"),
+    }
+
+    // The rule is only checked in strict mode.
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .validation_mode(ValidationMode::Fast)
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({ "value": 10 })
+    );
+}
+
+#[async_std::test]
+pub async fn test_block_string() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        async fn value(&self, s: String) -> String {
+            s
+        }
+    }
+
+    let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
+    let query = r#"
+        query($s: String = """
+            Default
+              value
+        """) {
+            a: value(s: """
+                Hello,
+                  World!
+
+                Yours,
+                  GraphQL.
+            """)
+            b: value(s: $s)
+        }
+    "#;
+    assert_eq!(
+        schema.execute(query).await.unwrap().data,
+        serde_json::json!({
+            "a": "Hello,\n  World!\n\nYours,\n  GraphQL.",
+            "b": "Default\n  value",
+        })
+    );
+}
+
+#[async_std::test]
+pub async fn test_variables_preprocessor() {
+    struct QueryRoot;
+
+    #[Object]
+    impl QueryRoot {
+        #[field]
+        pub async fn value(&self, value: String) -> String {
+            value
+        }
+    }
+
+    let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .variables_preprocessor(|mut variables| {
+            for value in variables.values_mut() {
+                if let Value::String(s) = value {
+                    *s = s.trim().to_lowercase();
+                }
+            }
+            variables
+        })
+        .finish();
+    let query = QueryBuilder::new(
+        r#"
+            query QueryWithVariables($value: String!) {
+                value(value: $value)
+            }
+        "#,
+    )
+    .variables(
+        Variables::parse_from_json(serde_json::json!({
+            "value": "  Hello World  ",
+        }))
+        .unwrap(),
+    );
+    let resp = query.execute(&schema).await.unwrap();
+    assert_eq!(
+        resp.data,
+        serde_json::json!({
+            "value": "hello world",
+        })
+    );
+}